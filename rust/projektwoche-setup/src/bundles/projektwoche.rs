@@ -26,9 +26,22 @@
 //! 1. All packages are installed concurrently using multi-threading
 //! 2. Configuration is applied after installation completes
 //! 3. Each tool is configured for optimal educational use
+//!
+//! ## Preferences
+//!
+//! The answers of the configuration wizard (`preferences` in the
+//! configuration) adjust the bundle:
+//!
+//! - **Browser**: Chrome by default, Firefox if chosen; other browsers are
+//!   left to the user
+//! - **Editor**: Visual Studio Code unless another editor was chosen
+//! - **Languages**: Python is added when chosen; Node.js and Bun are always
+//!   included, as the Projektwoche is built on JavaScript
+//! - **Extras**: Git is only installed with the additional tools
 
+use crate::config::interactive::WizardConfig;
 use crate::manager::SoftwareBundle;
-use crate::packages::{apps, ide, js};
+use crate::packages::{apps, ide, js, python};
 
 /// Creates the Projektwoche software bundle.
 ///
@@ -48,6 +61,11 @@ use crate::packages::{apps, ide, js};
 /// - **Cross-platform**: Automatically adapts to Windows/Linux environments
 /// - **Educational optimized**: Focuses on tools that enhance learning
 ///
+/// # Arguments
+///
+/// * `preferences` - Answers of the configuration wizard that choose the
+///   browser, editor, languages and extras
+///
 /// # Returns
 ///
 /// Returns a fully configured [`SoftwareBundle`] ready for installation.
@@ -55,14 +73,31 @@ use crate::packages::{apps, ide, js};
 /// # Example Usage
 ///
 /// ```rust
-/// let bundle = projektwoche::bundle();
+/// let bundle = projektwoche::bundle(&WizardConfig::default());
 /// bundle.install(&os, false)?; // Install on detected OS
 /// ```
-pub fn bundle() -> SoftwareBundle {
-  SoftwareBundle::new("Projektwoche", "A Bundle containing Packages to set up a development environment for the Projektwoche of the Athenaeum Stade")
-      .add_program(apps::git())
+pub fn bundle(preferences: &WizardConfig) -> SoftwareBundle {
+  let mut bundle = SoftwareBundle::new("Projektwoche", "A Bundle containing Packages to set up a development environment for the Projektwoche of the Athenaeum Stade");
+
+  if preferences.install_extras {
+    bundle = bundle.add_program(apps::git());
+  }
+
+  bundle = bundle
       .add_program(js::nodejs())
-      .add_program(js::bun())
-      .add_program(ide::vscode())
-      .add_program(apps::chrome())
+      .add_program(js::bun());
+
+  if preferences.languages.iter().any(|language| language == "python") {
+    bundle = bundle.add_program(python::python());
+  }
+
+  if preferences.editor == "vscode" {
+    bundle = bundle.add_program(ide::vscode());
+  }
+
+  match preferences.browser.as_str() {
+    "chrome" => bundle.add_program(apps::chrome()),
+    "firefox" => bundle.add_program(apps::firefox()),
+    _ => bundle,
+  }
 }
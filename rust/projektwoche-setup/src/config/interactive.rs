@@ -12,6 +12,8 @@
 //! - **Default Values**: Support for defaults to speed up configuration
 //! - **Multiple Input Types**: Yes/No, text, single choice, multiple choice, and paths
 //! - **Error Handling**: Graceful handling of user cancellation and errors
+//! - **Unattended Mode**: Prompts can be pre-answered from an answer file or
+//!   skipped entirely, for installs over SSH, Ansible or GPO scripts
 //!
 //! ## Usage Example
 //!
@@ -19,18 +21,211 @@
 //! use crate::config::interactive::{ask_yes_no, ask_text, ask_choice};
 //!
 //! // Simple yes/no question with beautiful UI
-//! let install_extras = ask_yes_no("Install optional components?", false);
+//! let install_extras = ask_yes_no("extras", "Install optional components?", false);
 //!
 //! // Text input with default and validation
-//! let username = ask_text("Enter your username", Some("developer"));
+//! let username = ask_text("username", "Enter your username", Some("developer"));
 //!
 //! // Beautiful single choice with arrow key navigation
 //! let editors = [("vscode", "Visual Studio Code"), ("vim", "Vim")];
-//! let choice = ask_choice("Select your editor:", &editors, Some(0));
+//! let choice = ask_choice("editor", "Select your editor:", &editors, Some(0));
+//! ```
+//!
+//! ## Unattended Mode
+//!
+//! Every prompt has a dotted key under which an answer file can answer it.
+//! [`init`] is called once at startup with the `--yes`, `--non-interactive`
+//! and `--answers` command line options. A prompt is then answered by:
+//!
+//! 1. `--yes` for confirmations ([`confirm`], [`confirm_action`])
+//! 2. the answer file, if it contains the prompt's key
+//! 3. the user, if prompting is allowed and stdin is a terminal
+//! 4. the prompt's default otherwise
+//!
+//! Answers that are not shown as a prompt are printed to stderr, so logs of
+//! unattended runs still show every decision.
+//!
+//! ```toml
+//! [install]
+//! confirm = true
+//!
+//! [wizard]
+//! editor = "vscode"
+//! languages = ["javascript", "python"]
+//! custom_path = false
 //! ```
 
+use crate::i18n;
 use inquire::{Confirm, MultiSelect, Select, Text, validator::Validation};
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::io::IsTerminal;
 use std::path::Path;
+use std::sync::OnceLock;
+use toml::Value;
+
+/// Decides how prompts are answered; set once at startup with [`init`].
+#[derive(Debug, Default, Clone)]
+pub struct PromptSettings {
+  assume_yes: bool,
+  non_interactive: bool,
+  json_output: bool,
+  answers: toml::Table,
+}
+
+impl PromptSettings {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Answers every confirmation with yes.
+  pub fn with_assume_yes(mut self, assume_yes: bool) -> Self {
+    self.assume_yes = assume_yes;
+    self
+  }
+
+  /// Never shows a prompt; unanswered prompts take their default.
+  pub fn with_non_interactive(mut self, non_interactive: bool) -> Self {
+    self.non_interactive = non_interactive;
+    self
+  }
+
+  /// Reserves stdout for JSON log records, so [`say`] writes to stderr.
+  pub fn with_json_output(mut self, json_output: bool) -> Self {
+    self.json_output = json_output;
+    self
+  }
+
+  /// Pre-answers prompts with the values of a TOML answer file.
+  pub fn with_answer_file(mut self, path: &Path) -> Result<Self, Box<dyn Error + Send + Sync>> {
+    let content = std::fs::read_to_string(path)
+      .map_err(|e| i18n::cannot_read_answers(path.display(), e))?;
+    self.answers = toml::from_str(&content)
+      .map_err(|e| i18n::invalid_answers(path.display(), e))?;
+    Ok(self)
+  }
+
+  /// Returns `true` if prompts may be shown to the user.
+  ///
+  /// Besides `--non-interactive`, this is also `false` when stdin or stderr
+  /// is not a terminal, e.g. when run from a script or over plain SSH.
+  pub fn is_interactive(&self) -> bool {
+    !self.non_interactive && std::io::stdin().is_terminal() && std::io::stderr().is_terminal()
+  }
+
+  fn answer(&self, key: &str) -> Option<&Value> {
+    let mut parts = key.split('.');
+    let first = self.answers.get(parts.next()?)?;
+    parts.try_fold(first, |value, part| value.get(part))
+  }
+}
+
+static SETTINGS: OnceLock<PromptSettings> = OnceLock::new();
+
+/// Sets how prompts are answered for the rest of the run.
+///
+/// Only the first call has an effect; without a call, prompts are shown
+/// whenever a terminal is attached.
+pub fn init(settings: PromptSettings) {
+  let _ = SETTINGS.set(settings);
+}
+
+fn settings() -> &'static PromptSettings {
+  SETTINGS.get_or_init(PromptSettings::default)
+}
+
+/// Prints a line of plain text for the user, such as a dry-run step.
+///
+/// With `--log-format json`, stdout only carries JSON records and the text
+/// goes to stderr instead. A live progress display is hidden while the text
+/// is printed, so the text ends up above its lines.
+pub fn say(text: impl std::fmt::Display) {
+  let _suspended = crate::progress::suspend();
+  if settings().json_output {
+    eprintln!("{}", text);
+  } else {
+    println!("{}", text);
+  }
+}
+
+/// Returns `true` if prompts are shown to the user in this run.
+pub fn is_interactive() -> bool {
+  settings().is_interactive()
+}
+
+/// Returns the answer file's answer for `key`, if it has a usable one.
+///
+/// Answers that cannot be used for the prompt are reported and ignored.
+fn preset<T>(
+  key: &str,
+  question: &str,
+  parse: impl FnOnce(&Value) -> Result<T, String>,
+) -> Option<T> {
+  let value = settings().answer(key)?;
+  match parse(value) {
+    Ok(answer) => {
+      eprintln!("{}", i18n::answered_by_file(question, value, key));
+      Some(answer)
+    }
+    Err(e) => {
+      eprintln!("{}", i18n::ignoring_answer(value, key, e));
+      None
+    }
+  }
+}
+
+/// Reports the default taken for a prompt that cannot be shown.
+fn unattended(question: &str, answer: &str) {
+  eprintln!("{}", i18n::answered_by_default(question, answer));
+}
+
+/// Parses a typed answer to a yes/no prompt, in English or German.
+fn parse_yes_no(input: &str) -> Result<bool, ()> {
+  match input.trim().to_lowercase().as_str() {
+    "y" | "yes" | "j" | "ja" => Ok(true),
+    "n" | "no" | "nein" => Ok(false),
+    _ => Err(()),
+  }
+}
+
+fn parse_choice<T: ToString + Clone>(value: &Value, options: &[(T, &str)]) -> Result<T, String> {
+  let name = value.as_str().ok_or(i18n::unexpected_string())?;
+  options
+    .iter()
+    .find(|(option, _)| option.to_string() == name)
+    .map(|(option, _)| option.clone())
+    .ok_or_else(|| {
+      let names: Vec<String> = options.iter().map(|(option, _)| option.to_string()).collect();
+      i18n::expected_one_of(names.join(", "))
+    })
+}
+
+/// Checks a path answer against the requirements of [`ask_path`].
+fn check_path(input: &str, must_exist: bool, must_be_writable: bool) -> Result<(), String> {
+  let path = Path::new(input);
+
+  if must_exist && !path.exists() {
+    return Err(i18n::path_missing(input));
+  }
+
+  if must_be_writable {
+    // Test if we can write to the directory
+    let test_dir = if path.is_dir() {
+      path.to_path_buf()
+    } else {
+      path.parent().unwrap_or(Path::new(".")).to_path_buf()
+    };
+
+    let test_file = test_dir.join(".write_test_inquire");
+    if std::fs::write(&test_file, "test").is_err() {
+      return Err(i18n::path_not_writable(input));
+    }
+    // Clean up test file
+    let _ = std::fs::remove_file(&test_file);
+  }
+
+  Ok(())
+}
 
 /// Asks the user a yes/no question with a beautiful confirmation prompt.
 ///
@@ -40,6 +235,7 @@ use std::path::Path;
 ///
 /// # Arguments
 ///
+/// * `key` - The key under which an answer file answers the question
 /// * `question` - The question text to display to the user
 /// * `default` - The default value if user presses Enter (true = Yes, false = No)
 ///
@@ -51,14 +247,30 @@ use std::path::Path;
 /// # Example
 ///
 /// ```rust
-/// let install_dev_tools = ask_yes_no("Install development tools?", true);
+/// let install_dev_tools = ask_yes_no("dev_tools", "Install development tools?", true);
 /// if install_dev_tools {
 ///     println!("Installing development tools...");
 /// }
 /// ```
-pub fn ask_yes_no(question: &str, default: bool) -> bool {
+pub fn ask_yes_no(key: &str, question: &str, default: bool) -> bool {
+  if let Some(answer) = preset(key, question, |value| {
+    value.as_bool().ok_or_else(|| i18n::unexpected_bool().to_string())
+  }) {
+    return answer;
+  }
+  if !is_interactive() {
+    unattended(question, if default { i18n::yes() } else { i18n::no() });
+    return default;
+  }
+
   Confirm::new(question)
     .with_default(default)
+    .with_parser(&parse_yes_no)
+    .with_formatter(&|answer| if answer { i18n::yes() } else { i18n::no() }.to_string())
+    .with_default_value_formatter(&|default| {
+      if default { i18n::default_yes() } else { i18n::default_no() }.to_string()
+    })
+    .with_error_message(i18n::answer_yes_or_no())
     .prompt()
     .unwrap_or(default) // Use default if user cancels
 }
@@ -71,6 +283,7 @@ pub fn ask_yes_no(question: &str, default: bool) -> bool {
 ///
 /// # Arguments
 ///
+/// * `key` - The key under which an answer file answers the question
 /// * `question` - The prompt text to display to the user
 /// * `default` - Optional default value to use if user enters nothing
 ///
@@ -82,10 +295,24 @@ pub fn ask_yes_no(question: &str, default: bool) -> bool {
 /// # Example
 ///
 /// ```rust
-/// let project_name = ask_text("Enter project name", Some("my-project"));
-/// let description = ask_text("Enter project description", None);
+/// let project_name = ask_text("project.name", "Enter project name", Some("my-project"));
+/// let description = ask_text("project.description", "Enter project description", None);
 /// ```
-pub fn ask_text(question: &str, default: Option<&str>) -> String {
+pub fn ask_text(key: &str, question: &str, default: Option<&str>) -> String {
+  if let Some(answer) = preset(key, question, |value| {
+    value
+      .as_str()
+      .map(str::to_string)
+      .ok_or_else(|| i18n::unexpected_string().to_string())
+  }) {
+    return answer;
+  }
+  if !is_interactive() {
+    let answer = default.unwrap_or("").to_string();
+    unattended(question, &answer);
+    return answer;
+  }
+
   let mut prompt = Text::new(question);
 
   if let Some(def) = default {
@@ -105,6 +332,7 @@ pub fn ask_text(question: &str, default: Option<&str>) -> String {
 ///
 /// # Arguments
 ///
+/// * `key` - The key under which an answer file answers the question
 /// * `question` - The prompt text to display above the options
 /// * `options` - Array of tuples containing (value, description) pairs
 /// * `default` - Optional index of the default selection (0-based)
@@ -112,7 +340,13 @@ pub fn ask_text(question: &str, default: Option<&str>) -> String {
 /// # Returns
 ///
 /// Returns the value (first element of the tuple) for the selected option.
-/// Returns the default option if user cancels.
+/// Returns the default option if user cancels. An answer file names the
+/// value of the option. A default outside of `options` selects the first
+/// option.
+///
+/// # Panics
+///
+/// Panics if `options` is empty.
 ///
 /// # Example
 ///
@@ -124,6 +358,7 @@ pub fn ask_text(question: &str, default: Option<&str>) -> String {
 /// ];
 ///
 /// let choice = ask_choice(
+///     "package_manager",
 ///     "Select package manager:",
 ///     &package_managers,
 ///     Some(0) // npm as default
@@ -132,10 +367,22 @@ pub fn ask_text(question: &str, default: Option<&str>) -> String {
 /// println!("Selected: {}", choice);
 /// ```
 pub fn ask_choice<T: ToString + Clone>(
+  key: &str,
   question: &str,
   options: &[(T, &str)],
   default: Option<usize>,
 ) -> T {
+  // Out-of-range defaults fall back to the first option
+  let default = default.filter(|&i| i < options.len());
+  if let Some(answer) = preset(key, question, |value| parse_choice(value, options)) {
+    return answer;
+  }
+  if !is_interactive() {
+    let answer = options[default.unwrap_or(0)].0.clone();
+    unattended(question, &answer.to_string());
+    return answer;
+  }
+
   let choices: Vec<&str> = options.iter().map(|(_, desc)| *desc).collect();
   let mut prompt = Select::new(question, choices);
 
@@ -165,6 +412,7 @@ pub fn ask_choice<T: ToString + Clone>(
 ///
 /// # Arguments
 ///
+/// * `key` - The key under which an answer file answers the question
 /// * `question` - The prompt text to display
 /// * `options` - Array of tuples containing (value, description) pairs
 /// * `defaults` - Optional vector of default selections (0-based indices)
@@ -172,6 +420,8 @@ pub fn ask_choice<T: ToString + Clone>(
 /// # Returns
 ///
 /// Returns a vector of selected values. Returns default selections if user cancels.
+/// An answer file lists the values of the selected options. Defaults outside
+/// of `options` are ignored.
 ///
 /// # Example
 ///
@@ -184,16 +434,46 @@ pub fn ask_choice<T: ToString + Clone>(
 /// ];
 ///
 /// let selected = ask_multiple_choice(
+///     "languages",
 ///     "Select programming languages to install:",
 ///     &languages,
 ///     Some(vec![0, 2]) // Default to Rust and JavaScript
 /// );
 /// ```
 pub fn ask_multiple_choice<T: ToString + Clone>(
+  key: &str,
   question: &str,
   options: &[(T, &str)],
   defaults: Option<Vec<usize>>,
 ) -> Vec<T> {
+  // Out-of-range defaults are ignored
+  let defaults = defaults.map(|indices| {
+    indices
+      .into_iter()
+      .filter(|&i| i < options.len())
+      .collect::<Vec<_>>()
+  });
+  if let Some(answer) = preset(key, question, |value| {
+    value
+      .as_array()
+      .ok_or(i18n::unexpected_list())?
+      .iter()
+      .map(|item| parse_choice(item, options))
+      .collect()
+  }) {
+    return answer;
+  }
+  if !is_interactive() {
+    let answer: Vec<T> = defaults
+      .unwrap_or_default()
+      .into_iter()
+      .map(|i| options[i].0.clone())
+      .collect();
+    let names: Vec<String> = answer.iter().map(ToString::to_string).collect();
+    unattended(question, &names.join(", "));
+    return answer;
+  }
+
   let choices: Vec<&str> = options.iter().map(|(_, desc)| *desc).collect();
   let mut prompt = MultiSelect::new(question, choices);
 
@@ -230,6 +510,7 @@ pub fn ask_multiple_choice<T: ToString + Clone>(
 ///
 /// # Arguments
 ///
+/// * `key` - The key under which an answer file answers the question
 /// * `question` - The prompt text to display
 /// * `default` - Optional default path
 /// * `must_exist` - If true, validates that the path exists
@@ -243,6 +524,7 @@ pub fn ask_multiple_choice<T: ToString + Clone>(
 ///
 /// ```rust
 /// let install_dir = ask_path(
+///     "install_dir",
 ///     "Enter installation directory",
 ///     Some("/usr/local/bin"),
 ///     false, // doesn't need to exist
@@ -250,39 +532,30 @@ pub fn ask_multiple_choice<T: ToString + Clone>(
 /// );
 /// ```
 pub fn ask_path(
+  key: &str,
   question: &str,
   default: Option<&str>,
   must_exist: bool,
   must_be_writable: bool,
 ) -> String {
-  let validator = move |input: &str| {
-    let path = Path::new(input);
-
-    if must_exist && !path.exists() {
-      return Ok(Validation::Invalid(
-        format!("Path '{}' does not exist", input).into(),
-      ));
-    }
-
-    if must_be_writable {
-      // Test if we can write to the directory
-      let test_dir = if path.is_dir() {
-        path.to_path_buf()
-      } else {
-        path.parent().unwrap_or(Path::new(".")).to_path_buf()
-      };
-
-      let test_file = test_dir.join(".write_test_inquire");
-      if std::fs::write(&test_file, "test").is_err() {
-        return Ok(Validation::Invalid(
-          format!("Path '{}' is not writable", input).into(),
-        ));
-      }
-      // Clean up test file
-      let _ = std::fs::remove_file(&test_file);
-    }
+  if let Some(answer) = preset(key, question, |value| {
+    let path = value.as_str().ok_or(i18n::unexpected_string())?;
+    check_path(path, must_exist, must_be_writable)?;
+    Ok(path.to_string())
+  }) {
+    return answer;
+  }
+  if !is_interactive() {
+    let answer = default.unwrap_or(".").to_string();
+    unattended(question, &answer);
+    return answer;
+  }
 
-    Ok(Validation::Valid)
+  let validator = move |input: &str| {
+    Ok(match check_path(input, must_exist, must_be_writable) {
+      Ok(()) => Validation::Valid,
+      Err(e) => Validation::Invalid(e.into()),
+    })
   };
 
   let mut prompt = Text::new(question).with_validator(validator);
@@ -296,6 +569,17 @@ pub fn ask_path(
     .unwrap_or_else(|_| default.unwrap_or(".").to_string())
 }
 
+/// Asks the user to confirm an action, e.g. before installing a bundle.
+///
+/// Works like [`ask_yes_no`], but `--yes` answers it with yes.
+pub fn confirm(key: &str, question: &str, default: bool) -> bool {
+  if settings().assume_yes {
+    eprintln!("{}", i18n::answered_by_yes(question, i18n::yes()));
+    return true;
+  }
+  ask_yes_no(key, question, default)
+}
+
 /// Displays a beautiful confirmation prompt showing what will be done.
 ///
 /// This function shows a summary of actions with proper formatting and
@@ -304,6 +588,7 @@ pub fn ask_path(
 ///
 /// # Arguments
 ///
+/// * `key` - The key under which an answer file answers the confirmation
 /// * `message` - Description of what will happen
 /// * `details` - Optional detailed list of actions
 ///
@@ -320,27 +605,65 @@ pub fn ask_path(
 ///     "Configure development environment"
 /// ];
 ///
-/// if confirm_action("The following will be installed:", Some(&details)) {
+/// if confirm_action("summary", "The following will be installed:", Some(&details)) {
 ///     // Proceed with installation
 /// }
 /// ```
-pub fn confirm_action(message: &str, details: Option<&[&str]>) -> bool {
-  println!("\n📋 {}", message);
+pub fn confirm_action(key: &str, message: &str, details: Option<&[&str]>) -> bool {
+  say(format!("\n📋 {}", message));
 
   if let Some(items) = details {
     for item in items {
-      println!("   • {}", item);
+      say(format!("   • {}", item));
+    }
+  }
+
+  say("");
+  confirm(key, i18n::continue_confirm(), true)
+}
+
+/// Preferences collected by the [`configuration_wizard`].
+///
+/// Stored as `preferences` in the configuration, where they decide which
+/// packages a bundle contains (see [`crate::bundles`]).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WizardConfig {
+  /// Preferred code editor, e.g. `vscode`
+  pub editor: String,
+  /// Preferred web browser, e.g. `chrome` or `firefox`
+  pub browser: String,
+  /// Programming languages to set up, e.g. `javascript` or `python`
+  pub languages: Vec<String>,
+  /// Directory for portable installs instead of the default location
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub install_path: Option<String>,
+  /// Whether to install additional development tools such as Git
+  pub install_extras: bool,
+}
+
+impl Default for WizardConfig {
+  fn default() -> Self {
+    Self {
+      editor: "vscode".to_string(),
+      browser: "chrome".to_string(),
+      languages: vec!["javascript".to_string()],
+      install_path: None,
+      install_extras: true,
     }
   }
+}
 
-  println!();
-  ask_yes_no("❓ Do you want to continue?", true)
+/// Returns the index of the option named `name`, if there is one.
+fn option_index(options: &[(&str, &str)], name: &str) -> Option<usize> {
+  options.iter().position(|(option, _)| *option == name)
 }
 
 /// Creates a beautiful multi-step configuration wizard.
 ///
 /// This function demonstrates how to chain multiple inquire prompts
-/// together to create a comprehensive configuration experience.
+/// together to create a comprehensive configuration experience. The
+/// `current` preferences are offered as the defaults of every question.
 ///
 /// # Returns
 ///
@@ -349,53 +672,65 @@ pub fn confirm_action(message: &str, details: Option<&[&str]>) -> bool {
 /// # Example
 ///
 /// ```rust
-/// if let Some(config) = configuration_wizard() {
+/// if let Some(config) = configuration_wizard(&WizardConfig::default()) {
 ///     println!("Configuration completed: {:?}", config);
 /// }
 /// ```
-#[derive(Debug, Clone)]
-pub struct WizardConfig {
-  pub editor: String,
-  pub browser: String,
-  pub languages: Vec<String>,
-  pub install_path: Option<String>,
-  pub install_extras: bool,
-}
-
-pub fn configuration_wizard() -> Option<WizardConfig> {
-  println!("🚀 Welcome to the Interactive Setup Wizard!");
-  println!("   Let's configure your development environment.\n");
+pub fn configuration_wizard(current: &WizardConfig) -> Option<WizardConfig> {
+  println!("{}", i18n::wizard_welcome());
 
   // Editor selection
+  let vscode = i18n::recommended("Visual Studio Code");
   let editors = [
-    ("vscode", "Visual Studio Code (Recommended)"),
+    ("vscode", vscode.as_str()),
     ("vim", "Vim"),
     ("emacs", "Emacs"),
     ("nano", "Nano"),
-    ("other", "Other (I'll specify)"),
+    ("other", i18n::other_specify()),
   ];
 
-  let editor_choice = ask_choice("🎯 Which code editor do you prefer?", &editors, Some(0));
+  let editor_default = option_index(&editors, &current.editor).unwrap_or(editors.len() - 1);
+  let editor_choice = ask_choice(
+    "wizard.editor",
+    i18n::wizard_editor(),
+    &editors,
+    Some(editor_default),
+  );
 
   let editor = if editor_choice == "other" {
-    ask_text("✏️  Please specify your preferred editor:", None)
+    ask_text(
+      "wizard.other_editor",
+      i18n::wizard_other_editor(),
+      option_index(&editors, &current.editor).is_none().then_some(current.editor.as_str()),
+    )
   } else {
     editor_choice.to_string()
   };
 
   // Browser selection
+  let chrome = i18n::recommended("Google Chrome");
   let browsers = [
-    ("chrome", "Google Chrome (Recommended)"),
+    ("chrome", chrome.as_str()),
     ("firefox", "Mozilla Firefox"),
     ("edge", "Microsoft Edge"),
     ("safari", "Safari"),
-    ("other", "Other"),
+    ("other", i18n::other()),
   ];
 
-  let browser_choice = ask_choice("🌐 Which web browser do you prefer?", &browsers, Some(0));
+  let browser_default = option_index(&browsers, &current.browser).unwrap_or(browsers.len() - 1);
+  let browser_choice = ask_choice(
+    "wizard.browser",
+    i18n::wizard_browser(),
+    &browsers,
+    Some(browser_default),
+  );
 
   let browser = if browser_choice == "other" {
-    ask_text("🌍 Please specify your preferred browser:", None)
+    ask_text(
+      "wizard.other_browser",
+      i18n::wizard_other_browser(),
+      option_index(&browsers, &current.browser).is_none().then_some(current.browser.as_str()),
+    )
   } else {
     browser_choice.to_string()
   };
@@ -411,9 +746,16 @@ pub fn configuration_wizard() -> Option<WizardConfig> {
   ];
 
   let languages: Vec<String> = ask_multiple_choice(
-    "💻 Which programming languages do you work with?",
+    "wizard.languages",
+    i18n::wizard_languages(),
     &language_options,
-    Some(vec![0, 1]), // Default to Rust and JavaScript
+    Some(
+      current
+        .languages
+        .iter()
+        .filter_map(|language| option_index(&language_options, language))
+        .collect(),
+    ),
   )
   .into_iter()
   .map(|s| s.to_string())
@@ -421,19 +763,21 @@ pub fn configuration_wizard() -> Option<WizardConfig> {
 
   // Installation path (optional)
   let ask_custom_path = ask_yes_no(
-    "📁 Do you want to specify a custom installation path?",
-    false,
+    "wizard.custom_path",
+    i18n::wizard_custom_path(),
+    current.install_path.is_some(),
   );
 
   let install_path = if ask_custom_path {
-    let default_path = if cfg!(windows) {
+    let default_path = current.install_path.as_deref().unwrap_or(if cfg!(windows) {
       "C:\\DevTools"
     } else {
       "/usr/local"
-    };
+    });
 
     Some(ask_path(
-      "📂 Enter the installation directory:",
+      "wizard.install_path",
+      i18n::wizard_install_path(),
       Some(default_path),
       false, // doesn't need to exist
       true,  // must be writable
@@ -444,14 +788,15 @@ pub fn configuration_wizard() -> Option<WizardConfig> {
 
   // Optional extras
   let install_extras = ask_yes_no(
-    "🔧 Install additional development tools? (Git, Docker, etc.)",
-    true,
+    "wizard.install_extras",
+    i18n::wizard_extras(),
+    current.install_extras,
   );
 
   // Final confirmation
-  let editor_summary = format!("Editor: {}", editor);
-  let browser_summary = format!("Browser: {}", browser);
-  let languages_summary = format!("Languages: {}", languages.join(", "));
+  let editor_summary = i18n::summary_editor(&editor);
+  let browser_summary = i18n::summary_browser(&browser);
+  let languages_summary = i18n::summary_languages(languages.join(", "));
 
   let mut summary = vec![
     editor_summary.as_str(),
@@ -461,15 +806,15 @@ pub fn configuration_wizard() -> Option<WizardConfig> {
 
   let install_path_summary;
   if let Some(ref path) = install_path {
-    install_path_summary = format!("Install path: {}", path);
+    install_path_summary = i18n::summary_install_path(path);
     summary.push(&install_path_summary);
   }
 
   if install_extras {
-    summary.push("Additional tools: Yes");
+    summary.push(i18n::summary_extras());
   }
 
-  if confirm_action("📋 Configuration Summary:", Some(&summary)) {
+  if confirm_action("wizard.confirm", i18n::wizard_summary(), Some(&summary)) {
     Some(WizardConfig {
       editor,
       browser,
@@ -478,7 +823,7 @@ pub fn configuration_wizard() -> Option<WizardConfig> {
       install_extras,
     })
   } else {
-    println!("❌ Configuration cancelled.");
+    println!("{}", i18n::wizard_cancelled());
     None
   }
 }
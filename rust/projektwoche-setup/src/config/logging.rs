@@ -0,0 +1,81 @@
+//! # Log Settings
+//!
+//! Every install, repair, upgrade and uninstall run writes its own log file,
+//! so a failed run can still be inspected after the terminal was closed.
+//! These settings choose the format and location of the files and how many
+//! of them are kept; `logs list/show/last` browse them. They also choose how
+//! the console shows the time of a message.
+//!
+//! ## Example
+//!
+//! ```toml
+//! [logging]
+//! format = "Json"      # One JSON object per line instead of plain text
+//! max_total_mb = 20    # Delete the oldest runs beyond 20 MB
+//! max_age_days = 7
+//! timestamp = "Elapsed" # Seconds since the start on the console
+//! ```
+
+use prowo_log::{LogFormat, LogRotation, LogTimestamp};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Settings of the console output and the per-run log files.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LoggingConfig {
+  /// Whether runs are logged to a file at all
+  pub file: bool,
+  /// Plain text or JSON lines
+  pub format: LogFormat,
+  /// Directory of the log files, instead of the default location
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub directory: Option<PathBuf>,
+  /// Size limit of a single run's file in megabytes
+  pub max_file_mb: u64,
+  /// Size limit of all log files together in megabytes
+  pub max_total_mb: u64,
+  /// Age in days after which a run's file is deleted
+  pub max_age_days: u64,
+  /// Time shown in front of console messages
+  pub timestamp: LogTimestamp,
+}
+
+impl Default for LoggingConfig {
+  fn default() -> Self {
+    Self {
+      file: true,
+      format: LogFormat::Text,
+      directory: None,
+      max_file_mb: 10,
+      max_total_mb: 50,
+      max_age_days: 30,
+      timestamp: LogTimestamp::Time,
+    }
+  }
+}
+
+impl LoggingConfig {
+  /// Returns the directory the log files are written to.
+  ///
+  /// Defaults to `prowo-setup/logs` in the state directory
+  /// (`~/.local/state` on Linux) or, where there is none, the local data
+  /// directory (`%LOCALAPPDATA%` on Windows).
+  pub fn directory(&self) -> Option<PathBuf> {
+    self.directory.clone().or_else(|| {
+      dirs::state_dir()
+        .or_else(dirs::data_local_dir)
+        .map(|dir| dir.join(super::APP_NAME).join("logs"))
+    })
+  }
+
+  /// Returns the rotation limits in the units the logger uses.
+  pub fn rotation(&self) -> LogRotation {
+    LogRotation {
+      max_file_size: self.max_file_mb.saturating_mul(1024 * 1024),
+      max_total_size: self.max_total_mb.saturating_mul(1024 * 1024),
+      max_age: Duration::from_secs(self.max_age_days.saturating_mul(24 * 60 * 60)),
+    }
+  }
+}
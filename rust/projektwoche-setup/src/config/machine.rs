@@ -8,7 +8,8 @@
 //!
 //! Currently supports:
 //! - **x86_64**: Standard 64-bit Intel/AMD processors
-//! - **AArch64**: ARM 64-bit processors (Apple Silicon, ARM servers)
+//! - **AArch64**: ARM 64-bit processors (Apple Silicon, ARM servers, Raspberry Pi 4/5)
+//! - **ARMv7**: ARM 32-bit processors (older Raspberry Pi models)
 //!
 //! ## Operating System Categories
 //!
@@ -19,37 +20,87 @@
 //! - **Arch-based**: Arch Linux and derivatives (Manjaro, EndeavourOS, etc.)
 //! - **RHEL-based**: Red Hat family (Fedora, CentOS, Rocky Linux, etc.)
 //! - **Debian-based**: Debian family (Ubuntu, Mint, Pop!_OS, etc.)
+//! - **Ubuntu-based**: Ubuntu and derivatives (Mint, Pop!_OS)
 //! - **Gentoo-based**: Gentoo and derivatives
 //! - **Android-based**: Android systems
+//!
+//! ## Matching
+//!
+//! An [`OsMatcher`] can additionally be restricted to CPU architectures, OS
+//! version ranges and [facts](Fact). When several mappings of a package match
+//! a machine, the most specific one wins (see [`OsMatcher::specificity`]).
+//!
+//! ## Facts
+//!
+//! Beyond OS and architecture, [`Facts`] describe the environment the tool
+//! runs in: OS codename, default shell, desktop environment, privileges and
+//! whether it runs inside WSL, a container or a virtual machine. Facts are
+//! detected once per run and never persisted, since they can change between
+//! sessions (e.g. SSH vs. desktop login).
 
+use crate::i18n;
+use crate::manager::version::Version;
 use os_info::get;
 use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::OnceLock;
 
 /// Supported CPU architectures.
 ///
 /// The enum automatically detects the current system architecture
 /// and falls back to x86_64 for unsupported architectures.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum Architectures {
   /// Standard 64-bit Intel/AMD processors
   X86_64,
   /// ARM 64-bit processors (Apple Silicon, ARM servers)
   AArch64,
+  /// ARM 32-bit processors (older Raspberry Pi models)
+  Armv7,
+}
+impl Architectures {
+  /// Parses an architecture name as reported by `uname -m` or package managers.
+  pub fn from_name(name: &str) -> Option<Self> {
+    match name.to_lowercase().as_str() {
+      "x86_64" | "amd64" | "x64" => Some(Architectures::X86_64),
+      "aarch64" | "arm64" => Some(Architectures::AArch64),
+      "armv7l" | "armv7" | "armhf" => Some(Architectures::Armv7),
+      _ => None,
+    }
+  }
+}
+
+impl std::str::FromStr for Architectures {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Self::from_name(s).ok_or_else(|| i18n::unknown_architecture(s))
+  }
 }
+
 impl Default for Architectures {
   fn default() -> Self {
-    match get().architecture() {
-      Some("x86_64") => Architectures::X86_64,
-      Some("aarch64") => Architectures::AArch64,
+    match get().architecture().and_then(Architectures::from_name) {
+      Some(arch) => arch,
       _ => {
-        eprintln!("Unsupported architecture detected, defaulting to x86_64.");
+        eprintln!("{}", i18n::unsupported_architecture());
         Architectures::X86_64
       }
     }
   }
 }
 
+impl std::fmt::Display for Architectures {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Architectures::X86_64 => write!(f, "x86_64"),
+      Architectures::AArch64 => write!(f, "aarch64"),
+      Architectures::Armv7 => write!(f, "armv7"),
+    }
+  }
+}
+
 /// Operating system wrapper with serialization support.
 ///
 /// This struct wraps the `os_info::Type` to provide serialization
@@ -65,6 +116,72 @@ impl Default for OS {
   }
 }
 
+impl std::str::FromStr for OS {
+  type Err = String;
+
+  /// Parses an operating system name such as `ubuntu`, `windows` or `Red Hat Linux`.
+  ///
+  /// Matching ignores case and spaces, and accepts both the display name and
+  /// the identifier used in the configuration file.
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let normalize = |name: &str| name.replace([' ', '-', '_'], "").to_lowercase();
+    let wanted = normalize(s);
+    let known = || {
+      WINDOWS_BASED_OS
+        .iter()
+        .chain(MAC_BASED_OS)
+        .chain(LINUX_BASED_OS)
+        .chain(ANDROID_BASED_OS)
+    };
+
+    known()
+      .find(|os| normalize(&os.to_string()) == wanted || normalize(&format!("{:?}", os.0)) == wanted)
+      .copied()
+      .ok_or_else(|| {
+        let names: Vec<String> = known().map(|os| format!("{:?}", os.0).to_lowercase()).collect();
+        i18n::unknown_os(s, names.join(", "))
+      })
+  }
+}
+
+impl std::fmt::Display for OS {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.0)
+  }
+}
+
+/// Version of the operating system as reported by the system itself.
+///
+/// The raw string is kept for display (e.g. `22.04`, `10.0.22631` or
+/// `rolling`), and parsed on demand for version range matching.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OsVersion(String);
+
+impl OsVersion {
+  /// Creates a version from a raw version string such as `22.04`.
+  pub fn new(version: impl Into<String>) -> Self {
+    Self(version.into())
+  }
+
+  /// Parses the version for comparisons, if it is numeric.
+  pub fn parsed(&self) -> Option<Version> {
+    Version::extract(&self.0)
+  }
+}
+
+impl Default for OsVersion {
+  /// Automatically detects the current operating system version.
+  fn default() -> Self {
+    Self(get().version().to_string())
+  }
+}
+
+impl std::fmt::Display for OsVersion {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.0)
+  }
+}
+
 /// Broad categories of operating systems for easier targeting.
 ///
 /// These categories allow packages to specify instructions for
@@ -83,6 +200,8 @@ pub enum OsCategory {
   RHELBased,
   /// Debian family (Ubuntu, Mint, Pop!_OS, etc.)
   DebianBased,
+  /// Ubuntu and derivatives (Mint, Pop!_OS)
+  UbuntuBased,
   /// Gentoo and derivatives
   GentooBased,
   /// Android-based systems
@@ -104,7 +223,8 @@ pub enum OsSelector {
 ///
 /// This struct provides flexible OS matching capabilities, allowing
 /// packages to specify which operating systems they support through
-/// either specific OS types or broad categories.
+/// either specific OS types or broad categories, optionally narrowed down
+/// to CPU architectures and OS version ranges.
 ///
 /// # Example
 ///
@@ -115,14 +235,30 @@ pub enum OsSelector {
 /// // Create a matcher for specific OS types
 /// let specific_matcher = OsMatcher::new(&[OS::Windows, OS::MacOS]);
 ///
-/// // Check if current OS is supported
-/// if linux_matcher.matches(&current_os) {
+/// // Ubuntu 22.04 or newer on 64-bit ARM
+/// let arm_ubuntu = OsMatcher::new(&[OS::Ubuntu])
+///   .for_arch(&[Architectures::AArch64])
+///   .min_version("22.04");
+///
+/// // Check if the current machine is supported
+/// if linux_matcher.matches_machine(&machine) {
 ///     // Install using Linux-specific instructions
 /// }
 /// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct OsMatcher {
   /// List of supported operating systems
   os_list: Vec<OS>,
+  /// Human-readable name of the matched systems, used in error messages
+  label: String,
+  /// Supported architectures, empty for any architecture
+  architectures: Vec<Architectures>,
+  /// Lowest supported OS version (inclusive)
+  min_version: Option<Version>,
+  /// First unsupported OS version (exclusive)
+  max_version: Option<Version>,
+  /// Facts that must be present (`true`) or absent (`false`)
+  facts: Vec<(Fact, bool)>,
 }
 impl OsMatcher {
   /// Creates a new OS matcher with a specific list of supported systems.
@@ -131,11 +267,83 @@ impl OsMatcher {
   ///
   /// * `os_list` - Array of operating systems this matcher should support
   pub fn new(os_list: &[OS]) -> Self {
+    let label = os_list
+      .iter()
+      .map(|os| os.to_string())
+      .collect::<Vec<_>>()
+      .join(", ");
+    Self::labeled(os_list, label)
+  }
+
+  fn labeled(os_list: &[OS], label: impl Into<String>) -> Self {
     Self {
       os_list: os_list.to_vec(),
+      label: label.into(),
+      architectures: Vec::new(),
+      min_version: None,
+      max_version: None,
+      facts: Vec::new(),
     }
   }
 
+  /// Creates a matcher for Windows 11 and newer (build 22000 and up).
+  pub fn windows_11() -> Self {
+    Self::labeled(WINDOWS_BASED_OS, "Windows 11").min_version("10.0.22000")
+  }
+
+  /// Restricts the matcher to the given CPU architectures.
+  ///
+  /// # Arguments
+  ///
+  /// * `architectures` - Architectures this matcher should support
+  pub fn for_arch(mut self, architectures: &[Architectures]) -> Self {
+    self.architectures = architectures.to_vec();
+    self
+  }
+
+  /// Restricts the matcher to machines where `fact` holds.
+  ///
+  /// # Arguments
+  ///
+  /// * `fact` - Fact that must be present, e.g. [`Fact::Wsl`]
+  pub fn with_fact(mut self, fact: Fact) -> Self {
+    self.facts.push((fact, true));
+    self
+  }
+
+  /// Restricts the matcher to machines where `fact` does not hold.
+  ///
+  /// # Arguments
+  ///
+  /// * `fact` - Fact that must be absent, e.g. [`Fact::Admin`]
+  pub fn without_fact(mut self, fact: Fact) -> Self {
+    self.facts.push((fact, false));
+    self
+  }
+
+  /// Restricts the matcher to OS versions at or above `version`.
+  ///
+  /// Versions are compared against the distribution's own version number,
+  /// e.g. `22.04` for Ubuntu or `10.0.22631` for Windows.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `version` contains no version number.
+  pub fn min_version(mut self, version: &str) -> Self {
+    self.min_version = Some(Version::extract(version).expect("min_version needs a version number"));
+    self
+  }
+
+  /// Restricts the matcher to OS versions below `version`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `version` contains no version number.
+  pub fn below_version(mut self, version: &str) -> Self {
+    self.max_version = Some(Version::extract(version).expect("below_version needs a version number"));
+    self
+  }
+
   /// Checks if the given OS is supported by this matcher.
   ///
   /// # Arguments
@@ -149,6 +357,48 @@ impl OsMatcher {
     self.os_list.iter().any(|o| o.0 == os.0)
   }
 
+  /// Checks if the given machine satisfies the OS, architecture and
+  /// version constraints of this matcher.
+  ///
+  /// A machine whose version cannot be parsed (e.g. rolling releases) never
+  /// matches a matcher with a version range.
+  pub fn matches_machine(&self, machine: &Machine) -> bool {
+    if !self.matches(&machine.os) {
+      return false;
+    }
+    if !self.architectures.is_empty() && !self.architectures.contains(&machine.arch) {
+      return false;
+    }
+    let facts = machine.facts();
+    if self.facts.iter().any(|(fact, expected)| facts.has(fact) != *expected) {
+      return false;
+    }
+    if self.min_version.is_none() && self.max_version.is_none() {
+      return true;
+    }
+
+    let Some(version) = machine.version.parsed() else {
+      return false;
+    };
+    let above_min = self.min_version.as_ref().is_none_or(|min| &version >= min);
+    let below_max = self.max_version.as_ref().is_none_or(|max| &version < max);
+    above_min && below_max
+  }
+
+  /// Ranks how specific this matcher is.
+  ///
+  /// Matchers with more constraints (architecture, version range, facts) rank
+  /// higher; among equally constrained matchers, the one covering fewer
+  /// operating systems wins. For example, "Debian-based on aarch64" beats
+  /// "Debian-based", which beats "Linux-based".
+  pub fn specificity(&self) -> (usize, std::cmp::Reverse<usize>) {
+    let constraints = usize::from(!self.architectures.is_empty())
+      + usize::from(self.min_version.is_some())
+      + usize::from(self.max_version.is_some())
+      + self.facts.len();
+    (constraints, std::cmp::Reverse(self.os_list.len()))
+  }
+
   /// Creates a matcher from an OS selector.
   ///
   /// # Arguments
@@ -171,14 +421,15 @@ impl OsMatcher {
   /// * `category` - The OS category to match against
   pub fn from_category(category: OsCategory) -> Self {
     match category {
-      OsCategory::Windows => OsMatcher::new(&WINDOWS_BASED_OS),
-      OsCategory::MacOS => OsMatcher::new(&MAC_BASED_OS),
-      OsCategory::LinuxBased => OsMatcher::new(&LINUX_BASED_OS),
-      OsCategory::ArchBased => OsMatcher::new(&ARCH_BASED_OS),
-      OsCategory::RHELBased => OsMatcher::new(&RHEL_BASED_OS),
-      OsCategory::DebianBased => OsMatcher::new(&DEBIAN_BASED_OS),
-      OsCategory::GentooBased => OsMatcher::new(&GENTOO_BASED_OS),
-      OsCategory::AndroidBased => OsMatcher::new(&ANDROID_BASED_OS),
+      OsCategory::Windows => OsMatcher::labeled(WINDOWS_BASED_OS, "Windows"),
+      OsCategory::MacOS => OsMatcher::labeled(MAC_BASED_OS, "macOS"),
+      OsCategory::LinuxBased => OsMatcher::labeled(LINUX_BASED_OS, "Linux"),
+      OsCategory::ArchBased => OsMatcher::labeled(ARCH_BASED_OS, "Arch-based"),
+      OsCategory::RHELBased => OsMatcher::labeled(RHEL_BASED_OS, "RHEL-based"),
+      OsCategory::DebianBased => OsMatcher::labeled(DEBIAN_BASED_OS, "Debian-based"),
+      OsCategory::UbuntuBased => OsMatcher::labeled(UBUNTU_BASED_OS, "Ubuntu-based"),
+      OsCategory::GentooBased => OsMatcher::labeled(GENTOO_BASED_OS, "Gentoo-based"),
+      OsCategory::AndroidBased => OsMatcher::labeled(ANDROID_BASED_OS, "Android-based"),
     }
   }
 
@@ -189,10 +440,13 @@ impl OsMatcher {
   /// * `categories` - Array of OS categories to combine
   pub fn from_categories(categories: &[OsCategory]) -> Self {
     let mut os_list = Vec::new();
+    let mut labels = Vec::new();
     for category in categories {
-      os_list.extend(OsMatcher::from_category(*category).os_list);
+      let matcher = OsMatcher::from_category(*category);
+      os_list.extend(matcher.os_list);
+      labels.push(matcher.label);
     }
-    OsMatcher::new(&os_list)
+    OsMatcher::labeled(&os_list, labels.join("/"))
   }
 
   /// Returns the list of supported operating systems.
@@ -201,6 +455,30 @@ impl OsMatcher {
   }
 }
 
+impl std::fmt::Display for OsMatcher {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.label)?;
+    if let Some(min) = &self.min_version {
+      write!(f, " >= {}", min)?;
+    }
+    if let Some(max) = &self.max_version {
+      write!(f, " < {}", max)?;
+    }
+    if !self.architectures.is_empty() {
+      let architectures: Vec<String> = self.architectures.iter().map(|a| a.to_string()).collect();
+      write!(f, " ({})", architectures.join(", "))?;
+    }
+    for (fact, expected) in &self.facts {
+      if *expected {
+        write!(f, " [{}]", fact)?;
+      } else {
+        write!(f, " [not {}]", fact)?;
+      }
+    }
+    Ok(())
+  }
+}
+
 pub const WINDOWS_BASED_OS: &[OS] = &[OS(os_info::Type::Windows)];
 
 pub const MAC_BASED_OS: &[OS] = &[OS(os_info::Type::Macos)];
@@ -270,21 +548,415 @@ pub const DEBIAN_BASED_OS: &[OS] = &[
   OS(os_info::Type::Raspbian),
 ];
 
+pub const UBUNTU_BASED_OS: &[OS] = &[
+  OS(os_info::Type::Ubuntu),
+  OS(os_info::Type::Mint),
+  OS(os_info::Type::Pop),
+];
+
 pub const GENTOO_BASED_OS: &[OS] = &[OS(os_info::Type::Gentoo)];
 
 pub const ANDROID_BASED_OS: &[OS] = &[OS(os_info::Type::Android)];
 
-/// Complete machine information including OS and architecture.
+/// Default shell of the current user.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Shell {
+  Bash,
+  Zsh,
+  Fish,
+  /// Windows PowerShell or PowerShell Core (`pwsh`)
+  Pwsh,
+  /// Any other shell, by executable name
+  Other(String),
+  /// The shell could not be determined
+  Unknown,
+}
+
+impl Shell {
+  fn detect() -> Self {
+    if cfg!(windows) {
+      return Shell::Pwsh;
+    }
+    let Ok(path) = std::env::var("SHELL") else {
+      return Shell::Unknown;
+    };
+    let name = Path::new(&path)
+      .file_name()
+      .map(|n| n.to_string_lossy().to_string())
+      .unwrap_or_default();
+    match name.as_str() {
+      "bash" => Shell::Bash,
+      "zsh" => Shell::Zsh,
+      "fish" => Shell::Fish,
+      "pwsh" | "powershell" => Shell::Pwsh,
+      "" => Shell::Unknown,
+      _ => Shell::Other(name),
+    }
+  }
+}
+
+impl std::fmt::Display for Shell {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Shell::Bash => write!(f, "bash"),
+      Shell::Zsh => write!(f, "zsh"),
+      Shell::Fish => write!(f, "fish"),
+      Shell::Pwsh => write!(f, "powershell"),
+      Shell::Other(name) => write!(f, "{}", name),
+      Shell::Unknown => write!(f, "unknown"),
+    }
+  }
+}
+
+/// Graphical desktop environment of the current session.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Desktop {
+  Gnome,
+  Kde,
+  Xfce,
+  Cinnamon,
+  Mate,
+  Lxqt,
+  /// The Windows shell (Explorer)
+  Windows,
+  /// The macOS desktop (Aqua)
+  MacOS,
+  /// Any other desktop environment, by its XDG name
+  Other(String),
+  /// No graphical session, e.g. SSH, a TTY or a container
+  None,
+}
+
+impl Desktop {
+  fn detect() -> Self {
+    if cfg!(windows) {
+      return Desktop::Windows;
+    }
+    if cfg!(target_os = "macos") {
+      return Desktop::MacOS;
+    }
+
+    let current = std::env::var("XDG_CURRENT_DESKTOP")
+      .or_else(|_| std::env::var("DESKTOP_SESSION"))
+      .unwrap_or_default();
+    if current.is_empty() {
+      return Desktop::None;
+    }
+
+    // XDG_CURRENT_DESKTOP may hold a list such as "ubuntu:GNOME"
+    let names: Vec<String> = current.split(':').map(|n| n.to_lowercase()).collect();
+    let has = |wanted: &str| names.iter().any(|n| n.contains(wanted));
+    if has("gnome") || has("unity") {
+      Desktop::Gnome
+    } else if has("kde") || has("plasma") {
+      Desktop::Kde
+    } else if has("xfce") {
+      Desktop::Xfce
+    } else if has("cinnamon") {
+      Desktop::Cinnamon
+    } else if has("mate") {
+      Desktop::Mate
+    } else if has("lxqt") {
+      Desktop::Lxqt
+    } else {
+      Desktop::Other(current)
+    }
+  }
+}
+
+impl std::fmt::Display for Desktop {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Desktop::Gnome => write!(f, "gnome"),
+      Desktop::Kde => write!(f, "kde"),
+      Desktop::Xfce => write!(f, "xfce"),
+      Desktop::Cinnamon => write!(f, "cinnamon"),
+      Desktop::Mate => write!(f, "mate"),
+      Desktop::Lxqt => write!(f, "lxqt"),
+      Desktop::Windows => write!(f, "windows"),
+      Desktop::MacOS => write!(f, "macos"),
+      Desktop::Other(name) => write!(f, "{}", name),
+      Desktop::None => write!(f, "none"),
+    }
+  }
+}
+
+/// Administrator rights available to the tool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Privileges {
+  /// Running as root or as an elevated Administrator
+  Elevated,
+  /// Not elevated, but sudo (or a UAC prompt on Windows) can be used
+  Sudo,
+  /// No way to gain administrator rights
+  Unprivileged,
+}
+
+impl std::fmt::Display for Privileges {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Privileges::Elevated => write!(f, "elevated"),
+      Privileges::Sudo => write!(f, "sudo"),
+      Privileges::Unprivileged => write!(f, "unprivileged"),
+    }
+  }
+}
+
+/// A yes/no property of the machine that matchers and checks can query.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Fact {
+  /// Running inside the Windows Subsystem for Linux
+  Wsl,
+  /// Administrator rights are available, directly or through sudo
+  Admin,
+}
+
+impl std::fmt::Display for Fact {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Fact::Wsl => write!(f, "wsl"),
+      Fact::Admin => write!(f, "admin"),
+    }
+  }
+}
+
+/// Detected facts about the environment the tool runs in.
+///
+/// Use [`Facts::host`] (or [`Machine::facts`]) to get the facts of the
+/// current run; detection happens once and is cached.
+#[derive(Debug, Clone)]
+pub struct Facts {
+  /// Release codename, e.g. `jammy` or `bookworm`
+  pub codename: Option<String>,
+  /// Default shell of the current user
+  pub shell: Shell,
+  /// Desktop environment of the current session
+  pub desktop: Desktop,
+  /// Available administrator rights
+  pub privileges: Privileges,
+  /// Running inside WSL
+  pub wsl: bool,
+  /// Running inside a container
+  pub container: bool,
+  /// Running inside a virtual machine
+  pub virtual_machine: bool,
+}
+
+impl Facts {
+  /// Returns the facts of the current host, detecting them on first use.
+  pub fn host() -> &'static Facts {
+    static FACTS: OnceLock<Facts> = OnceLock::new();
+    FACTS.get_or_init(Facts::detect)
+  }
+
+  fn detect() -> Self {
+    let privileges = if is_elevated() {
+      Privileges::Elevated
+    } else if can_elevate() {
+      Privileges::Sudo
+    } else {
+      Privileges::Unprivileged
+    };
+
+    Self {
+      codename: detect_codename(),
+      shell: Shell::detect(),
+      desktop: Desktop::detect(),
+      privileges,
+      wsl: detect_wsl(),
+      container: detect_container(),
+      virtual_machine: detect_virtual_machine(),
+    }
+  }
+
+  /// Checks whether a fact holds on this machine.
+  pub fn has(&self, fact: &Fact) -> bool {
+    match fact {
+      Fact::Wsl => self.wsl,
+      Fact::Admin => self.privileges != Privileges::Unprivileged,
+    }
+  }
+
+  /// Returns the facts as labelled values for display.
+  pub fn entries(&self) -> Vec<(&'static str, String)> {
+    let yes_no = |value: bool| if value { i18n::yes() } else { i18n::no() }.to_string();
+    vec![
+      (
+        i18n::fact_codename(),
+        self.codename.clone().unwrap_or_else(|| i18n::fact_unknown().to_string()),
+      ),
+      (i18n::fact_shell(), self.shell.to_string()),
+      (i18n::fact_desktop(), self.desktop.to_string()),
+      (i18n::fact_privileges(), self.privileges.to_string()),
+      (i18n::fact_wsl(), yes_no(self.wsl)),
+      (i18n::fact_container(), yes_no(self.container)),
+      (i18n::fact_virtual_machine(), yes_no(self.virtual_machine)),
+    ]
+  }
+}
+
+/// Reads a `KEY=value` entry from `/etc/os-release`.
+fn os_release_value(key: &str) -> Option<String> {
+  let content = std::fs::read_to_string("/etc/os-release").ok()?;
+  content.lines().find_map(|line| {
+    let (name, value) = line.split_once('=')?;
+    (name == key).then(|| value.trim_matches('"').to_string())
+  })
+}
+
+fn detect_codename() -> Option<String> {
+  os_release_value("VERSION_CODENAME")
+    .or_else(|| os_release_value("UBUNTU_CODENAME"))
+    .filter(|codename| !codename.is_empty())
+    .or_else(|| get().codename().map(|codename| codename.to_string()))
+}
+
+fn detect_wsl() -> bool {
+  if !cfg!(target_os = "linux") {
+    return false;
+  }
+  std::env::var_os("WSL_DISTRO_NAME").is_some()
+    || Path::new("/proc/sys/fs/binfmt_misc/WSLInterop").exists()
+    || std::fs::read_to_string("/proc/version")
+      .map(|version| version.to_lowercase().contains("microsoft"))
+      .unwrap_or(false)
+}
+
+fn detect_container() -> bool {
+  if !cfg!(target_os = "linux") {
+    return false;
+  }
+  Path::new("/.dockerenv").exists()
+    || Path::new("/run/.containerenv").exists()
+    || std::env::var_os("container").is_some()
+    || std::fs::read_to_string("/proc/1/cgroup")
+      .map(|cgroup| {
+        ["docker", "kubepods", "containerd", "lxc", "podman"]
+          .iter()
+          .any(|marker| cgroup.contains(marker))
+      })
+      .unwrap_or(false)
+}
+
+fn detect_virtual_machine() -> bool {
+  if !cfg!(target_os = "linux") {
+    return false;
+  }
+  if let Ok(output) = std::process::Command::new("systemd-detect-virt").arg("--vm").output() {
+    let result = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    return output.status.success() && result != "none";
+  }
+
+  let product = std::fs::read_to_string("/sys/class/dmi/id/product_name").unwrap_or_default();
+  ["VirtualBox", "VMware", "KVM", "QEMU", "Virtual Machine", "Bochs"]
+    .iter()
+    .any(|marker| product.contains(marker))
+}
+
+/// Checks whether an executable can be found on PATH.
+pub(crate) fn command_exists(program: &str) -> bool {
+  let locator = if cfg!(windows) { "where" } else { "which" };
+  std::process::Command::new(locator)
+    .arg(program)
+    .output()
+    .map(|o| o.status.success())
+    .unwrap_or(false)
+}
+
+/// Checks whether the current process runs as root or an elevated Administrator.
+fn is_elevated() -> bool {
+  if cfg!(windows) {
+    // `net session` only succeeds in an elevated shell
+    std::process::Command::new("net")
+      .arg("session")
+      .output()
+      .map(|o| o.status.success())
+      .unwrap_or(false)
+  } else {
+    std::process::Command::new("id")
+      .arg("-u")
+      .output()
+      .map(|o| String::from_utf8_lossy(&o.stdout).trim() == "0")
+      .unwrap_or(false)
+  }
+}
+
+/// Checks whether a process that is not elevated can gain administrator
+/// rights: through a UAC prompt on Windows, or through sudo (passwordless or
+/// via membership in an admin group) elsewhere.
+fn can_elevate() -> bool {
+  if cfg!(windows) {
+    // Administrators run with a filtered token until they confirm a UAC
+    // prompt, but the token still lists the Administrators group (by its
+    // SID, which does not depend on the system language)
+    return std::process::Command::new("whoami")
+      .arg("/groups")
+      .output()
+      .map(|o| String::from_utf8_lossy(&o.stdout).contains("S-1-5-32-544"))
+      .unwrap_or(false);
+  }
+  if !command_exists("sudo") {
+    return false;
+  }
+
+  let passwordless = std::process::Command::new("sudo")
+    .args(["-n", "true"])
+    .output()
+    .map(|o| o.status.success())
+    .unwrap_or(false);
+  if passwordless {
+    return true;
+  }
+
+  std::process::Command::new("id")
+    .arg("-Gn")
+    .output()
+    .map(|o| {
+      String::from_utf8_lossy(&o.stdout)
+        .split_whitespace()
+        .any(|g| matches!(g, "sudo" | "wheel" | "admin"))
+    })
+    .unwrap_or(false)
+}
+
+/// Complete machine information including OS, version and architecture.
 ///
 /// This struct represents all the detected information about the current
 /// machine that packages need to make installation decisions.
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Machine {
   /// Detected operating system
   #[serde(default)]
   pub(crate) os: OS,
+  /// Detected operating system version
+  #[serde(default)]
+  pub(crate) version: OsVersion,
   /// Detected CPU architecture
   #[serde(default)]
   pub(crate) arch: Architectures,
 }
+
+impl Machine {
+  /// Detects the current machine from scratch.
+  ///
+  /// Unlike the values stored in the configuration, this always reflects the
+  /// system the tool is running on right now.
+  pub fn detect() -> Self {
+    Self::default()
+  }
+
+  /// Returns the detected facts of the host the tool runs on.
+  ///
+  /// Facts always describe the real host, even when the OS or architecture
+  /// was overridden.
+  pub fn facts(&self) -> &'static Facts {
+    Facts::host()
+  }
+}
+
+impl std::fmt::Display for Machine {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{} {} ({})", self.os, self.version, self.arch)
+  }
+}
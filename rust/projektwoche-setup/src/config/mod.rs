@@ -13,18 +13,41 @@
 //!
 //! ## Configuration Storage
 //!
-//! Configuration is stored in platform-specific locations:
-//! - **Windows**: `%APPDATA%\prowo-setup\config.toml`
-//! - **macOS**: `~/Library/Application Support/prowo-setup/config.toml`
-//! - **Linux**: `~/.config/prowo-setup/config.toml`
+//! Settings are merged from several layers (see [`settings`] for details):
+//! - **System**: `/etc/prowo-setup/config.toml` (Linux),
+//!   `/Library/Application Support/prowo-setup/config.toml` (macOS) or
+//!   `%ProgramData%\prowo-setup\config.toml` (Windows), e.g. for lab-wide
+//!   settings such as the proxy (see [`network`]) or the allowed bundles
+//! - **User**: `~/.config/prowo-setup/config.toml` (Linux),
+//!   `~/Library/Application Support/prowo-setup/config.toml` (macOS) or
+//!   `%APPDATA%\prowo-setup\config.toml` (Windows)
+//! - **Project**: `.prowo-setup.toml` in the current directory or a parent
+//! - **Environment**: `PROWO_*` variables, e.g. `PROWO_LOG_LEVEL=Debug`
+//!
+//! Every field can be read and changed with the `config get/set/unset/list`
+//! commands; `config list --show-origin` shows which layer a value comes from.
+
+//! ## Machine Detection
+//!
+//! The stored machine information is only a record of the last detection.
+//! Before a command acts on the machine, it is detected again and compared
+//! with the stored values (see [`reconcile_machine`]), so a configuration copied from
+//! another PC or an OS upgrade does not lead to installing for the wrong
+//! system. Deliberate overrides are marked with `machine_override` and are
+//! kept as they are.
 
 pub mod interactive;
+pub mod logging;
 pub mod machine;
+pub mod network;
+pub mod settings;
 
-use crate::logger::LogLevel;
-use confy::ConfyError;
+use crate::i18n;
+use prowo_log::{LogLevel, Logger};
 use serde::{Deserialize, Serialize};
-use std::process::exit;
+use settings::{Migration, SettingsStore};
+use std::collections::BTreeMap;
+use toml::Value;
 
 /// Main configuration structure containing machine information.
 ///
@@ -35,40 +58,104 @@ pub struct Config {
   /// Machine-specific information (OS, architecture)
   #[serde(default)]
   pub(crate) machine: machine::Machine,
+  /// Whether `machine` was set by hand and should not be re-detected
+  #[serde(default)]
+  pub(crate) machine_override: bool,
+  /// Language of messages, prompts and help; follows the system by default
+  #[serde(default)]
+  pub language: i18n::Language,
   /// Log level configuration
   #[serde(default = "default_log_level")]
   pub log_level: LogLevel,
+  /// User-defined values for placeholders in instruction strings
+  #[serde(default)]
+  pub(crate) variables: BTreeMap<String, String>,
+  /// Bundles that may be installed, by CLI name; empty allows every bundle
+  #[serde(default)]
+  pub(crate) allowed_bundles: Vec<String>,
+  /// Proxy, CA bundle and mirror settings for all downloads
+  #[serde(default)]
+  pub(crate) network: network::NetworkConfig,
+  /// Answers of the configuration wizard, which shape the bundles
+  #[serde(default)]
+  pub(crate) preferences: interactive::WizardConfig,
+  /// Format, location and rotation of the per-run log files
+  #[serde(default)]
+  pub(crate) logging: logging::LoggingConfig,
+}
+
+impl Config {
+  /// Returns `true` if the bundle with the given CLI name may be used.
+  pub fn allows_bundle(&self, name: &str) -> bool {
+    self.allowed_bundles.is_empty()
+      || self
+        .allowed_bundles
+        .iter()
+        .any(|allowed| allowed.eq_ignore_ascii_case(name))
+  }
+}
+
+/// Application name under which the configuration files are stored.
+pub const APP_NAME: &str = "prowo-setup";
+
+/// Prefix of environment variables overriding settings, e.g. `PROWO_LOG_LEVEL`.
+pub const ENV_PREFIX: &str = "PROWO";
+
+/// Upgrades of the user file, indexed by the schema version they start from.
+static MIGRATIONS: [Migration; 1] = [unpin_defaults];
+
+/// Schema 0 to 1: files written before configuration layers existed stored
+/// every setting. Values equal to the defaults are removed so the system
+/// configuration can take effect; the machine information is kept.
+fn unpin_defaults(value: &mut Value) {
+  let Ok(Value::Table(defaults)) = Value::try_from(Config::default()) else {
+    return;
+  };
+  if let Some(table) = value.as_table_mut() {
+    table.retain(|key, current| {
+      key == "machine" || key == "machine_override" || defaults.get(key) != Some(current)
+    });
+  }
+}
+
+/// Returns the store that reads and writes the configuration layers.
+pub fn store() -> SettingsStore {
+  SettingsStore::new(APP_NAME)
+    .with_env_prefix(ENV_PREFIX)
+    .with_migrations(&MIGRATIONS)
 }
 
 fn default_log_level() -> LogLevel {
   LogLevel::Info
 }
 
-/// Loads or creates the application configuration.
+/// Loads the application configuration from all layers.
 ///
-/// This function attempts to load existing configuration from disk,
-/// or creates a new configuration with detected system information.
+/// On the first start, the detected machine information is recorded in the
+/// user file so later starts can notice when it no longer matches.
 ///
 /// # Returns
 ///
-/// Returns the loaded/created configuration on success.
+/// Returns the merged configuration on success.
 ///
 /// # Errors
 ///
-/// If configuration loading fails, the function prints an error message
-/// and exits the program with code 1.
+/// Returns an error naming the file or environment variable at fault if a
+/// layer cannot be read or contains invalid values. The `config path`,
+/// `config edit` and `config reset` commands still work in that case.
 pub fn use_config() -> Result<Config, Box<dyn std::error::Error>> {
-  let config: Result<Config, ConfyError> = confy::load("prowo-setup", "config");
-  match config {
-    Ok(config) => Ok(config),
-    Err(e) => {
-      eprintln!("Unbekannter Fehler beim Laden der Konfiguration: {}", e);
-      exit(1)
-    }
+  let store = store();
+  let config: Config = store.load()?;
+  if !store.user_defines("machine")? {
+    store.persist(&config, "machine")?;
   }
+  Ok(config)
 }
 
-/// Saves the configuration to disk.
+/// Saves the changes made to the configuration to the user file.
+///
+/// Only values that are set in the user file or differ from the system
+/// configuration are written, so lab-wide settings stay in effect.
 ///
 /// # Arguments
 ///
@@ -78,7 +165,46 @@ pub fn use_config() -> Result<Config, Box<dyn std::error::Error>> {
 ///
 /// Returns Ok(()) on success, or an error if the save operation fails.
 pub fn save_config(config: &Config) -> Result<(), Box<dyn std::error::Error>> {
-  confy::store("prowo-setup", "config", config)?;
+  store().save(config)
+}
+
+/// Compares the stored machine information with a fresh detection.
+///
+/// If they differ, a warning is logged and the user is asked whether the
+/// stored values should be replaced. Accepting updates and saves the
+/// configuration; declining keeps the stored values for this run.
+/// Machines marked as overridden are used without detection.
+///
+/// # Arguments
+///
+/// * `config` - Loaded configuration, updated in place when accepted
+/// * `logger` - Logger used to report the mismatch
+///
+/// # Errors
+///
+/// Returns an error if the updated configuration cannot be saved.
+pub fn reconcile_machine(
+  config: &mut Config,
+  logger: &Logger,
+) -> Result<(), Box<dyn std::error::Error>> {
+  if config.machine_override {
+    logger.info(i18n::machine_from_config(&config.machine));
+    return Ok(());
+  }
+
+  let detected = machine::Machine::detect();
+  if detected == config.machine {
+    return Ok(());
+  }
+
+  logger.warn(i18n::machine_mismatch(&config.machine, &detected));
+  if interactive::confirm("machine.update", i18n::machine_update_confirm(), true) {
+    config.machine = detected;
+    save_config(config)?;
+    logger.info(i18n::machine_updated(&config.machine));
+  } else {
+    logger.warn(i18n::machine_kept());
+  }
   Ok(())
 }
 
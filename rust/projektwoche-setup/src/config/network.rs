@@ -0,0 +1,123 @@
+//! # Network Settings
+//!
+//! School and company networks often only reach the internet through an HTTP
+//! proxy that intercepts TLS with its own certificate authority. These
+//! settings describe such a network once, and the tool passes them on to
+//! every program it starts:
+//!
+//! - **Environment**: `HTTP(S)_PROXY`, `NO_PROXY` and the CA variables that
+//!   curl, git, npm, Node.js and pip understand
+//! - **Package managers**: proxy and CA options on the command line of apt,
+//!   dnf/yum and Chocolatey, which ignore the environment or lose it when run
+//!   through sudo (winget follows the Windows proxy settings)
+//! - **Mirrors**: URL prefixes that are rewritten before a download, e.g. to
+//!   fetch Node.js from a local mirror. Tools that download on their own, like
+//!   nvm, get the mirror through their environment variable (see
+//!   [`MIRROR_VARIABLES`])
+//!
+//! ## Example
+//!
+//! ```toml
+//! [network]
+//! proxy = "http://proxy.school.lan:3128"
+//! no_proxy = "localhost,.school.lan"
+//! ca_bundle = "/etc/ssl/certs/school-bundle.pem"
+//!
+//! [network.mirrors]
+//! "https://nodejs.org/dist" = "http://mirror.school.lan/nodejs"
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// Environment variables that point tools at a download server, and the
+/// server's default URL.
+///
+/// If a mirror prefix matches the default URL, the variable is set to the
+/// rewritten URL.
+pub const MIRROR_VARIABLES: &[(&str, &str)] = &[
+  // nvm and nvm-windows
+  ("NVM_NODEJS_ORG_MIRROR", "https://nodejs.org/dist"),
+  // node-gyp, for native npm packages
+  ("NODEJS_ORG_MIRROR", "https://nodejs.org/dist"),
+];
+
+/// Proxy, certificate and mirror settings applied to network operations.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NetworkConfig {
+  /// Proxy URL for HTTP and HTTPS, e.g. `http://proxy:3128`
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub proxy: Option<String>,
+  /// Comma-separated hosts and domains that bypass the proxy
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub no_proxy: Option<String>,
+  /// PEM file with the certificate authorities to trust
+  ///
+  /// Several tools replace their built-in store with this file, so it should
+  /// contain the public CAs in addition to the network's own CA.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub ca_bundle: Option<PathBuf>,
+  /// URL prefixes and their replacements, e.g. a local mirror
+  #[serde(default)]
+  pub mirrors: BTreeMap<String, String>,
+}
+
+impl NetworkConfig {
+  /// Returns the environment variables that make child processes use these settings.
+  pub fn environment(&self) -> Vec<(&'static str, String)> {
+    let mut env = Vec::new();
+
+    if let Some(proxy) = &self.proxy {
+      for name in [
+        "HTTP_PROXY",
+        "HTTPS_PROXY",
+        "http_proxy",
+        "https_proxy",
+        "npm_config_proxy",
+        "npm_config_https_proxy",
+      ] {
+        env.push((name, proxy.clone()));
+      }
+    }
+    if let Some(no_proxy) = &self.no_proxy {
+      for name in ["NO_PROXY", "no_proxy", "npm_config_noproxy"] {
+        env.push((name, no_proxy.clone()));
+      }
+    }
+    if let Some(ca_bundle) = &self.ca_bundle {
+      let path = ca_bundle.display().to_string();
+      for name in [
+        "CURL_CA_BUNDLE",
+        "GIT_SSL_CAINFO",
+        "NODE_EXTRA_CA_CERTS",
+        "npm_config_cafile",
+        "REQUESTS_CA_BUNDLE",
+        "PIP_CERT",
+      ] {
+        env.push((name, path.clone()));
+      }
+    }
+    for (name, url) in MIRROR_VARIABLES {
+      let rewritten = self.rewrite(url);
+      if rewritten != *url {
+        env.push((name, rewritten));
+      }
+    }
+
+    env
+  }
+
+  /// Rewrites `url` with the longest matching mirror prefix.
+  ///
+  /// Strings that do not start with a configured prefix are returned unchanged.
+  pub fn rewrite(&self, url: &str) -> String {
+    self
+      .mirrors
+      .iter()
+      .filter(|(from, _)| url.starts_with(from.as_str()))
+      .max_by_key(|(from, _)| from.len())
+      .map(|(from, to)| format!("{}{}", to, &url[from.len()..]))
+      .unwrap_or_else(|| url.to_string())
+  }
+}
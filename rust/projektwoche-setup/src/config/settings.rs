@@ -0,0 +1,790 @@
+//! # Configuration Settings
+//!
+//! Layered configuration and generic `config` subcommands shared by all
+//! Projektwoche command-line tools. Keys are derived from the serde fields of
+//! a configuration struct, so new fields can be read and changed without
+//! writing any additional CLI code.
+//!
+//! ## Layers
+//!
+//! Settings are merged from several sources; later layers win:
+//!
+//! 1. **Defaults** of the configuration struct
+//! 2. **System**: `/etc/<app>/config.toml`, `/Library/Application Support/<app>/config.toml`
+//!    on macOS or `%ProgramData%\<app>\config.toml` on Windows, maintained by admins
+//! 3. **User**: the per-user file managed by `confy`, changed by `config set`
+//! 4. **Project**: a `.<app>.toml` file in the current directory or one of its parents
+//! 5. **Environment**: variables such as `PROWO_LOG_LEVEL`; `__` separates
+//!    nested keys, e.g. `PROWO_VARIABLES__DEB_ARCH`
+//!
+//! Only the user file is ever written. It keeps just the values that were set
+//! there, so settings from the system file stay in effect unless a user
+//! overrides them. `config list --show-origin` shows where each value comes from.
+//!
+//! Nested fields are addressed with dotted keys, e.g. `machine.os` or
+//! `variables.deb_arch`. Parts that contain dots are quoted, e.g.
+//! `network.mirrors."https://nodejs.org/dist"`. Every change is validated by deserializing the
+//! complete configuration again before it is written, so invalid values and
+//! unknown keys are rejected with an explanation.
+//!
+//! ## Schema Migration
+//!
+//! The user file records the `schema_version` it was written with. Files from
+//! older versions are upgraded on load by the store's migrations, each of
+//! which turns version `n` into version `n + 1`.
+//!
+//! ## Usage Example
+//!
+//! ```rust,ignore
+//! #[derive(Subcommand)]
+//! enum Commands {
+//!   Config {
+//!     #[clap(subcommand)]
+//!     action: SettingsCommand,
+//!   },
+//! }
+//!
+//! let store = SettingsStore::new("prowo-setup").with_env_prefix("PROWO");
+//! store.run::<Config>(&action)?;
+//! ```
+
+use super::interactive::confirm;
+use crate::i18n;
+use clap::Subcommand;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use toml::Value;
+use toml::map::Map;
+
+/// Key under which the user file records its schema version.
+const SCHEMA_VERSION_KEY: &str = "schema_version";
+
+/// Upgrades a user configuration from one schema version to the next.
+pub type Migration = fn(&mut Value);
+
+/// Subcommands for reading and changing configuration values.
+#[derive(Subcommand, Debug, Clone)]
+pub enum SettingsCommand {
+  /// Print the value of a setting
+  Get {
+    /// Setting to read, e.g. `log_level` or `machine.os`
+    key: String,
+    /// Also print which layer the value comes from
+    #[clap(long)]
+    show_origin: bool,
+  },
+  /// Change the value of a setting in the user configuration
+  Set {
+    /// Setting to change, e.g. `log_level` or `variables.deb_arch`
+    key: String,
+    /// New value, validated before it is saved
+    value: String,
+  },
+  /// Remove a setting from the user configuration
+  ///
+  /// The value of the system configuration or the default applies again.
+  Unset {
+    /// Setting to remove
+    key: String,
+  },
+  /// List all settings with their current values
+  List {
+    /// Also print which layer every value comes from
+    #[clap(long)]
+    show_origin: bool,
+  },
+  /// Print the path of the user configuration file
+  Path {
+    /// Print the system, user and project files instead
+    #[clap(long)]
+    all: bool,
+  },
+  /// Reset the user configuration to its defaults
+  ///
+  /// System-wide and project settings stay in effect. Use `--yes` to skip
+  /// the confirmation.
+  Reset,
+  /// Open the user configuration file in $VISUAL or $EDITOR
+  ///
+  /// The file is validated after the editor exits.
+  Edit,
+}
+
+/// The layer a configuration value comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Origin {
+  /// Built-in default of the configuration struct
+  Default,
+  /// Machine-wide configuration file
+  System(PathBuf),
+  /// Configuration file of the current user
+  User(PathBuf),
+  /// Configuration file of the current project directory
+  Project(PathBuf),
+  /// Environment variable
+  Environment(String),
+}
+
+impl std::fmt::Display for Origin {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Origin::Default => write!(f, "default"),
+      Origin::System(path) => write!(f, "system:{}", path.display()),
+      Origin::User(path) => write!(f, "user:{}", path.display()),
+      Origin::Project(path) => write!(f, "project:{}", path.display()),
+      Origin::Environment(name) => write!(f, "env:{}", name),
+    }
+  }
+}
+
+/// One source of settings, already parsed.
+struct Layer {
+  origin: Origin,
+  value: Value,
+}
+
+/// Location and layering rules of a tool's configuration.
+#[derive(Debug, Clone, Copy)]
+pub struct SettingsStore {
+  /// Application name used to find the configuration files
+  app: &'static str,
+  /// Prefix of environment variable overrides, e.g. `PROWO`
+  env_prefix: Option<&'static str>,
+  /// Migrations of the user file, indexed by the version they upgrade from
+  migrations: &'static [Migration],
+}
+
+impl SettingsStore {
+  /// Creates a store for the configuration of `app`.
+  ///
+  /// # Arguments
+  ///
+  /// * `app` - Application name, e.g. `prowo-setup`
+  pub fn new(app: &'static str) -> Self {
+    Self {
+      app,
+      env_prefix: None,
+      migrations: &[],
+    }
+  }
+
+  /// Reads overrides from environment variables starting with `prefix_`.
+  ///
+  /// # Returns
+  ///
+  /// Returns `self` for method chaining.
+  pub fn with_env_prefix(mut self, prefix: &'static str) -> Self {
+    self.env_prefix = Some(prefix);
+    self
+  }
+
+  /// Sets the migrations applied to outdated user files.
+  ///
+  /// The current schema version is the number of migrations.
+  ///
+  /// # Returns
+  ///
+  /// Returns `self` for method chaining.
+  pub fn with_migrations(mut self, migrations: &'static [Migration]) -> Self {
+    self.migrations = migrations;
+    self
+  }
+
+  /// Returns the path of the user configuration file.
+  pub fn path(&self) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    Ok(confy::get_configuration_file_path(self.app, "config")?)
+  }
+
+  /// Returns the path of the machine-wide configuration file.
+  pub fn system_path(&self) -> PathBuf {
+    if cfg!(windows) {
+      let program_data =
+        std::env::var("ProgramData").unwrap_or_else(|_| "C:\\ProgramData".to_string());
+      Path::new(&program_data).join(self.app).join("config.toml")
+    } else if cfg!(target_os = "macos") {
+      Path::new("/Library/Application Support")
+        .join(self.app)
+        .join("config.toml")
+    } else {
+      Path::new("/etc").join(self.app).join("config.toml")
+    }
+  }
+
+  /// Returns the project configuration file of the current directory or
+  /// the nearest parent that has one.
+  pub fn project_path(&self) -> Option<PathBuf> {
+    let file_name = format!(".{}.toml", self.app);
+    let current = std::env::current_dir().ok()?;
+    current
+      .ancestors()
+      .map(|dir| dir.join(&file_name))
+      .find(|path| path.is_file())
+  }
+
+  /// Loads the merged configuration of all layers.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error naming the file or variable if a layer cannot be read
+  /// or contains invalid values.
+  pub fn load<T>(&self) -> Result<T, Box<dyn std::error::Error>>
+  where
+    T: Serialize + DeserializeOwned + Default,
+  {
+    let (merged, _) = self.merge::<T>(None)?;
+    Ok(deserialize(&merged)?)
+  }
+
+  /// Writes the changes made to a loaded configuration to the user file.
+  ///
+  /// The user file is rewritten from `config`: a value is written if it is
+  /// already set in the user file or differs from the layers below it, and
+  /// entries that `config` no longer contains, such as removed map keys, are
+  /// dropped. Values that come from the project file or the environment are
+  /// never copied into the user file; the user's own value is kept instead.
+  pub fn save<T>(&self, config: &T) -> Result<(), Box<dyn std::error::Error>>
+  where
+    T: Serialize + DeserializeOwned + Default,
+  {
+    let layers = self.layers::<T>()?;
+    let user_path = self.path()?;
+    let existing = self.read_user()?.unwrap_or_else(empty_table);
+    let lower = merge_layers(layers.iter().filter(|layer| is_below_user(&layer.origin)));
+    let higher = merge_layers(layers.iter().filter(|layer| is_above_user(&layer.origin)));
+
+    let mut user = empty_table();
+    let mut entries = Vec::new();
+    flatten("", &to_value(config)?, &mut entries);
+    for (key, value) in entries {
+      let own = lookup(&existing, &key);
+      if lookup(&higher, &key) == Some(&value) {
+        if let Some(own) = own {
+          insert(&mut user, &key, own.clone())?;
+        }
+        continue;
+      }
+      if own.is_some() || lookup(&lower, &key) != Some(&value) {
+        insert(&mut user, &key, value)?;
+      }
+    }
+    self.write_user(&user_path, user)
+  }
+
+  /// Stores a value of `config` in the user file even if it matches the
+  /// layers below, e.g. to record machine information on the first start.
+  pub fn persist<T: Serialize>(&self, config: &T, key: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let value = to_value(config)?;
+    let new = lookup(&value, key)
+      .cloned()
+      .ok_or_else(|| i18n::unknown_setting(key))?;
+    let mut user = self.read_user()?.unwrap_or_else(empty_table);
+    insert(&mut user, key, new)?;
+    self.write_user(&self.path()?, user)
+  }
+
+  /// Returns `true` if the user file sets `key`.
+  pub fn user_defines(&self, key: &str) -> Result<bool, Box<dyn std::error::Error>> {
+    Ok(
+      self
+        .read_user()?
+        .is_some_and(|user| lookup(&user, key).is_some()),
+    )
+  }
+
+  /// Executes a settings subcommand against the configuration type `T`.
+  ///
+  /// Results are printed to stdout so they can be used in scripts.
+  /// `path`, `reset` and `edit` also work while the configuration is invalid.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if a key is unknown, a value is invalid, or the
+  /// configuration file cannot be read or written.
+  pub fn run<T>(&self, command: &SettingsCommand) -> Result<(), Box<dyn std::error::Error>>
+  where
+    T: Serialize + DeserializeOwned + Default,
+  {
+    match command {
+      SettingsCommand::Get { key, show_origin } => {
+        let (merged, origins) = self.merge::<T>(None)?;
+        let current = to_value(&deserialize::<T>(&merged)?)?;
+        let value = lookup(&current, key).ok_or_else(|| unknown_key(key, &current))?;
+        match origins.get(key) {
+          Some(origin) if *show_origin => println!("{}\t{}", origin, display_value(value)),
+          _ => println!("{}", display_value(value)),
+        }
+      }
+      SettingsCommand::Set { key, value } => {
+        let (merged, _) = self.merge::<T>(None)?;
+        let existing = lookup(&merged, key);
+        if let Some(Value::Table(_)) = existing {
+          return Err(i18n::setting_group(key).into());
+        }
+        let parsed = parse_value(existing, value);
+        let mut user = self.read_user()?.unwrap_or_else(empty_table);
+        insert(&mut user, key, parsed.clone())?;
+
+        // Unknown fields are dropped by serde, so check the key survived
+        let (merged, origins) = self.merge::<T>(Some(&user))
+          .map_err(|e| i18n::invalid_value(key, e))?;
+        let saved = to_value(&validate::<T>(&merged, key)?)?;
+        if lookup(&saved, key).is_none() {
+          return Err(unknown_key(key, &saved));
+        }
+        self.write_user(&self.path()?, user)?;
+        println!("{} = {}", key, display_value(&parsed));
+        if let Some(origin) = origins.get(key).filter(|origin| is_above_user(origin)) {
+          println!("{}", i18n::value_overridden(origin));
+        }
+      }
+      SettingsCommand::Unset { key } => {
+        let mut user = self.read_user()?.unwrap_or_else(empty_table);
+        if lookup(&user, key).is_none() {
+          let (merged, origins) = self.merge::<T>(None)?;
+          return Err(match origins.get(key) {
+            Some(origin) => i18n::not_in_user_config(key, origin).into(),
+            None => unknown_key(key, &merged),
+          });
+        }
+        remove(&mut user, key);
+
+        let (merged, origins) = self.merge::<T>(Some(&user))?;
+        let updated = validate::<T>(&merged, key)?;
+        self.write_user(&self.path()?, user)?;
+        match (lookup(&to_value(&updated)?, key), origins.get(key)) {
+          (Some(value), Some(origin)) => println!("{}", i18n::value_from(key, display_value(value), origin)),
+          _ => println!("{}", i18n::value_removed(key)),
+        }
+      }
+      SettingsCommand::List { show_origin } => {
+        let (merged, origins) = self.merge::<T>(None)?;
+        let current = to_value(&deserialize::<T>(&merged)?)?;
+        let mut entries = Vec::new();
+        flatten("", &current, &mut entries);
+        for (key, value) in entries {
+          match origins.get(&key) {
+            Some(origin) if *show_origin => println!("{}\t{} = {}", origin, key, value),
+            _ => println!("{} = {}", key, value),
+          }
+        }
+      }
+      SettingsCommand::Path { all } => {
+        if *all {
+          let project = self.project_path();
+          let files = [
+            ("system", Some(self.system_path())),
+            ("user", Some(self.path()?)),
+            ("project", project),
+          ];
+          for (name, path) in files {
+            match path {
+              Some(path) if path.exists() => println!("{:<8} {}", name, path.display()),
+              Some(path) => println!("{:<8} {} {}", name, path.display(), i18n::file_missing()),
+              None => println!("{:<8} {}", name, i18n::file_none()),
+            }
+          }
+        } else {
+          println!("{}", self.path()?.display());
+        }
+      }
+      SettingsCommand::Reset => {
+        if !confirm("config.reset", i18n::reset_confirm(), false) {
+          println!("{}", i18n::reset_cancelled());
+          return Ok(());
+        }
+        self.write_user(&self.path()?, empty_table())?;
+        println!("{}", i18n::reset_done());
+      }
+      SettingsCommand::Edit => {
+        let path = self.path()?;
+        if !path.exists() {
+          self.write_user(&path, empty_table())?;
+        }
+        open_editor(&path)?;
+
+        self.load::<T>().map_err(i18n::edit_fix_hint)?;
+        println!("{}", i18n::config_file_saved());
+      }
+    }
+    Ok(())
+  }
+
+  /// Reads all layers, lowest precedence first.
+  fn layers<T>(&self) -> Result<Vec<Layer>, Box<dyn std::error::Error>>
+  where
+    T: Serialize + Default,
+  {
+    let mut layers = vec![Layer {
+      origin: Origin::Default,
+      value: to_value(&T::default())?,
+    }];
+
+    let system = self.system_path();
+    if let Some(value) = read_file(&system)? {
+      layers.push(Layer {
+        origin: Origin::System(system),
+        value,
+      });
+    }
+    if let Some(value) = self.read_user()? {
+      layers.push(Layer {
+        origin: Origin::User(self.path()?),
+        value,
+      });
+    }
+    if let Some(project) = self.project_path()
+      && let Some(value) = read_file(&project)?
+    {
+      layers.push(Layer {
+        origin: Origin::Project(project),
+        value,
+      });
+    }
+
+    let below = merge_layers(layers.iter());
+    layers.extend(self.env_layers(&below));
+    Ok(layers)
+  }
+
+  /// Merges all layers, optionally replacing the user file's content, and
+  /// records the origin of every value.
+  ///
+  /// Each file is validated on its own so errors name the file at fault.
+  fn merge<T>(
+    &self,
+    user: Option<&Value>,
+  ) -> Result<(Value, BTreeMap<String, Origin>), Box<dyn std::error::Error>>
+  where
+    T: Serialize + DeserializeOwned + Default,
+  {
+    let mut layers = self.layers::<T>()?;
+    if let Some(user) = user {
+      let user_layer = Layer {
+        origin: Origin::User(self.path()?),
+        value: user.clone(),
+      };
+      match layers.iter().position(|layer| matches!(layer.origin, Origin::User(_))) {
+        Some(index) => layers[index] = user_layer,
+        None => {
+          let index = layers
+            .iter()
+            .position(|layer| is_above_user(&layer.origin))
+            .unwrap_or(layers.len());
+          layers.insert(index, user_layer);
+        }
+      }
+    }
+
+    let mut merged = empty_table();
+    let mut origins = BTreeMap::new();
+    for layer in &layers {
+      merge_into(&mut merged, &layer.value);
+      let mut entries = Vec::new();
+      flatten("", &layer.value, &mut entries);
+      for (key, _) in entries {
+        origins.insert(key, layer.origin.clone());
+      }
+      deserialize::<T>(&merged)
+        .map_err(|e| i18n::invalid_configuration(&layer.origin, e))?;
+    }
+    Ok((merged, origins))
+  }
+
+  /// Reads environment overrides for keys that exist in the lower layers.
+  fn env_layers(&self, below: &Value) -> Vec<Layer> {
+    let Some(prefix) = self.env_prefix else {
+      return Vec::new();
+    };
+    let prefix = format!("{}_", prefix);
+
+    let mut variables: Vec<(String, String)> = std::env::vars()
+      .filter(|(name, _)| name.starts_with(&prefix))
+      .collect();
+    variables.sort();
+
+    variables
+      .into_iter()
+      .filter_map(|(name, raw)| {
+        let key = name[prefix.len()..].to_lowercase().replace("__", ".");
+        // Only known settings or entries of a known map, e.g. `variables.x`
+        let known = lookup(below, &key).is_some()
+          || key
+            .rsplit_once('.')
+            .is_some_and(|(parent, _)| matches!(lookup(below, parent), Some(Value::Table(_))));
+        if !known {
+          return None;
+        }
+
+        let mut value = empty_table();
+        insert(&mut value, &key, parse_value(lookup(below, &key), &raw)).ok()?;
+        Some(Layer {
+          origin: Origin::Environment(name),
+          value,
+        })
+      })
+      .collect()
+  }
+
+  /// Reads the user file, upgrading it to the current schema version.
+  ///
+  /// The upgrade only happens in memory; the file is rewritten in the new
+  /// schema the next time a setting is saved.
+  fn read_user(&self) -> Result<Option<Value>, Box<dyn std::error::Error>> {
+    let path = self.path()?;
+    let Some(mut value) = read_file(&path)? else {
+      return Ok(None);
+    };
+
+    let version = match value.get(SCHEMA_VERSION_KEY) {
+      Some(Value::Integer(version)) => usize::try_from(*version).unwrap_or(0),
+      _ => 0,
+    };
+    if version < self.migrations.len() {
+      for migration in &self.migrations[version..] {
+        migration(&mut value);
+      }
+    }
+
+    if let Some(table) = value.as_table_mut() {
+      table.remove(SCHEMA_VERSION_KEY);
+    }
+    Ok(Some(value))
+  }
+
+  /// Writes the user file, recording the current schema version.
+  ///
+  /// The content goes to a temporary file next to it first, which then
+  /// replaces the file, so an interrupted write or a full disk never leaves
+  /// a truncated configuration behind.
+  fn write_user(&self, path: &Path, mut value: Value) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(table) = value.as_table_mut() {
+      table.remove(SCHEMA_VERSION_KEY);
+      if !self.migrations.is_empty() {
+        table.insert(
+          SCHEMA_VERSION_KEY.to_string(),
+          Value::Integer(self.migrations.len() as i64),
+        );
+      }
+    }
+    if let Some(parent) = path.parent() {
+      std::fs::create_dir_all(parent)?;
+    }
+    let content = toml::to_string_pretty(&value)?;
+
+    let mut partial = path.as_os_str().to_owned();
+    partial.push(".part");
+    let partial = PathBuf::from(partial);
+    let written = std::fs::write(&partial, content).and_then(|_| std::fs::rename(&partial, path));
+    if written.is_err() {
+      let _ = std::fs::remove_file(&partial);
+    }
+    Ok(written?)
+  }
+}
+
+fn is_below_user(origin: &Origin) -> bool {
+  matches!(origin, Origin::Default | Origin::System(_))
+}
+
+fn is_above_user(origin: &Origin) -> bool {
+  matches!(origin, Origin::Project(_) | Origin::Environment(_))
+}
+
+fn empty_table() -> Value {
+  Value::Table(Map::new())
+}
+
+/// Reads a TOML file, returning `None` if it does not exist.
+fn read_file(path: &Path) -> Result<Option<Value>, Box<dyn std::error::Error>> {
+  let content = match std::fs::read_to_string(path) {
+    Ok(content) => content,
+    Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+    Err(e) => return Err(i18n::cannot_read(path.display(), e).into()),
+  };
+  let table: toml::Table = content
+    .parse()
+    .map_err(|e| i18n::invalid_configuration(path.display(), e))?;
+  Ok(Some(Value::Table(table)))
+}
+
+fn merge_layers<'a>(layers: impl Iterator<Item = &'a Layer>) -> Value {
+  let mut merged = empty_table();
+  for layer in layers {
+    merge_into(&mut merged, &layer.value);
+  }
+  merged
+}
+
+/// Merges `overlay` into `base`; tables are merged key by key.
+fn merge_into(base: &mut Value, overlay: &Value) {
+  match (base, overlay) {
+    (Value::Table(base), Value::Table(overlay)) => {
+      for (key, value) in overlay {
+        match base.get_mut(key) {
+          Some(existing) => merge_into(existing, value),
+          None => {
+            base.insert(key.clone(), value.clone());
+          }
+        }
+      }
+    }
+    (base, overlay) => *base = overlay.clone(),
+  }
+}
+
+fn to_value<T: Serialize>(config: &T) -> Result<Value, Box<dyn std::error::Error>> {
+  Ok(Value::try_from(config)?)
+}
+
+fn deserialize<T: DeserializeOwned>(value: &Value) -> Result<T, String> {
+  value.clone().try_into().map_err(|e: toml::de::Error| e.message().to_string())
+}
+
+/// Deserializes a changed configuration, naming the key if it is rejected.
+fn validate<T: DeserializeOwned>(value: &Value, key: &str) -> Result<T, Box<dyn std::error::Error>> {
+  deserialize(value).map_err(|e| i18n::invalid_value(key, e).into())
+}
+
+fn unknown_key(key: &str, config: &Value) -> Box<dyn std::error::Error> {
+  let mut entries = Vec::new();
+  flatten("", config, &mut entries);
+  let keys: Vec<String> = entries.into_iter().map(|(key, _)| key).collect();
+  i18n::unknown_setting_available(key, keys.join(", ")).into()
+}
+
+/// Splits a dotted key into its parts; parts containing dots can be quoted,
+/// e.g. `network.mirrors."https://nodejs.org/dist"`.
+fn split_key(key: &str) -> Vec<String> {
+  let mut parts = vec![String::new()];
+  let mut quoted = false;
+  for c in key.chars() {
+    match c {
+      '"' => quoted = !quoted,
+      '.' if !quoted => parts.push(String::new()),
+      c => parts.last_mut().expect("parts is never empty").push(c),
+    }
+  }
+  parts
+}
+
+/// Quotes a key part for display if it could not be split again otherwise.
+fn quote_part(part: &str) -> String {
+  if part.contains(['.', '"']) || part.is_empty() {
+    format!("\"{}\"", part)
+  } else {
+    part.to_string()
+  }
+}
+
+fn lookup<'a>(value: &'a Value, key: &str) -> Option<&'a Value> {
+  split_key(key)
+    .iter()
+    .try_fold(value, |current, part| current.as_table()?.get(part))
+}
+
+fn insert(root: &mut Value, key: &str, new: Value) -> Result<(), Box<dyn std::error::Error>> {
+  let parts = split_key(key);
+  let (last, parents) = parts.split_last().ok_or(i18n::empty_key())?;
+
+  let mut current = root;
+  for part in parents {
+    let table = current
+      .as_table_mut()
+      .ok_or_else(|| i18n::cannot_contain_settings(part))?;
+    current = table
+      .entry(part.clone())
+      .or_insert_with(|| Value::Table(Map::new()));
+  }
+  current
+    .as_table_mut()
+    .ok_or_else(|| i18n::cannot_set_inside_value(key))?
+    .insert(last.clone(), new);
+  Ok(())
+}
+
+fn remove(root: &mut Value, key: &str) {
+  let parts = split_key(key);
+  let Some((last, parents)) = parts.split_last() else {
+    return;
+  };
+
+  let mut current = root;
+  for part in parents {
+    match current.as_table_mut().and_then(|table| table.get_mut(part)) {
+      Some(next) => current = next,
+      None => return,
+    }
+  }
+  if let Some(table) = current.as_table_mut() {
+    table.remove(last);
+  }
+}
+
+/// Parses a command line value into the type of the setting it replaces.
+///
+/// Strings are taken literally. For other types the text is read as a TOML
+/// value, so `true`, `3` or `["a", "b"]` work as expected.
+fn parse_value(existing: Option<&Value>, raw: &str) -> Value {
+  match existing {
+    Some(Value::String(_)) => Value::String(raw.to_string()),
+    _ => format!("value = {}", raw)
+      .parse::<toml::Table>()
+      .ok()
+      .and_then(|mut table| table.remove("value"))
+      .unwrap_or_else(|| Value::String(raw.to_string())),
+  }
+}
+
+/// Formats a value for output; strings are printed without quotes.
+fn display_value(value: &Value) -> String {
+  match value {
+    Value::String(s) => s.clone(),
+    Value::Table(table) => toml::to_string(table).unwrap_or_default().trim_end().to_string(),
+    other => other.to_string(),
+  }
+}
+
+/// Collects all leaf values with their dotted keys.
+fn flatten(prefix: &str, value: &Value, entries: &mut Vec<(String, Value)>) {
+  match value {
+    Value::Table(table) => {
+      for (key, value) in table {
+        let key = if prefix.is_empty() {
+          quote_part(key)
+        } else {
+          format!("{}.{}", prefix, quote_part(key))
+        };
+        flatten(&key, value, entries);
+      }
+    }
+    other => entries.push((prefix.to_string(), other.clone())),
+  }
+}
+
+/// Opens `path` in the user's editor and waits for it to exit.
+fn open_editor(path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+  let editor = std::env::var("VISUAL")
+    .or_else(|_| std::env::var("EDITOR"))
+    .unwrap_or_else(|_| {
+      if cfg!(windows) {
+        "notepad".to_string()
+      } else {
+        "vi".to_string()
+      }
+    });
+  let mut parts = editor.split_whitespace();
+  let program = parts.next().ok_or(i18n::no_editor())?;
+
+  let status = Command::new(program)
+    .args(parts)
+    .arg(path)
+    .status()
+    .map_err(|e| i18n::editor_start_failed(program, e))?;
+  if !status.success() {
+    return Err(i18n::editor_exited(program, status).into());
+  }
+  Ok(())
+}
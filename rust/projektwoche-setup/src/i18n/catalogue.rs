@@ -0,0 +1,772 @@
+//! # Message Catalogue
+//!
+//! The English and German text of every user-facing message. Each entry
+//! becomes a function named like the entry; placeholders in braces are its
+//! arguments, in the order they are listed.
+
+use super::{Language, language};
+
+/// Defines one function per message that picks the text of the current
+/// language. Messages with arguments are formatted, the others are returned
+/// as they are.
+macro_rules! catalogue {
+  ($($name:ident $(($($arg:ident),*))? { en: $en:literal, de: $de:literal })*) => {
+    $(catalogue!(@message $name $(($($arg),*))? $en $de);)*
+  };
+  (@message $name:ident ($($arg:ident),*) $en:literal $de:literal) => {
+    pub fn $name($($arg: impl std::fmt::Display),*) -> String {
+      match language() {
+        Language::German => format!($de),
+        _ => format!($en),
+      }
+    }
+  };
+  (@message $name:ident $en:literal $de:literal) => {
+    pub fn $name() -> &'static str {
+      match language() {
+        Language::German => $de,
+        _ => $en,
+      }
+    }
+  };
+}
+
+catalogue! {
+  // Command line
+  help_flag { en: "Print help", de: "Hilfe anzeigen" }
+  version_flag { en: "Print version", de: "Version anzeigen" }
+  ignoring_log_env(name, error) {
+    en: "Ignoring {name}: {error}",
+    de: "{name} wird ignoriert: {error}"
+  }
+
+  // Log files
+  logging_to(path) {
+    en: "Logging this run to {path}",
+    de: "Dieser Lauf wird in {path} protokolliert"
+  }
+  not_logged(error) {
+    en: "This run is not logged to a file: {error}",
+    de: "Dieser Lauf wird nicht in eine Datei protokolliert: {error}"
+  }
+  log_not_pruned(path, error) {
+    en: "Old log {path} could not be deleted: {error}",
+    de: "Altes Log {path} konnte nicht gelöscht werden: {error}"
+  }
+  no_log_directory { en: "No directory for log files found", de: "Kein Verzeichnis für Logdateien gefunden" }
+  run_log(path) { en: "Log of this run: {path}", de: "Log dieses Laufs: {path}" }
+  log_directory(path) { en: "Log directory: {path}", de: "Logverzeichnis: {path}" }
+  no_runs { en: "No runs recorded yet.", de: "Noch keine Läufe aufgezeichnet." }
+  column_run { en: "RUN", de: "LAUF" }
+  column_started { en: "STARTED (UTC)", de: "BEGINN (UTC)" }
+  column_size { en: "SIZE", de: "GRÖSSE" }
+  column_problems { en: "PROBLEMS", de: "PROBLEME" }
+  run_problems(errors, warnings) {
+    en: "{errors} errors, {warnings} warnings",
+    de: "{errors} Fehler, {warnings} Warnungen"
+  }
+  run_unreadable(error) { en: "unreadable: {error}", de: "nicht lesbar: {error}" }
+  run_not_found(run) {
+    en: "No run '{run}' found. Use `logs list` to see the recorded runs.",
+    de: "Kein Lauf '{run}' gefunden. `logs list` zeigt die aufgezeichneten Läufe."
+  }
+
+  // Configuration
+  config_load_failed(error) {
+    en: "Failed to load or create the configuration: {error}",
+    de: "Fehler beim Laden/Erstellen der Konfiguration: {error}"
+  }
+  config_fix_hint {
+    en: "Use `projektwoche-setup config edit` to fix your configuration or `config reset` to start over.",
+    de: "Mit `projektwoche-setup config edit` lässt sich die Konfiguration reparieren, mit `config reset` fängst du neu an."
+  }
+  config_save_failed(error) {
+    en: "Failed to save configuration: {error}",
+    de: "Konfiguration konnte nicht gespeichert werden: {error}"
+  }
+  using_machine(machine) { en: "Using configuration: {machine}", de: "Verwende Konfiguration: {machine}" }
+  bundle_not_allowed(bundle, allowed) {
+    en: "The bundle '{bundle}' is not allowed on this machine (allowed: {allowed}).",
+    de: "Das Bundle '{bundle}' ist auf diesem Rechner nicht erlaubt (erlaubt: {allowed})."
+  }
+  unknown_setting(key) { en: "Unknown setting '{key}'", de: "Unbekannte Einstellung '{key}'" }
+  unknown_setting_available(key, keys) {
+    en: "Unknown setting '{key}' (available: {keys})",
+    de: "Unbekannte Einstellung '{key}' (verfügbar: {keys})"
+  }
+  invalid_value(key, error) { en: "Invalid value for '{key}': {error}", de: "Ungültiger Wert für '{key}': {error}" }
+  invalid_configuration(origin, error) {
+    en: "Invalid configuration in {origin}: {error}",
+    de: "Ungültige Konfiguration in {origin}: {error}"
+  }
+  cannot_read(path, error) { en: "Cannot read {path}: {error}", de: "{path} kann nicht gelesen werden: {error}" }
+  setting_group(key) {
+    en: "'{key}' is a group of settings; set its keys individually",
+    de: "'{key}' ist eine Gruppe von Einstellungen; setze ihre Schlüssel einzeln"
+  }
+  value_overridden(origin) {
+    en: "Note: this value is currently overridden by {origin}",
+    de: "Hinweis: dieser Wert wird derzeit von {origin} überschrieben"
+  }
+  not_in_user_config(key, origin) {
+    en: "'{key}' is not set in the user configuration (value from {origin})",
+    de: "'{key}' ist in der Benutzerkonfiguration nicht gesetzt (Wert aus {origin})"
+  }
+  value_from(key, value, origin) { en: "{key} = {value} (from {origin})", de: "{key} = {value} (aus {origin})" }
+  value_removed(key) { en: "{key} removed", de: "{key} entfernt" }
+  file_missing { en: "(missing)", de: "(fehlt)" }
+  file_none { en: "(none)", de: "(keine)" }
+  reset_confirm { en: "Reset the user configuration to its defaults?", de: "Benutzerkonfiguration auf die Standardwerte zurücksetzen?" }
+  reset_cancelled { en: "Reset cancelled.", de: "Zurücksetzen abgebrochen." }
+  reset_done { en: "User configuration reset to defaults.", de: "Benutzerkonfiguration auf die Standardwerte zurückgesetzt." }
+  edit_fix_hint(error) {
+    en: "{error}\nRun `config edit` again to fix it or `config reset` to start over.",
+    de: "{error}\nMit `config edit` lässt sich das beheben, mit `config reset` fängst du neu an."
+  }
+  config_file_saved { en: "Configuration saved.", de: "Konfiguration gespeichert." }
+  empty_key { en: "Empty key", de: "Leerer Schlüssel" }
+  cannot_contain_settings(key) {
+    en: "'{key}' cannot contain other settings",
+    de: "'{key}' kann keine weiteren Einstellungen enthalten"
+  }
+  cannot_set_inside_value(key) {
+    en: "Cannot set '{key}' inside a value",
+    de: "'{key}' kann nicht innerhalb eines Werts gesetzt werden"
+  }
+  no_editor { en: "No editor configured", de: "Kein Editor eingestellt" }
+  editor_start_failed(program, error) {
+    en: "Failed to start editor '{program}': {error}",
+    de: "Editor '{program}' konnte nicht gestartet werden: {error}"
+  }
+  editor_exited(program, status) {
+    en: "Editor '{program}' exited with {status}",
+    de: "Editor '{program}' wurde mit {status} beendet"
+  }
+
+  // Machine
+  machine_override_for_run(machine) {
+    en: "Using machine override for this run: {machine}",
+    de: "Für diesen Lauf vorgegebener Rechner: {machine}"
+  }
+  machine_from_config(machine) {
+    en: "Using overridden machine from configuration: {machine}",
+    de: "Verwende den in der Konfiguration vorgegebenen Rechner: {machine}"
+  }
+  machine_mismatch(stored, detected) {
+    en: "Stored machine information ({stored}) does not match this system ({detected}).",
+    de: "Die gespeicherten Informationen über den Rechner ({stored}) passen nicht zu diesem System ({detected})."
+  }
+  machine_update_confirm { en: "Update the stored machine information?", de: "Gespeicherte Informationen über den Rechner aktualisieren?" }
+  machine_updated(machine) {
+    en: "Machine information updated to: {machine}",
+    de: "Informationen über den Rechner aktualisiert: {machine}"
+  }
+  machine_kept {
+    en: "Keeping the stored machine information for this run.",
+    de: "Für diesen Lauf bleiben die gespeicherten Informationen über den Rechner erhalten."
+  }
+  machine_stored(machine) { en: "Stored:      {machine}", de: "Gespeichert: {machine}" }
+  machine_detected(machine) { en: "Detected:    {machine}", de: "Erkannt:     {machine}" }
+  machine_override_active {
+    en: "Override:    yes (run `config machine redetect` to remove it)",
+    de: "Vorgabe:     ja (`config machine redetect` entfernt sie)"
+  }
+  machine_mismatch_hint {
+    en: "Mismatch:    run `config machine redetect` to update the stored values",
+    de: "Abweichung:  `config machine redetect` aktualisiert die gespeicherten Werte"
+  }
+  machine_this_run(machine) { en: "This run:    {machine}", de: "Dieser Lauf: {machine}" }
+  machine_set(machine) {
+    en: "Machine information set to: {machine}",
+    de: "Informationen über den Rechner gesetzt: {machine}"
+  }
+  machine_overridden(machine) {
+    en: "Machine information overridden: {machine}",
+    de: "Informationen über den Rechner vorgegeben: {machine}"
+  }
+  unknown_architecture(arch) {
+    en: "Unknown architecture '{arch}', expected one of: x86_64, aarch64, armv7",
+    de: "Unbekannte Architektur '{arch}', erwartet wird eine von: x86_64, aarch64, armv7"
+  }
+  unknown_os(os, names) {
+    en: "Unknown operating system '{os}', expected one of: {names}",
+    de: "Unbekanntes Betriebssystem '{os}', erwartet wird eines von: {names}"
+  }
+  fact_codename { en: "Codename", de: "Codename" }
+  fact_shell { en: "Shell", de: "Shell" }
+  fact_desktop { en: "Desktop", de: "Desktop" }
+  fact_privileges { en: "Privileges", de: "Rechte" }
+  fact_wsl { en: "WSL", de: "WSL" }
+  fact_container { en: "Container", de: "Container" }
+  fact_virtual_machine { en: "Virtual machine", de: "Virtuelle Maschine" }
+  fact_unknown { en: "unknown", de: "unbekannt" }
+  unsupported_architecture {
+    en: "Unsupported architecture detected, defaulting to x86_64.",
+    de: "Nicht unterstützte Architektur erkannt, verwende x86_64."
+  }
+  nothing_to_override {
+    en: "Nothing to override. Use --os, --arch or --os-version.",
+    de: "Nichts vorzugeben. Verwende --os, --arch oder --os-version."
+  }
+
+  // Commands
+  install_banner { en: "==> INSTALLATION", de: "==> INSTALLATION" }
+  install_banner_dry_run { en: "==> INSTALLATION (DRY RUN)", de: "==> INSTALLATION (DRY-RUN)" }
+  install_failed(error) { en: "Installation failed: {error}", de: "Fehler bei der Installation: {error}" }
+  install_done { en: "==> Installation finished.", de: "==> Installation abgeschlossen." }
+  uninstall_banner { en: "==> UNINSTALLATION", de: "==> DEINSTALLATION" }
+  uninstall_banner_dry_run { en: "==> UNINSTALLATION (DRY RUN)", de: "==> DEINSTALLATION (DRY-RUN)" }
+  uninstall_failed(error) { en: "Uninstallation failed: {error}", de: "Fehler bei der Deinstallation: {error}" }
+  uninstall_done { en: "==> Uninstallation finished.", de: "==> Deinstallation abgeschlossen." }
+  repair_banner { en: "==> REPAIR", de: "==> REPARATUR" }
+  repair_banner_dry_run { en: "==> REPAIR (DRY RUN)", de: "==> REPARATUR (DRY-RUN)" }
+  repair_failed(error) { en: "Repair failed: {error}", de: "Fehler bei der Reparatur: {error}" }
+  repair_done { en: "==> Repair finished.", de: "==> Reparatur abgeschlossen." }
+  upgrade_banner { en: "==> UPGRADE", de: "==> UPGRADE" }
+  upgrade_banner_dry_run { en: "==> UPGRADE (DRY RUN)", de: "==> UPGRADE (DRY-RUN)" }
+  upgrade_failed(error) { en: "Upgrade failed: {error}", de: "Fehler beim Upgrade: {error}" }
+  upgrade_done { en: "==> Upgrade finished.", de: "==> Upgrade abgeschlossen." }
+  status_json_failed(error) {
+    en: "Failed to serialize status report: {error}",
+    de: "Statusbericht konnte nicht als JSON ausgegeben werden: {error}"
+  }
+  self_update_banner { en: "==> SELF-UPDATE (not implemented yet)", de: "==> SELF-UPDATE (noch nicht implementiert)" }
+  configure_banner { en: "==> CONFIGURATION", de: "==> KONFIGURATION" }
+  preferences_saved(preferences) { en: "Preferences saved: {preferences}", de: "Einstellungen gespeichert: {preferences}" }
+  configuration_saved { en: "Configuration saved successfully.", de: "Konfiguration gespeichert." }
+  configuration_cancelled { en: "Configuration cancelled by user.", de: "Konfiguration vom Benutzer abgebrochen." }
+  configure_done { en: "==> Configuration finished.", de: "==> Konfiguration abgeschlossen." }
+  report_failed(path, error) {
+    en: "Failed to write the support report {path}: {error}",
+    de: "Der Supportbericht {path} konnte nicht geschrieben werden: {error}"
+  }
+
+  // Bundles
+  logger_not_initialized {
+    en: "Logger system not initialized. Call init_logger() first.",
+    de: "Loggersystem nicht initialisiert. Zuerst init_logger() aufrufen."
+  }
+  install_thread_panicked(error) {
+    en: "Thread panicked during installation: {error}",
+    de: "Thread während der Installation abgestürzt: {error}"
+  }
+  configure_thread_panicked(error) {
+    en: "Thread panicked during configuration: {error}",
+    de: "Thread während der Einrichtung abgestürzt: {error}"
+  }
+  upgrade_thread_panicked(error) {
+    en: "Thread panicked during upgrade: {error}",
+    de: "Thread während des Upgrades abgestürzt: {error}"
+  }
+  uninstall_thread_panicked(error) {
+    en: "Thread panicked during uninstallation: {error}",
+    de: "Thread während der Deinstallation abgestürzt: {error}"
+  }
+  deconfigure_thread_panicked(error) {
+    en: "Thread panicked during deconfiguration: {error}",
+    de: "Thread während des Entfernens der Einrichtung abgestürzt: {error}"
+  }
+  installer_panicked { en: "installer thread panicked", de: "Installations-Thread abgestürzt" }
+  configurator_panicked { en: "configurator thread panicked", de: "Einrichtungs-Thread abgestürzt" }
+  upgrader_panicked { en: "upgrader thread panicked", de: "Upgrade-Thread abgestürzt" }
+  uninstaller_panicked { en: "uninstaller thread panicked", de: "Deinstallations-Thread abgestürzt" }
+  deconfigurator_panicked { en: "deconfigurator thread panicked", de: "Thread zum Entfernen der Einrichtung abgestürzt" }
+  bundle_description(description) { en: "Description: {description}", de: "Beschreibung: {description}" }
+  installing_bundle(bundle) { en: "Installing bundle: {bundle}", de: "Installiere Bundle: {bundle}" }
+  packages_to_install { en: "The following packages will be installed:", de: "Folgende Pakete werden installiert:" }
+  force_reinstall {
+    en: "Packages will be reinstalled even if they are already present.",
+    de: "Pakete werden neu installiert, auch wenn sie schon vorhanden sind."
+  }
+  install_confirm { en: "Do you want to continue with the installation?", de: "Mit der Installation fortfahren?" }
+  install_cancelled { en: "Installation cancelled by user.", de: "Installation vom Benutzer abgebrochen." }
+  bundle_install_failed { en: "Bundle installation finished with errors.", de: "Installation des Bundles mit Fehlern beendet." }
+  bundle_install_done { en: "Bundle installation completed successfully!", de: "Bundle erfolgreich installiert!" }
+  repairing_bundle(bundle) { en: "Repairing bundle: {bundle}", de: "Repariere Bundle: {bundle}" }
+  packages_to_repair {
+    en: "The following packages will be reinstalled and reconfigured:",
+    de: "Folgende Pakete werden neu installiert und eingerichtet:"
+  }
+  uninstall_first {
+    en: "Every package will be uninstalled before it is installed again.",
+    de: "Jedes Paket wird vor der Neuinstallation deinstalliert."
+  }
+  repair_confirm { en: "Do you want to continue with the repair?", de: "Mit der Reparatur fortfahren?" }
+  repair_cancelled { en: "Repair cancelled by user.", de: "Reparatur vom Benutzer abgebrochen." }
+  bundle_repair_failed { en: "Bundle repair finished with errors.", de: "Reparatur des Bundles mit Fehlern beendet." }
+  bundle_repair_done { en: "Bundle repair completed successfully!", de: "Bundle erfolgreich repariert!" }
+  upgrading_bundle(bundle) { en: "Upgrading bundle: {bundle}", de: "Aktualisiere Bundle: {bundle}" }
+  packages_to_upgrade {
+    en: "The following packages will be upgraded if a newer version is available:",
+    de: "Folgende Pakete werden aktualisiert, wenn eine neuere Version verfügbar ist:"
+  }
+  upgrade_confirm { en: "Do you want to continue with the upgrade?", de: "Mit dem Upgrade fortfahren?" }
+  upgrade_cancelled { en: "Upgrade cancelled by user.", de: "Upgrade vom Benutzer abgebrochen." }
+  bundle_upgrade_failed { en: "Bundle upgrade finished with errors.", de: "Upgrade des Bundles mit Fehlern beendet." }
+  bundle_upgrade_done { en: "Bundle upgrade completed successfully!", de: "Bundle erfolgreich aktualisiert!" }
+  uninstalling_bundle(bundle) { en: "Uninstalling bundle: {bundle}", de: "Deinstalliere Bundle: {bundle}" }
+  packages_to_uninstall { en: "The following packages will be uninstalled:", de: "Folgende Pakete werden deinstalliert:" }
+  uninstall_confirm {
+    en: "Are you sure you want to uninstall these packages?",
+    de: "Sollen diese Pakete wirklich deinstalliert werden?"
+  }
+  uninstall_cancelled { en: "Uninstallation cancelled by user.", de: "Deinstallation vom Benutzer abgebrochen." }
+  bundle_uninstall_done { en: "Bundle uninstallation completed successfully!", de: "Bundle erfolgreich deinstalliert!" }
+  bundle_uninstall_failed { en: "Bundle uninstallation finished with errors.", de: "Deinstallation des Bundles mit Fehlern beendet." }
+
+  // Packages
+  step(step, total, description) { en: "Step {step}/{total}: {description}", de: "Schritt {step}/{total}: {description}" }
+  installing_program(program) { en: "Installing program: {program}", de: "Installiere Programm: {program}" }
+  unsupported_machine(program, machine, supported) {
+    en: "{program} has no instructions for {machine} (supported: {supported})",
+    de: "{program} hat keine Anweisungen für {machine} (unterstützt: {supported})"
+  }
+  not_an_assertion(instruction) {
+    en: "only assertions can detect an installation, not: {instruction}",
+    de: "Nur Prüfungen können eine Installation erkennen, nicht: {instruction}"
+  }
+  no_instructions(machine) { en: "no instructions for {machine}", de: "keine Anweisungen für {machine}" }
+  user_scope {
+    en: "No administrator rights available, installing for the current user only.",
+    de: "Keine Administratorrechte vorhanden, es wird nur für den aktuellen Benutzer installiert."
+  }
+  force_mode { en: "Force mode enabled, skipping installed checks.", de: "Erzwungene Installation, die Prüfung auf vorhandene Installationen entfällt." }
+  checking_installed {
+    en: "Checking whether the program is already installed...",
+    de: "Prüfe, ob das Programm schon installiert ist..."
+  }
+  already_installed { en: "already installed", de: "bereits installiert" }
+  skipping_installed {
+    en: "Program already installed, skipping installation.",
+    de: "Programm ist bereits installiert, Installation wird übersprungen."
+  }
+  not_installed(reason) { en: "Not installed: {reason}", de: "Nicht installiert: {reason}" }
+  proceeding_install {
+    en: "Program not installed, proceeding with installation.",
+    de: "Programm ist nicht installiert, Installation beginnt."
+  }
+  checking_prerequisites { en: "Checking prerequisites...", de: "Prüfe Voraussetzungen..." }
+  prerequisite_unmet(prerequisite) { en: "prerequisite not met: {prerequisite}", de: "Voraussetzung nicht erfüllt: {prerequisite}" }
+  prerequisite_unmet_because(prerequisite, reason) {
+    en: "Prerequisite not met: {prerequisite} ({reason})",
+    de: "Voraussetzung nicht erfüllt: {prerequisite} ({reason})"
+  }
+  command_failed(error) { en: "Command failed: {error}", de: "Befehl fehlgeschlagen: {error}" }
+  command_done { en: "Command executed successfully.", de: "Befehl erfolgreich ausgeführt." }
+  installed { en: "installed", de: "installiert" }
+  install_completed(program) { en: "Completed installation of: {program}", de: "Installation abgeschlossen: {program}" }
+  configuration_failed(error) { en: "configuration failed: {error}", de: "Einrichtung fehlgeschlagen: {error}" }
+  uninstallation_failed(error) { en: "uninstallation failed: {error}", de: "Deinstallation fehlgeschlagen: {error}" }
+  deconfiguration_failed(error) {
+    en: "removing the configuration failed: {error}",
+    de: "Entfernen der Einrichtung fehlgeschlagen: {error}"
+  }
+  checking_upgrades(program) { en: "Checking for upgrades: {program}", de: "Suche nach Upgrades: {program}" }
+  not_installed_detail { en: "not installed", de: "nicht installiert" }
+  skipping_not_installed {
+    en: "Program is not installed, skipping upgrade.",
+    de: "Programm ist nicht installiert, Upgrade wird übersprungen."
+  }
+  state_unknown {
+    en: "Installation state unknown, attempting upgrade anyway.",
+    de: "Installationsstand unbekannt, Upgrade wird trotzdem versucht."
+  }
+  no_upgrade_instructions { en: "no upgrade instructions", de: "keine Upgrade-Anweisungen" }
+  skipping_no_upgrade {
+    en: "No upgrade instructions defined, skipping.",
+    de: "Keine Upgrade-Anweisungen definiert, wird übersprungen."
+  }
+  unknown_version { en: "unknown", de: "unbekannt" }
+  latest_version { en: "latest", de: "neueste" }
+  already_at(version) { en: "already at {version}", de: "bereits {version}" }
+  up_to_date(version) { en: "Already up to date ({version}).", de: "Bereits aktuell ({version})." }
+  upgrading_from_to(from, to) { en: "Upgrading from {from} to {to}", de: "Upgrade von {from} auf {to}" }
+  upgrading_to_latest(from) {
+    en: "Upgrading from {from} to the latest version",
+    de: "Upgrade von {from} auf die neueste Version"
+  }
+  upgrade_step_failed(error) { en: "Upgrade failed: {error}", de: "Upgrade fehlgeschlagen: {error}" }
+  upgrade_step_done { en: "Upgrade step executed successfully.", de: "Upgrade-Schritt erfolgreich ausgeführt." }
+  upgrade_completed(program) { en: "Completed upgrade of: {program}", de: "Upgrade abgeschlossen: {program}" }
+  no_configuration(program) {
+    en: "No configuration functions for program: {program}",
+    de: "Keine Einrichtungsschritte für Programm: {program}"
+  }
+  no_configuration_mapping(program) {
+    en: "No configuration mapping found for program: {program}",
+    de: "Keine Einrichtung für Programm gefunden: {program}"
+  }
+  no_uninstallation(program) {
+    en: "No uninstallation functions for program: {program}",
+    de: "Keine Deinstallationsschritte für Programm: {program}"
+  }
+  no_uninstallation_mapping(program) {
+    en: "No uninstallation mapping found for program: {program}",
+    de: "Keine Deinstallation für Programm gefunden: {program}"
+  }
+  no_deconfiguration(program) {
+    en: "No deconfiguration functions for program: {program}",
+    de: "Keine Schritte zum Entfernen der Einrichtung für Programm: {program}"
+  }
+  no_deconfiguration_mapping(program) {
+    en: "No deconfiguration mapping found for program: {program}",
+    de: "Kein Entfernen der Einrichtung für Programm gefunden: {program}"
+  }
+
+  // Instructions
+  dry_run_download(url, path) { en: "Dry run: would download {url} to {path}", de: "Probelauf: würde {url} nach {path} herunterladen" }
+  dry_run_execute(command) { en: "Dry run: would execute command: {command}", de: "Probelauf: würde Befehl ausführen: {command}" }
+  dry_run_script(script) { en: "Dry run: would run script: {script}", de: "Probelauf: würde Skript ausführen: {script}" }
+  dry_run_expect(command, expect) {
+    en: "Dry run: expect the result of: {command} to be {expect}",
+    de: "Probelauf: erwarte als Ergebnis von {command}: {expect}"
+  }
+  dry_run_extract(archive, destination) {
+    en: "Dry run: would extract {archive} to {destination}",
+    de: "Probelauf: würde {archive} nach {destination} entpacken"
+  }
+  dry_run_env_var(name, value) {
+    en: "Dry run: would set environment variable {name}={value}",
+    de: "Probelauf: würde Umgebungsvariable {name}={value} setzen"
+  }
+  dry_run_shortcut(name, target) {
+    en: "Dry run: would create shortcut '{name}' pointing to '{target}'",
+    de: "Probelauf: würde Verknüpfung '{name}' auf '{target}' anlegen"
+  }
+  dry_run_shortcut_icon(name, target, icon) {
+    en: "Dry run: would create shortcut '{name}' pointing to '{target}' with icon '{icon}'",
+    de: "Probelauf: würde Verknüpfung '{name}' auf '{target}' mit Symbol '{icon}' anlegen"
+  }
+  dry_run_wait(seconds, command) {
+    en: "Dry run: would wait up to {seconds} seconds for command '{command}' to succeed",
+    de: "Probelauf: würde bis zu {seconds} Sekunden warten, bis Befehl '{command}' erfolgreich ist"
+  }
+  dry_run_install_into(package, path) {
+    en: "Dry run: would install package '{package}' (into {path} where supported)",
+    de: "Probelauf: würde Paket '{package}' installieren (nach {path}, wo möglich)"
+  }
+  dry_run_install(package) { en: "Dry run: would install package '{package}'", de: "Probelauf: würde Paket '{package}' installieren" }
+  dry_run_upgrade(package) { en: "Dry run: would upgrade package '{package}'", de: "Probelauf: würde Paket '{package}' aktualisieren" }
+  dry_run_install_language(package) {
+    en: "Dry run: would install package '{package}' using language package manager",
+    de: "Probelauf: würde Paket '{package}' mit dem Paketmanager der Sprache installieren"
+  }
+  dry_run_clone_to(url, path) {
+    en: "Dry run: would clone repository '{url}' to '{path}'",
+    de: "Probelauf: würde Repository '{url}' nach '{path}' klonen"
+  }
+  dry_run_clone(url) {
+    en: "Dry run: would clone repository '{url}' to current directory",
+    de: "Probelauf: würde Repository '{url}' ins aktuelle Verzeichnis klonen"
+  }
+  dry_run_sudo(reason) {
+    en: "Dry run: would request administrator privileges: {reason}",
+    de: "Probelauf: würde Administratorrechte anfordern: {reason}"
+  }
+  dry_run_update_lists { en: "Dry run: would refresh the package lists", de: "Probelauf: würde die Paketlisten aktualisieren" }
+  dry_run_restart(service) { en: "Dry run: would restart service '{service}'", de: "Probelauf: würde Dienst '{service}' neu starten" }
+  dry_run_backup(path) { en: "Dry run: would backup file '{path}'", de: "Probelauf: würde Datei '{path}' sichern" }
+  dry_run_edit(path, find, replace) {
+    en: "Dry run: would edit file '{path}' replacing '{find}' with '{replace}'",
+    de: "Probelauf: würde in Datei '{path}' '{find}' durch '{replace}' ersetzen"
+  }
+  sudo_required(reason) {
+    en: "Administrator privileges required: {reason}",
+    de: "Administratorrechte erforderlich: {reason}"
+  }
+  sudo_windows_hint {
+    en: "Please ensure you are running as Administrator or have UAC enabled",
+    de: "Bitte stelle sicher, dass du als Administrator arbeitest oder die Benutzerkontensteuerung aktiviert ist"
+  }
+  backed_up(path, backup) { en: "Backed up {path} to {backup}", de: "{path} nach {backup} gesichert" }
+  download_failed { en: "Download failed", de: "Download fehlgeschlagen" }
+  downloaded_percent(percent) { en: "Downloaded {percent}%", de: "{percent}% heruntergeladen" }
+  downloaded_kb(kb) { en: "Downloaded {kb} KB", de: "{kb} KB heruntergeladen" }
+  unknown_placeholder(name, template, available) {
+    en: "Unknown placeholder '{{{name}}}' in '{template}' (available: {available})",
+    de: "Unbekannter Platzhalter '{{{name}}}' in '{template}' (verfügbar: {available})"
+  }
+  execution_failed(code) {
+    en: "Execution failed with exit code {code}",
+    de: "Ausführung mit Exit-Code {code} fehlgeschlagen"
+  }
+  unsupported_filetype { en: "Unsupported filetype for execution", de: "Dieser Dateityp kann nicht ausgeführt werden" }
+  empty_command { en: "Empty command", de: "Leerer Befehl" }
+  empty_check_command { en: "Empty check command", de: "Leerer Prüfbefehl" }
+  command_exit_code(code) {
+    en: "Command failed with exit code {code}",
+    de: "Befehl mit Exit-Code {code} fehlgeschlagen"
+  }
+  script_exit_code(code) {
+    en: "Script failed with exit code {code}",
+    de: "Skript mit Exit-Code {code} fehlgeschlagen"
+  }
+  run_failed(program, error) {
+    en: "Failed to run '{program}': {error}",
+    de: "'{program}' konnte nicht ausgeführt werden: {error}"
+  }
+  unexpected_output(expect, output) {
+    en: "Expected '{expect}' but got '{output}'",
+    de: "Erwartet wurde '{expect}', erhalten '{output}'"
+  }
+  no_file_extension { en: "No file extension", de: "Keine Dateiendung" }
+  unsupported_archive(extension) {
+    en: "Unsupported archive format: {extension}",
+    de: "Nicht unterstütztes Archivformat: {extension}"
+  }
+  condition_timeout { en: "Timeout waiting for condition", de: "Zeitüberschreitung beim Warten auf die Bedingung" }
+  no_user_package_manager {
+    en: "No package manager that installs without administrator rights found",
+    de: "Kein Paketmanager gefunden, der ohne Administratorrechte installiert"
+  }
+  no_suitable_package_manager { en: "No suitable package manager found", de: "Kein passender Paketmanager gefunden" }
+  no_language_package_manager {
+    en: "No suitable language package manager found",
+    de: "Kein passender Paketmanager für die Sprache gefunden"
+  }
+  clone_failed { en: "Git clone failed", de: "Git-Clone fehlgeschlagen" }
+  no_service_manager { en: "No service manager found", de: "Keine Dienstverwaltung gefunden" }
+  step_download_run(file) { en: "Download and run {file}", de: "{file} herunterladen und ausführen" }
+  step_run(command) { en: "Run `{command}`", de: "`{command}` ausführen" }
+  step_run_script(line) { en: "Run script `{line}`", de: "Skript `{line}` ausführen" }
+  step_download(file) { en: "Download {file}", de: "{file} herunterladen" }
+  step_check(check) { en: "Check {check}", de: "Prüfe {check}" }
+  step_extract(file) { en: "Extract {file}", de: "{file} entpacken" }
+  step_set(name) { en: "Set {name}", de: "{name} setzen" }
+  step_shortcut(name) { en: "Create shortcut {name}", de: "Verknüpfung {name} anlegen" }
+  step_wait(command) { en: "Wait for `{command}`", de: "Auf `{command}` warten" }
+  step_install(package) { en: "Install {package}", de: "{package} installieren" }
+  step_upgrade(package) { en: "Upgrade {package}", de: "{package} aktualisieren" }
+  step_clone(url) { en: "Clone {url}", de: "{url} klonen" }
+  step_sudo { en: "Request administrator rights", de: "Administratorrechte anfordern" }
+  step_update_lists { en: "Refresh package lists", de: "Paketlisten aktualisieren" }
+  step_restart(service) { en: "Restart {service}", de: "{service} neu starten" }
+  step_backup(path) { en: "Back up {path}", de: "{path} sichern" }
+  step_edit(path) { en: "Edit {path}", de: "{path} bearbeiten" }
+  assertion(command, expect) { en: "'{command}' outputs '{expect}'", de: "'{command}' gibt '{expect}' aus" }
+
+  // Doctor
+  check_os { en: "Operating system", de: "Betriebssystem" }
+  check_package_manager { en: "Package manager", de: "Paketmanager" }
+  check_privileges { en: "Privileges", de: "Rechte" }
+  check_network { en: "Network", de: "Netzwerk" }
+  check_home { en: "Home directory", de: "Home-Verzeichnis" }
+  os_supported(machine) { en: "{machine} is supported", de: "{machine} wird unterstützt" }
+  os_unsupported(machine) { en: "{machine} is not officially supported", de: "{machine} wird nicht offiziell unterstützt" }
+  os_unsupported_fix {
+    en: "Packages without instructions for this system will be skipped",
+    de: "Pakete ohne Anweisungen für dieses System werden übersprungen"
+  }
+  winget_missing { en: "winget is not available", de: "winget ist nicht verfügbar" }
+  winget_missing_fix {
+    en: "Install or update \"App Installer\" from the Microsoft Store",
+    de: "Installiere oder aktualisiere \"App Installer\" aus dem Microsoft Store"
+  }
+  using_package_manager(manager) { en: "Using {manager}", de: "Verwende {manager}" }
+  no_package_manager { en: "No supported package manager found", de: "Kein unterstützter Paketmanager gefunden" }
+  no_package_manager_fix {
+    en: "Install one of: apt, dnf, yum, pacman, zypper or brew",
+    de: "Installiere einen von: apt, dnf, yum, pacman, zypper oder brew"
+  }
+  running_as_admin { en: "Running as Administrator", de: "Läuft als Administrator" }
+  not_admin { en: "Not running as Administrator", de: "Läuft nicht als Administrator" }
+  not_admin_fix {
+    en: "Expect UAC prompts, or run the terminal as Administrator",
+    de: "Rechne mit UAC-Abfragen oder starte das Terminal als Administrator"
+  }
+  running_as_root { en: "Running as root", de: "Läuft als root" }
+  sudo_missing {
+    en: "sudo is not installed and the tool is not running as root",
+    de: "sudo ist nicht installiert und das Tool läuft nicht als root"
+  }
+  sudo_missing_fix { en: "Run the tool as root or install sudo", de: "Starte das Tool als root oder installiere sudo" }
+  sudo_passwordless { en: "sudo is available without a password", de: "sudo ist ohne Passwort verfügbar" }
+  sudo_available { en: "sudo is available (password required)", de: "sudo ist verfügbar (Passwort nötig)" }
+  no_sudo_rights { en: "Current user has no sudo rights", de: "Der aktuelle Benutzer hat keine sudo-Rechte" }
+  no_sudo_rights_fix {
+    en: "Ask an administrator to add you to the 'sudo' or 'wheel' group",
+    de: "Bitte einen Administrator, dich zur Gruppe 'sudo' oder 'wheel' hinzuzufügen"
+  }
+  curl_missing {
+    en: "curl is not installed, downloads are impossible",
+    de: "curl ist nicht installiert, Downloads sind nicht möglich"
+  }
+  curl_missing_fix {
+    en: "Install curl with your system package manager",
+    de: "Installiere curl mit dem Paketmanager des Systems"
+  }
+  ca_bundle_missing(path) {
+    en: "Configured CA bundle {path} does not exist",
+    de: "Das konfigurierte CA-Bundle {path} existiert nicht"
+  }
+  ca_bundle_missing_fix {
+    en: "Fix the path with `config set network.ca_bundle <file>` or remove it with `config unset network.ca_bundle`",
+    de: "Korrigiere den Pfad mit `config set network.ca_bundle <datei>` oder entferne ihn mit `config unset network.ca_bundle`"
+  }
+  reachable_via_proxy(proxy) { en: "Internet reachable via proxy {proxy}", de: "Internet über Proxy {proxy} erreichbar" }
+  reachable { en: "Internet reachable", de: "Internet erreichbar" }
+  unreachable_via_proxy(proxy) {
+    en: "Internet not reachable through proxy {proxy}",
+    de: "Internet über Proxy {proxy} nicht erreichbar"
+  }
+  unreachable_via_proxy_fix {
+    en: "Check the proxy address and credentials with `config get network.proxy` or in HTTPS_PROXY/HTTP_PROXY",
+    de: "Prüfe Adresse und Zugangsdaten des Proxys mit `config get network.proxy` oder in HTTPS_PROXY/HTTP_PROXY"
+  }
+  unreachable {
+    en: "Internet not reachable and no proxy configured",
+    de: "Internet nicht erreichbar und kein Proxy konfiguriert"
+  }
+  unreachable_fix {
+    en: "If this network uses a proxy, run `config set network.proxy http://proxy:3128`",
+    de: "Wenn dieses Netzwerk einen Proxy nutzt, führe `config set network.proxy http://proxy:3128` aus"
+  }
+  home_unknown { en: "Home directory could not be determined", de: "Das Home-Verzeichnis konnte nicht bestimmt werden" }
+  home_unknown_fix {
+    en: "Make sure HOME (or USERPROFILE on Windows) is set",
+    de: "Stelle sicher, dass HOME (unter Windows USERPROFILE) gesetzt ist"
+  }
+  home_writable(path) { en: "{path} is writable", de: "{path} ist beschreibbar" }
+  home_not_writable(path, error) { en: "{path} is not writable: {error}", de: "{path} ist nicht beschreibbar: {error}" }
+  home_not_writable_fix {
+    en: "Fix the ownership of your home directory or log in with your own account",
+    de: "Korrigiere den Besitzer deines Home-Verzeichnisses oder melde dich mit deinem eigenen Konto an"
+  }
+  bun_check_skipped { en: "Home directory unknown, skipping Bun check", de: "Home-Verzeichnis unbekannt, Bun-Prüfung entfällt" }
+  bun_not_installed { en: "Bun is not installed in the home directory", de: "Bun ist nicht im Home-Verzeichnis installiert" }
+  on_path(path) { en: "{path} is on PATH", de: "{path} ist im PATH" }
+  not_on_path(path) { en: "{path} is not on PATH", de: "{path} ist nicht im PATH" }
+  add_to_path_windows(path) {
+    en: "Add {path} to your user PATH and open a new terminal",
+    de: "Füge {path} zu deinem Benutzer-PATH hinzu und öffne ein neues Terminal"
+  }
+  add_to_path_unix {
+    en: "Add 'export PATH=\"$HOME/.bun/bin:$PATH\"' to ~/.bashrc and open a new terminal",
+    de: "Füge 'export PATH=\"$HOME/.bun/bin:$PATH\"' zu ~/.bashrc hinzu und öffne ein neues Terminal"
+  }
+  doctor_fix(fix) { en: "       fix: {fix}", de: "    Lösung: {fix}" }
+  doctor_totals(blocking, warnings) {
+    en: "{blocking} blocking problem(s), {warnings} warning(s)",
+    de: "{blocking} blockierende(s) Problem(e), {warnings} Warnung(en)"
+  }
+
+  // Status
+  state_installed { en: "installed", de: "installiert" }
+  state_missing { en: "missing", de: "fehlt" }
+  state_outdated { en: "outdated", de: "veraltet" }
+  state_unsupported { en: "unsupported", de: "nicht unterstützt" }
+  state_unknown_short { en: "unknown", de: "unbekannt" }
+  column_package { en: "PACKAGE", de: "PAKET" }
+  column_status { en: "STATUS", de: "STATUS" }
+  column_version { en: "VERSION", de: "VERSION" }
+  column_detail { en: "DETAIL", de: "DETAILS" }
+  status_bundle(bundle, os) { en: "Bundle: {bundle} ({os})", de: "Bundle: {bundle} ({os})" }
+  empty_version_probe { en: "Empty version probe", de: "Leere Versionsabfrage" }
+  version_probe_failed(error) { en: "Version probe failed: {error}", de: "Versionsabfrage fehlgeschlagen: {error}" }
+  no_version_found(output) { en: "No version found in '{output}'", de: "Keine Version in '{output}' gefunden" }
+  no_installed_checks { en: "No installed checks defined", de: "Keine Installationsprüfungen definiert" }
+  requires_at_least(version) { en: "Requires at least {version}", de: "Benötigt mindestens {version}" }
+  status_prerequisite(prerequisite) {
+    en: "Prerequisite not met: {prerequisite}",
+    de: "Voraussetzung nicht erfüllt: {prerequisite}"
+  }
+  status_panicked { en: "Status check panicked", de: "Statusprüfung abgestürzt" }
+
+  // Phases of a package
+  configure_started { en: "Configuring program", de: "Richte Programm ein" }
+  configure_failed { en: "Configuration failed", de: "Einrichtung fehlgeschlagen" }
+  configure_step_done { en: "Configuration applied successfully.", de: "Einrichtung erfolgreich angewendet." }
+  configure_completed { en: "Completed configuration of", de: "Einrichtung abgeschlossen" }
+  uninstall_started { en: "Uninstalling program", de: "Deinstalliere Programm" }
+  uninstall_phase_failed { en: "Uninstallation failed", de: "Deinstallation fehlgeschlagen" }
+  uninstall_step_done { en: "Uninstallation executed successfully.", de: "Deinstallation erfolgreich ausgeführt." }
+  uninstall_completed { en: "Completed uninstallation of", de: "Deinstallation abgeschlossen" }
+  deconfigure_started { en: "Deconfiguring program", de: "Entferne Einrichtung von Programm" }
+  deconfigure_failed { en: "Deconfiguration failed", de: "Entfernen der Einrichtung fehlgeschlagen" }
+  deconfigure_step_done { en: "Deconfiguration applied successfully.", de: "Einrichtung erfolgreich entfernt." }
+  deconfigure_completed { en: "Completed deconfiguration of", de: "Entfernen der Einrichtung abgeschlossen" }
+
+  // Reports
+  operation_installation { en: "Installation", de: "Installation" }
+  operation_repair { en: "Repair", de: "Reparatur" }
+  operation_uninstallation { en: "Uninstallation", de: "Deinstallation" }
+  operation_upgrade { en: "Upgrade", de: "Upgrade" }
+  operation_summary(operation) { en: "{operation} summary:", de: "Zusammenfassung ({operation}):" }
+  outcome_ok { en: "ok", de: "ok" }
+  outcome_skipped { en: "skipped", de: "übersprungen" }
+  outcome_failed { en: "failed", de: "fehlgeschlagen" }
+  progress_starting { en: "starting", de: "startet" }
+  progress_skipped(detail) { en: "skipped: {detail}", de: "übersprungen: {detail}" }
+  progress_failed(error) { en: "failed: {error}", de: "fehlgeschlagen: {error}" }
+
+  // Prompts
+  yes { en: "yes", de: "ja" }
+  no { en: "no", de: "nein" }
+  default_yes { en: "Y/n", de: "J/n" }
+  default_no { en: "y/N", de: "j/N" }
+  answer_yes_or_no {
+    en: "Please answer with yes or no (y/n).",
+    de: "Bitte mit ja oder nein antworten (j/n)."
+  }
+  answered_by_file(question, value, key) {
+    en: "{question} {value} (answer file: {key})",
+    de: "{question} {value} (Antwortdatei: {key})"
+  }
+  ignoring_answer(value, key, error) {
+    en: "Ignoring answer file value {value} for '{key}': {error}",
+    de: "Wert {value} der Antwortdatei für '{key}' wird ignoriert: {error}"
+  }
+  answered_by_default(question, answer) {
+    en: "{question} {answer} (default, not interactive)",
+    de: "{question} {answer} (Standard, nicht interaktiv)"
+  }
+  answered_by_yes(question, answer) { en: "{question} {answer} (--yes)", de: "{question} {answer} (--yes)" }
+  path_missing(path) { en: "Path '{path}' does not exist", de: "Pfad '{path}' existiert nicht" }
+  path_not_writable(path) { en: "Path '{path}' is not writable", de: "Pfad '{path}' ist nicht beschreibbar" }
+  continue_confirm { en: "❓ Do you want to continue?", de: "❓ Möchtest du fortfahren?" }
+
+  // Configuration wizard
+  wizard_welcome {
+    en: "🚀 Welcome to the Interactive Setup Wizard!\n   Let's configure your development environment.\n",
+    de: "🚀 Willkommen beim interaktiven Einrichtungsassistenten!\n   Lass uns deine Entwicklungsumgebung einrichten.\n"
+  }
+  recommended(name) { en: "{name} (Recommended)", de: "{name} (Empfohlen)" }
+  other_specify { en: "Other (I'll specify)", de: "Andere (gebe ich selbst an)" }
+  other { en: "Other", de: "Andere" }
+  wizard_editor { en: "🎯 Which code editor do you prefer?", de: "🎯 Welchen Code-Editor bevorzugst du?" }
+  wizard_other_editor { en: "✏️  Please specify your preferred editor:", de: "✏️  Welchen Editor bevorzugst du?" }
+  wizard_browser { en: "🌐 Which web browser do you prefer?", de: "🌐 Welchen Webbrowser bevorzugst du?" }
+  wizard_other_browser { en: "🌍 Please specify your preferred browser:", de: "🌍 Welchen Browser bevorzugst du?" }
+  wizard_languages {
+    en: "💻 Which programming languages do you work with?",
+    de: "💻 Mit welchen Programmiersprachen arbeitest du?"
+  }
+  wizard_custom_path {
+    en: "📁 Do you want to specify a custom installation path?",
+    de: "📁 Möchtest du einen eigenen Installationspfad angeben?"
+  }
+  wizard_install_path { en: "📂 Enter the installation directory:", de: "📂 Installationsverzeichnis:" }
+  wizard_extras {
+    en: "🔧 Install additional development tools? (Git, Docker, etc.)",
+    de: "🔧 Zusätzliche Entwicklungswerkzeuge installieren? (Git, Docker usw.)"
+  }
+  summary_editor(editor) { en: "Editor: {editor}", de: "Editor: {editor}" }
+  summary_browser(browser) { en: "Browser: {browser}", de: "Browser: {browser}" }
+  summary_languages(languages) { en: "Languages: {languages}", de: "Sprachen: {languages}" }
+  summary_install_path(path) { en: "Install path: {path}", de: "Installationspfad: {path}" }
+  summary_extras { en: "Additional tools: Yes", de: "Zusätzliche Werkzeuge: Ja" }
+  wizard_summary { en: "Configuration Summary:", de: "Zusammenfassung der Einstellungen:" }
+  unexpected_string { en: "expected a string", de: "Text erwartet" }
+  unexpected_bool { en: "expected true or false", de: "true oder false erwartet" }
+  unexpected_list { en: "expected a list of strings", de: "Liste von Texten erwartet" }
+  expected_one_of(names) { en: "expected one of {names}", de: "einer von {names} erwartet" }
+  cannot_read_answers(path, error) {
+    en: "Cannot read answer file {path}: {error}",
+    de: "Antwortdatei {path} kann nicht gelesen werden: {error}"
+  }
+  invalid_answers(path, error) {
+    en: "Invalid answer file {path}: {error}",
+    de: "Ungültige Antwortdatei {path}: {error}"
+  }
+  wizard_cancelled { en: "❌ Configuration cancelled.", de: "❌ Einrichtung abgebrochen." }
+}
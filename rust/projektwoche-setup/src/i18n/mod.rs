@@ -0,0 +1,268 @@
+//! # Translations
+//!
+//! Messages, prompts and the command line help are available in English and
+//! German. Most users are German school students, but the tool is published
+//! for everyone, so the language follows the system unless it is set in the
+//! configuration:
+//!
+//! ```bash
+//! projektwoche-setup config set language German
+//! PROWO_LANGUAGE=English projektwoche-setup install projektwoche
+//! ```
+//!
+//! ## Message Catalogue
+//!
+//! Every user-facing text is a function of the [`catalogue`] (re-exported
+//! here) that returns the text in the current language. Texts without
+//! placeholders return `&'static str`, texts with placeholders take their
+//! values as arguments and return a `String`:
+//!
+//! ```rust,ignore
+//! use crate::i18n;
+//!
+//! logger.info(i18n::install_banner());
+//! logger.error(i18n::install_failed(&e));
+//! ```
+//!
+//! The English help of the command line comes from the doc comments of the
+//! clap definitions; [`localize_command`] replaces it with the German texts
+//! of [`GERMAN_HELP`].
+//!
+//! Machine-readable values stay English in every language: the types,
+//! operations and outcomes of JSON events, the states of `status --json` and
+//! the names of settings and values. Only free text is translated.
+//!
+//! The support bundle of `report` stays English as well, including the
+//! summary printed to the terminal: it is written for the maintainers, who
+//! compare reports from many machines.
+
+pub mod catalogue;
+
+pub use catalogue::*;
+
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+/// Language of messages, prompts and help.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Language {
+  /// The language of the system, German if it is set to German and English
+  /// otherwise
+  #[default]
+  #[serde(alias = "system", alias = "auto")]
+  System,
+  #[serde(alias = "english", alias = "en")]
+  English,
+  #[serde(alias = "german", alias = "deutsch", alias = "de")]
+  German,
+}
+
+impl Language {
+  /// Returns the language to use, detecting it if it follows the system.
+  pub fn resolve(self) -> Language {
+    match self {
+      Language::System => system_locale()
+        .map(|locale| Language::from_locale(&locale))
+        .unwrap_or(Language::English),
+      language => language,
+    }
+  }
+
+  /// Picks the language of a locale name such as `de_DE.UTF-8` or `en-US`.
+  pub fn from_locale(locale: &str) -> Language {
+    if locale.to_ascii_lowercase().starts_with("de") {
+      Language::German
+    } else {
+      Language::English
+    }
+  }
+}
+
+/// Returns the locale of the user, from the environment or, on Windows, the
+/// user's display settings.
+fn system_locale() -> Option<String> {
+  // LANGUAGE may hold a list such as `de:en`; its first entry counts
+  ["LANGUAGE", "LC_ALL", "LC_MESSAGES", "LANG"]
+    .iter()
+    .filter_map(|name| std::env::var(name).ok())
+    .map(|value| value.split(':').next().unwrap_or_default().to_string())
+    .find(|value| !value.is_empty() && value != "C" && value != "POSIX")
+    .or_else(platform_locale)
+}
+
+#[cfg(windows)]
+fn platform_locale() -> Option<String> {
+  use windows_sys::Win32::Globalization::GetUserDefaultLocaleName;
+
+  // LOCALE_NAME_MAX_LENGTH
+  let mut buffer = [0u16; 85];
+  // SAFETY: the buffer outlives the call and its length is passed along
+  let length = unsafe { GetUserDefaultLocaleName(buffer.as_mut_ptr(), buffer.len() as i32) };
+  // The length includes the terminating null
+  (length > 1).then(|| String::from_utf16_lossy(&buffer[..length as usize - 1]))
+}
+
+#[cfg(not(windows))]
+fn platform_locale() -> Option<String> {
+  None
+}
+
+/// Language chosen with [`init`]
+static LANGUAGE: OnceLock<Language> = OnceLock::new();
+
+/// Resolved system language, used until [`init`] is called
+static SYSTEM_LANGUAGE: OnceLock<Language> = OnceLock::new();
+
+/// Sets the language for the rest of the process.
+///
+/// Messages created before, e.g. while the configuration that names the
+/// language is loaded, use the system language. Only the first call takes
+/// effect.
+pub fn init(language: Language) {
+  let _ = LANGUAGE.set(language.resolve());
+}
+
+/// Returns the language messages are created in.
+pub fn language() -> Language {
+  match LANGUAGE.get() {
+    Some(language) => *language,
+    None => *SYSTEM_LANGUAGE.get_or_init(|| Language::System.resolve()),
+  }
+}
+
+/// German help texts of the command line.
+///
+/// Each entry names a command by its path of subcommands (empty for the tool
+/// itself) and an argument of it (empty for the command's own description),
+/// followed by the text. The first paragraph of a text is the short help of
+/// `-h`, the whole text the long help of `--help`. Entries for commands or
+/// arguments that do not exist are ignored.
+pub const GERMAN_HELP: &[(&str, &str, &str)] = &[
+  ("", "", "Ein CLI, um schnell eine Entwicklungsumgebung einzurichten\n\nDieses CLI ist ein eigener Paketmanager für Software-Bundles, mit dem sich Entwicklungsumgebungen schnell einrichten lassen. \nEs installiert, deinstalliert und aktualisiert Softwarepakete."),
+  ("", "os", "Vorgeben, auf diesem Betriebssystem zu laufen (z.B. ubuntu, windows)\n\nGilt nur für diesen Lauf. Nützlich, um die Paketzuordnungen mit einem Probelauf auf einem anderen Rechner zu testen."),
+  ("", "arch", "Vorgeben, auf dieser CPU-Architektur zu laufen (x86_64, aarch64, armv7)"),
+  ("", "os_version", "Vorgeben, auf dieser Betriebssystemversion zu laufen (z.B. 22.04)"),
+  ("", "yes", "Jede Bestätigung mit Ja beantworten"),
+  ("", "non_interactive", "Nie nachfragen; Fragen nehmen den Wert der Antwortdatei oder ihren Standard\n\nGilt auch, wenn stdin kein Terminal ist, z.B. über SSH oder in Skripten."),
+  ("", "answers", "TOML-Datei mit Antworten auf Fragen, für unbeaufsichtigte Installationen"),
+  ("", "log_format", "Format der Lognachrichten auf der Konsole\n\n`json` gibt jede Nachricht als JSON-Objekt aus, einschließlich der Ereignisse der Pakete (started, step, finished, failed), für Dashboards und Skripte, die den Fortschritt eines Laufs verfolgen."),
+  ("", "verbose", "Mehr Lognachrichten anzeigen; wiederholen für noch mehr (-vv)\n\nJedes `-v` senkt das Loglevel aus der Konfiguration oder `PROWO_LOG` um eine Stufe, z.B. von Info auf Debug."),
+  ("", "quiet", "Weniger Lognachrichten anzeigen; wiederholen für noch weniger (-qq)"),
+  ("install", "", "Ein Software-Bundle installieren\n\nInstalliert ein Software-Bundle mit mehreren Paketen für einen bestimmten Zweck und richtet sie ein. \nWenn du ein Bundle erwartest, es hier aber nicht findest, aktualisiere das CLI mit `projektwoche-setup self-update`."),
+  ("install", "package", "Welches Bundle installiert werden soll"),
+  ("install", "debug", "Probelauf: anzeigen, was installiert würde, ohne es zu tun\n\nZeigt alle Installationsschritte an, ohne etwas am System zu ändern."),
+  ("install", "force", "Pakete neu installieren, auch wenn sie schon installiert sind"),
+  ("repair", "", "Ein Software-Bundle reparieren\n\nRepariert ein Software-Bundle, indem alle seine Pakete neu installiert und eingerichtet werden. \nDie Prüfung auf bereits installierte Pakete entfällt, sodass kaputte Installationen ersetzt werden."),
+  ("repair", "package", "Welches Bundle repariert werden soll"),
+  ("repair", "uninstall_first", "Jedes Paket vor der Neuinstallation deinstallieren"),
+  ("repair", "debug", "Probelauf: anzeigen, was repariert würde, ohne es zu tun"),
+  ("uninstall", "", "Ein Software-Bundle deinstallieren\n\nDeinstalliert ein zuvor installiertes Software-Bundle und macht seine Einstellungen rückgängig. \nWenn du ein Bundle erwartest, es hier aber nicht findest, aktualisiere das CLI mit `projektwoche-setup self-update`."),
+  ("uninstall", "package", "Welches Bundle deinstalliert werden soll"),
+  ("uninstall", "debug", "Probelauf: anzeigen, was deinstalliert würde, ohne es zu tun\n\nZeigt alle Deinstallationsschritte an, ohne etwas am System zu ändern."),
+  ("upgrade", "", "Die Pakete eines installierten Software-Bundles aktualisieren\n\nAktualisiert die Pakete eines installierten Software-Bundles. \nPakete, die nicht installiert oder schon aktuell sind, werden übersprungen."),
+  ("upgrade", "package", "Welches Bundle aktualisiert werden soll"),
+  ("upgrade", "debug", "Probelauf: anzeigen, was aktualisiert würde, ohne es zu tun\n\nZeigt alle Aktualisierungsschritte an, ohne etwas am System zu ändern."),
+  ("status", "", "Den Installationsstand eines Software-Bundles anzeigen\n\nPrüft diesen Rechner gegen ein Software-Bundle. \nJedes Paket wird nur lesend geprüft und als installiert, fehlend, veraltet oder nicht unterstützt gemeldet."),
+  ("status", "package", "Welches Bundle geprüft werden soll"),
+  ("status", "json", "Den Bericht als JSON statt als Tabelle ausgeben"),
+  ("doctor", "", "Probleme der Umgebung finden, an denen Installationen scheitern\n\nFindet häufige Probleme der Umgebung, an denen Installationen scheitern. \nZu jedem Problem wird eine Lösung vorgeschlagen. Der Befehl endet mit Status 1, wenn ein blockierendes Problem gefunden wurde."),
+  ("self-update", "", "Das CLI selbst aktualisieren\n\nLädt die neueste Version von projektwoche-setup herunter und installiert sie, damit du die neuesten Bundles und Funktionen hast.\n\n**Hinweis:** Diese Funktion ist noch nicht umgesetzt."),
+  ("configure", "", "Das CLI interaktiv einrichten\n\nInteraktiver Assistent für deine Einstellungen. \nDie Antworten entscheiden, welche Pakete die Bundles installieren, z.B. Firefox statt Chrome oder Python zusätzlich zu JavaScript."),
+  ("logs", "", "Die Logdateien früherer Läufe ansehen\n\nJeder Installations-, Reparatur-, Aktualisierungs- und Deinstallationslauf schreibt eine eigene Logdatei, sodass fehlgeschlagene Läufe später untersucht werden können."),
+  ("logs list", "", "Die aufgezeichneten Läufe auflisten, neueste zuerst"),
+  ("logs show", "", "Das Log eines Laufs ausgeben"),
+  ("logs show", "run", "Lauf-ID wie in `logs list` oder seine Nummer in der Liste (1 = neuester)"),
+  ("logs last", "", "Das Log des neuesten Laufs ausgeben"),
+  ("report", "", "Alles zur Fehlersuche in einem Archiv sammeln\n\nSammelt Logs, Konfiguration und Informationen über den Rechner zur Fehlersuche in einem Zip-Archiv. \nGeheimnisse in der Konfiguration und den Logs werden unkenntlich gemacht. Eine kurze Zusammenfassung wird ausgegeben, die in einen Chat eingefügt werden kann."),
+  ("report", "output", "Wohin das Archiv geschrieben wird, statt prowo-report-<Zeit>.zip im aktuellen Verzeichnis"),
+  ("config", "", "Einstellungen verwalten\n\nZeigt und ändert alle Einstellungen, etwa das Loglevel, die Sprache oder Vorlagenvariablen, und die gespeicherten Informationen über den Rechner."),
+  ("config get", "", "Den Wert einer Einstellung ausgeben"),
+  ("config get", "key", "Zu lesende Einstellung, z.B. `log_level` oder `machine.os`"),
+  ("config get", "show_origin", "Auch ausgeben, aus welcher Ebene der Wert stammt"),
+  ("config set", "", "Den Wert einer Einstellung in der Benutzerkonfiguration ändern"),
+  ("config set", "key", "Zu ändernde Einstellung, z.B. `log_level` oder `variables.deb_arch`"),
+  ("config set", "value", "Neuer Wert, der vor dem Speichern geprüft wird"),
+  ("config unset", "", "Eine Einstellung aus der Benutzerkonfiguration entfernen\n\nDanach gilt wieder der Wert der Systemkonfiguration oder der Standard."),
+  ("config unset", "key", "Zu entfernende Einstellung"),
+  ("config list", "", "Alle Einstellungen mit ihren aktuellen Werten auflisten"),
+  ("config list", "show_origin", "Auch ausgeben, aus welcher Ebene jeder Wert stammt"),
+  ("config path", "", "Den Pfad der Benutzerkonfiguration ausgeben"),
+  ("config path", "all", "Stattdessen die System-, Benutzer- und Projektdateien ausgeben"),
+  ("config reset", "", "Die Benutzerkonfiguration auf die Standardwerte zurücksetzen\n\nSystemweite und Projekteinstellungen bleiben wirksam. Mit `--yes` entfällt die Bestätigung."),
+  ("config edit", "", "Die Benutzerkonfiguration in $VISUAL oder $EDITOR öffnen\n\nDie Datei wird geprüft, nachdem der Editor beendet wurde."),
+  ("config machine", "", "Die gespeicherten Informationen über den Rechner verwalten"),
+  ("config machine show", "", "Die gespeicherten und die erkannten Informationen über den Rechner anzeigen"),
+  ("config machine redetect", "", "Den Rechner neu erkennen und das Ergebnis speichern, eine Vorgabe wird entfernt"),
+  ("config machine override", "", "Informationen über den Rechner von Hand vorgeben, statt sie zu erkennen\n\nNimmt die globalen Optionen --os, --arch und --os-version. Nicht angegebene Optionen behalten ihren gespeicherten Wert. Die Vorgabe gilt, bis `config machine redetect` ausgeführt wird."),
+];
+
+/// Replaces the help of `command` and its subcommands with the texts of the
+/// current language.
+///
+/// Must be called on the command before it parses the arguments.
+pub fn localize_command(command: clap::Command) -> clap::Command {
+  match language() {
+    Language::German => apply_help(command, "", GERMAN_HELP),
+    _ => command,
+  }
+}
+
+/// Splits a help text into the short help (first paragraph) and the long help.
+fn split_help(text: &'static str) -> (&'static str, &'static str) {
+  let short = text.split("\n\n").next().unwrap_or(text);
+  (short, text)
+}
+
+fn apply_help(mut command: clap::Command, path: &str, texts: &[(&str, &str, &'static str)]) -> clap::Command {
+  command = command
+    .help_template("{before-help}{about-with-newline}\nAufruf: {usage}\n\n{all-args}{after-help}")
+    .subcommand_help_heading("Befehle");
+  // clap only adds its help and version flags when the command is built, so
+  // they are replaced by flags with a translated text
+  command = command.disable_help_flag(true).arg(
+    clap::Arg::new("help")
+      .short('h')
+      .long("help")
+      .help(help_flag())
+      .action(clap::ArgAction::Help),
+  );
+  if path.is_empty() && command.get_version().is_some() {
+    command = command.disable_version_flag(true).arg(
+      clap::Arg::new("version")
+        .short('V')
+        .long("version")
+        .help(version_flag())
+        .action(clap::ArgAction::Version),
+    );
+  }
+
+  for (_, id, text) in texts.iter().filter(|(command_path, _, _)| *command_path == path) {
+    let (short, long) = split_help(text);
+    if id.is_empty() {
+      command = command.about(short).long_about(long);
+    } else if command.get_arguments().any(|arg| arg.get_id() == *id) {
+      command = command.mut_arg(*id, |arg| arg.help(short).long_help(long));
+    }
+  }
+
+  // Arguments are listed under translated headings unless they have their own
+  let ids: Vec<(clap::Id, bool)> = command
+    .get_arguments()
+    .filter(|arg| arg.get_help_heading().is_none())
+    .map(|arg| (arg.get_id().clone(), arg.is_positional()))
+    .collect();
+  for (id, positional) in ids {
+    let heading = if positional { "Argumente" } else { "Optionen" };
+    command = command.mut_arg(id, |arg| arg.help_heading(heading));
+  }
+
+  let names: Vec<String> = command
+    .get_subcommands()
+    .map(|subcommand| subcommand.get_name().to_string())
+    .collect();
+  for name in names {
+    let sub_path = if path.is_empty() { name.clone() } else { format!("{} {}", path, name) };
+    command = command.mut_subcommand(&name, |subcommand| apply_help(subcommand, &sub_path, texts));
+  }
+  command
+}
@@ -45,22 +45,29 @@
 //!
 //! ## Usage Example
 //!
-//! ```rust,no_run
+//! ```rust,ignore
 //! use projektwoche_setup::bundles::projektwoche;
 //! use projektwoche_setup::config;
+//! use projektwoche_setup::manager::InstallOptions;
+//! use prowo_log::LoggerSystem;
 //!
 //! // Load system configuration
 //! let config = config::use_config()?;
+//! let (logger_system, _collector) = LoggerSystem::new();
 //!
-//! // Get the Projektwoche bundle
-//! let bundle = projektwoche::bundle();
+//! // Get the Projektwoche bundle with the user's tool choices
+//! let mut bundle = projektwoche::bundle(&config.preferences);
 //!
 //! // Install with dry-run to preview
-//! bundle.install(&config.machine.os, true)?;
+//! let preview = InstallOptions { dry_run: true, ..Default::default() };
+//! bundle.install(&config.machine, preview, &logger_system)?;
 //!
 //! // Actually install
-//! bundle.install(&config.machine.os, false)?;
-//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! let report = bundle.install(&config.machine, InstallOptions::default(), &logger_system)?;
+//! if report.has_failures() {
+//!   eprintln!("Some packages could not be installed");
+//! }
+//! # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
 //! ```
 //!
 //! ## Threading Model
@@ -87,9 +94,10 @@
 
 pub mod bundles;
 pub mod config;
-pub mod logger;
+pub mod i18n;
 pub mod manager;
 pub mod packages;
+pub mod progress;
 
 // Re-export commonly used types for convenience
 pub use config::{Config, use_config};
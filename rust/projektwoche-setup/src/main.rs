@@ -14,13 +14,18 @@
 
 mod bundles;
 mod config;
+mod i18n;
 mod manager;
 mod packages;
+mod progress;
 
-mod logger;
+use clap::{CommandFactory, FromArgMatches, Parser, Subcommand};
+use config::settings::SettingsCommand;
+use prowo_log::{LogFormat, LogLevel, LoggerSystem, ConsoleOutput, FileOutput, JsonOutput, LevelFilter};
+use progress::ProgressOutput;
 
-use clap::{Parser, Subcommand};
-use logger::{LogLevel, LoggerSystem, ConsoleOutput, LevelFilter};
+/// Environment variable with log directives, see [`Cli::log_filter`].
+const LOG_ENV: &str = "PROWO_LOG";
 
 /// Main CLI application structure that defines the command-line interface
 /// using the `clap` derive macros for automatic argument parsing.
@@ -34,6 +39,146 @@ use logger::{LogLevel, LoggerSystem, ConsoleOutput, LevelFilter};
 struct Cli {
   #[clap(subcommand)]
   command: Commands,
+
+  /// Pretend to run on this operating system (e.g. ubuntu, windows)
+  ///
+  /// Only affects this run. Useful for testing package mappings with a dry run
+  /// on another host.
+  #[clap(long, global = true)]
+  os: Option<config::machine::OS>,
+
+  /// Pretend to run on this CPU architecture (x86_64, aarch64, armv7)
+  #[clap(long, global = true)]
+  arch: Option<config::machine::Architectures>,
+
+  /// Pretend to run on this operating system version (e.g. 22.04)
+  #[clap(long, global = true)]
+  os_version: Option<String>,
+
+  /// Answer every confirmation with yes
+  #[clap(short, long, global = true)]
+  yes: bool,
+
+  /// Never prompt; questions take their answer-file value or default
+  ///
+  /// Also the case when stdin is not a terminal, e.g. over SSH or in scripts.
+  #[clap(long, global = true)]
+  non_interactive: bool,
+
+  /// TOML file with answers to prompts, for unattended installs
+  #[clap(long, global = true, value_name = "FILE")]
+  answers: Option<std::path::PathBuf>,
+
+  /// Format of the log messages on the console
+  ///
+  /// `json` prints one JSON object per message, including the package
+  /// lifecycle events (started, step, finished, failed), for dashboards and
+  /// scripts that follow the progress of a run.
+  #[clap(long, global = true, value_enum, default_value_t)]
+  log_format: LogFormat,
+
+  /// Show more log messages; repeat for even more (-vv)
+  ///
+  /// Each `-v` lowers the log level from the configuration or `PROWO_LOG` by
+  /// one step, e.g. from Info to Debug.
+  #[clap(short, long, global = true, action = clap::ArgAction::Count)]
+  verbose: u8,
+
+  /// Show fewer log messages; repeat for even fewer (-qq)
+  #[clap(short, long, global = true, action = clap::ArgAction::Count)]
+  quiet: u8,
+}
+
+impl Cli {
+  /// Builds the prompt settings from `--yes`, `--non-interactive` and `--answers`.
+  fn prompt_settings(
+    &self,
+  ) -> Result<config::interactive::PromptSettings, Box<dyn std::error::Error + Send + Sync>> {
+    let settings = config::interactive::PromptSettings::new()
+      .with_assume_yes(self.yes)
+      .with_non_interactive(self.non_interactive)
+      .with_json_output(self.log_format == LogFormat::Json);
+    match &self.answers {
+      Some(path) => settings.with_answer_file(path),
+      None => Ok(settings),
+    }
+  }
+
+  /// Builds the log filter of this run.
+  ///
+  /// The level comes from the configuration (`log_level`), is replaced by
+  /// the directives in `PROWO_LOG` (e.g. `debug` or
+  /// `info,installer=debug,package:Node.js=debug`) and finally shifted by
+  /// `-v` and `-q`. Invalid directives are ignored and returned as a warning.
+  fn log_filter(&self, configured: LogLevel) -> (LevelFilter, Option<String>) {
+    let (filter, warning) = match std::env::var(LOG_ENV) {
+      Ok(directives) => match LevelFilter::from_directives(configured.clone(), &directives) {
+        Ok(filter) => (filter, None),
+        Err(e) => (LevelFilter::new(configured), Some(i18n::ignoring_log_env(LOG_ENV, e))),
+      },
+      Err(_) => (LevelFilter::new(configured), None),
+    };
+    let level = filter.min_level().shifted(i32::from(self.quiet) - i32::from(self.verbose));
+    (filter.with_min_level(level), warning)
+  }
+
+  /// Returns the name of the run's log file for commands that change the
+  /// system, or `None` if the command is not logged to a file.
+  fn log_name(&self) -> Option<&'static str> {
+    match self.command {
+      Commands::Install { .. } => Some("install"),
+      Commands::Repair { .. } => Some("repair"),
+      Commands::Uninstall { .. } => Some("uninstall"),
+      Commands::Upgrade { .. } => Some("upgrade"),
+      _ => None,
+    }
+  }
+
+  /// Returns `true` if the command runs the packages of a bundle for real,
+  /// where a live progress display replaces the plain console lines.
+  fn shows_progress(&self) -> bool {
+    match self.command {
+      Commands::Install { debug, .. }
+      | Commands::Repair { debug, .. }
+      | Commands::Uninstall { debug, .. }
+      | Commands::Upgrade { debug, .. } => !debug,
+      _ => false,
+    }
+  }
+
+  /// Returns `true` if the command acts on this machine and should compare
+  /// the stored machine with the detected one first.
+  ///
+  /// Read-only commands such as `status` or `doctor` and the commands that
+  /// manage the configuration never change the stored machine.
+  fn reconciles_machine(&self) -> bool {
+    matches!(
+      self.command,
+      Commands::Install { .. }
+        | Commands::Repair { .. }
+        | Commands::Uninstall { .. }
+        | Commands::Upgrade { .. }
+        | Commands::Configure
+    )
+  }
+
+  /// Returns `true` if any machine override was given on the command line.
+  fn overrides_machine(&self) -> bool {
+    self.os.is_some() || self.arch.is_some() || self.os_version.is_some()
+  }
+
+  /// Applies the command line machine overrides to `machine`.
+  fn apply_machine_overrides(&self, machine: &mut config::machine::Machine) {
+    if let Some(os) = self.os {
+      machine.os = os;
+    }
+    if let Some(arch) = self.arch {
+      machine.arch = arch;
+    }
+    if let Some(version) = &self.os_version {
+      machine.version = config::machine::OsVersion::new(version.clone());
+    }
+  }
 }
 
 /// Available CLI commands that users can execute.
@@ -60,6 +205,30 @@ enum Commands {
     /// be executed without actually making any changes to the system.
     #[clap(short, long)]
     debug: bool,
+
+    /// Reinstall packages even if they are detected as already installed
+    #[clap(short, long)]
+    force: bool,
+  },
+
+  /// Repair a Software Bundle
+  ///
+  /// Reinstalls and reconfigures every package of the bundle, even if it is
+  /// detected as already installed. Useful when an installation is broken.
+  #[clap(
+    long_about = "Repair a Software Bundle by reinstalling and reconfiguring all of its packages. \nThe installed checks are ignored, so broken installations are installed again."
+  )]
+  Repair {
+    /// Which Bundle to repair
+    package: Bundles,
+
+    /// Uninstall every package before installing it again
+    #[clap(long)]
+    uninstall_first: bool,
+
+    /// Dry run: show what would be repaired without doing it
+    #[clap(short, long)]
+    debug: bool,
   },
 
   /// Uninstall a Software Bundle
@@ -82,6 +251,55 @@ enum Commands {
     debug: bool,
   },
 
+  /// Upgrade the packages of an installed Software Bundle
+  ///
+  /// Compares the installed version of every package with the newest version
+  /// offered by the package manager and runs the package's upgrade instructions.
+  #[clap(
+    visible_alias = "up",
+    long_about = "Upgrade the packages of an installed Software Bundle. \nPackages that are not installed or already up to date are skipped."
+  )]
+  Upgrade {
+    /// Which Bundle to upgrade
+    #[clap(value_enum, default_value_t)]
+    package: Bundles,
+
+    /// Dry run: show what would be upgraded without doing it
+    ///
+    /// When enabled, this will display all upgrade steps that would
+    /// be executed without actually making any changes to the system.
+    #[clap(short, long)]
+    debug: bool,
+  },
+
+  /// Show the installation status of a Software Bundle
+  ///
+  /// Runs the installed checks and version probes of every package in the
+  /// bundle without installing anything and reports which packages are
+  /// installed, missing, outdated or unsupported on this machine.
+  #[clap(
+    visible_alias = "s",
+    long_about = "Audit this machine against a Software Bundle. \nEvery package is checked read-only and reported as installed, missing, outdated or unsupported."
+  )]
+  Status {
+    /// Which Bundle to audit
+    package: Bundles,
+
+    /// Print the report as JSON instead of a table
+    #[clap(long)]
+    json: bool,
+  },
+
+  /// Diagnose environment problems that break installations
+  ///
+  /// Checks privileges, package managers, network/proxy access, PATH and the
+  /// home directory, and suggests a fix for every problem found.
+  /// Exits with a non-zero status if a blocking problem was detected.
+  #[clap(
+    long_about = "Diagnose environment problems that commonly break installations. \nEvery problem is reported with a suggested fix. The command exits with status 1 if a blocking problem was found."
+  )]
+  Doctor,
+
   /// Update the CLI tool itself
   ///
   /// Downloads and installs the latest version of the projektwoche-setup tool.
@@ -94,14 +312,38 @@ enum Commands {
   ///
   /// Opens an interactive configuration wizard that allows you to customize
   /// the CLI tool's behavior, set preferences, and configure installation options.
-  // #[clap(
-  //   long_about = "Interactive configuration wizard for customizing CLI behavior, setting user preferences, and configuring installation options."
-  // )]
-  // Configure,
+  #[clap(
+    long_about = "Interactive configuration wizard for customizing CLI behavior, setting user preferences, and configuring installation options. \nThe answers decide which packages the bundles install, e.g. Firefox instead of Chrome or Python in addition to JavaScript."
+  )]
+  Configure,
+
+  /// Browse the log files of past runs
+  ///
+  /// Every install, repair, upgrade and uninstall run is logged to its own
+  /// file, so failed runs can be inspected later.
+  Logs {
+    #[clap(subcommand)]
+    action: LogsAction,
+  },
+
+  /// Collect everything needed for troubleshooting into one archive
+  ///
+  /// Zips the logs of recent runs, the effective configuration with secrets
+  /// redacted, the detected machine, OS and package manager versions and the
+  /// doctor report, and prints a short summary to paste into a chat.
+  #[clap(
+    long_about = "Collect logs, configuration and machine information for troubleshooting into one zip archive. \nSecrets in the configuration and the logs are redacted. A short summary is printed that can be pasted into a chat."
+  )]
+  Report {
+    /// Where to write the archive, instead of prowo-report-<time>.zip in the current directory
+    #[clap(short, long)]
+    output: Option<std::path::PathBuf>,
+  },
 
   /// Manage configuration settings
   ///
-  /// Allows you to view and modify various configuration settings such as log levels.
+  /// Allows you to view and modify every configuration setting, such as the
+  /// log level or template variables, and the stored machine information.
   #[clap(visible_alias = "cfg")]
   Config {
     #[clap(subcommand)]
@@ -112,23 +354,42 @@ enum Commands {
 /// Configuration management commands.
 #[derive(Subcommand, Debug)]
 enum ConfigAction {
-  /// Manage log level settings
-  Loglevel {
+  #[clap(flatten)]
+  Settings(SettingsCommand),
+  /// Manage the stored machine information
+  Machine {
     #[clap(subcommand)]
-    action: LogLevelAction,
+    action: MachineAction,
   },
 }
 
-/// Log level management commands.
+/// Log browsing commands.
 #[derive(Subcommand, Debug)]
-enum LogLevelAction {
-  /// Show current log level
-  Default,
-  /// Set log level
-  Set {
-    /// Log level to set
-    level: LogLevel,
+enum LogsAction {
+  /// List the recorded runs, newest first
+  List,
+  /// Print the log of a run
+  Show {
+    /// Run id as shown by `logs list`, or its number in the list (1 = newest)
+    run: String,
   },
+  /// Print the log of the newest run
+  Last,
+}
+
+/// Machine information management commands.
+#[derive(Subcommand, Debug)]
+enum MachineAction {
+  /// Show the stored and the detected machine information and facts
+  Show,
+  /// Detect the machine again and store the result, removing any override
+  Redetect,
+  /// Store machine information by hand instead of detecting it
+  ///
+  /// Takes the global --os, --arch and --os-version options. Options that
+  /// are not given keep their stored value. The override is kept until
+  /// `config machine redetect` is run.
+  Override,
 }
 
 /// Available software bundles that can be installed or uninstalled.
@@ -150,6 +411,28 @@ enum Bundles {
   Projektwoche,
 }
 
+impl Bundles {
+  /// Name of the bundle on the command line, as used in `allowed_bundles`.
+  fn name(self) -> &'static str {
+    match self {
+      Bundles::Projektwoche => "projektwoche",
+    }
+  }
+
+  /// Builds the software bundle behind this CLI value, composed from the
+  /// user's preferences and with the template variables, network settings
+  /// and install path from the configuration.
+  fn load(self, config: &config::Config) -> manager::SoftwareBundle {
+    let bundle = match self {
+      Bundles::Projektwoche => bundles::projektwoche::bundle(&config.preferences),
+    };
+    bundle
+      .with_variables(config.variables.clone())
+      .with_network(config.network.clone())
+      .with_install_path(config.preferences.install_path.as_ref().map(std::path::PathBuf::from))
+  }
+}
+
 /// Application entry point that orchestrates the CLI workflow.
 ///
 /// This function:
@@ -163,90 +446,322 @@ enum Bundles {
 /// Configuration errors are printed to stderr and cause the program to exit.
 /// Installation/uninstallation errors are caught and displayed with context.
 fn main() {
-  let cli = Cli::parse();
+  // The configuration decides the language of the help texts and whether
+  // and where this run is logged to a file, so it is loaded first
+  let loaded = config::use_config();
+  i18n::init(loaded.as_ref().map(|config| config.language).unwrap_or_default());
+
+  let matches = i18n::localize_command(Cli::command()).get_matches();
+  let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
 
   // Initialize logger system for configuration errors
   let (logger_system, mut collector) = LoggerSystem::new();
-  collector.add_output(Box::new(ConsoleOutput::new(true)));
-  collector.add_filter(Box::new(LevelFilter::new(LogLevel::Info)));
-  
+  let timestamp = loaded
+    .as_ref()
+    .map(|config| config.logging.timestamp)
+    .unwrap_or_default();
+  let use_colors = prowo_log::colors_supported();
+  match cli.log_format {
+    LogFormat::Text if cli.shows_progress() && prowo_log::live_display_supported() => {
+      collector.add_output(Box::new(ProgressOutput::new(
+        ConsoleOutput::new(use_colors).with_timestamp(timestamp),
+        use_colors,
+      )))
+    }
+    LogFormat::Text => collector.add_output(Box::new(
+      ConsoleOutput::new(use_colors).with_timestamp(timestamp),
+    )),
+    LogFormat::Json => collector.add_output(Box::new(JsonOutput::new())),
+  }
+  let configured = loaded
+    .as_ref()
+    .map(|config| config.log_level.clone())
+    .unwrap_or_default();
+  let (filter, filter_warning) = cli.log_filter(configured);
+  collector.add_filter(Box::new(filter));
+
+  let mut log_file = Ok(None);
+  let mut prune_warnings = Vec::new();
+  if let (Ok(config), Some(name)) = (&loaded, cli.log_name())
+    && config.logging.file
+  {
+    log_file = match config.logging.directory() {
+      Some(dir) => FileOutput::create(&dir, name, config.logging.format, &config.logging.rotation())
+        .map(|output| {
+          let path = output.path().to_path_buf();
+          prune_warnings = output
+            .prune_failures()
+            .iter()
+            .map(|(path, e)| i18n::log_not_pruned(path.display(), e))
+            .collect();
+          collector.add_output(Box::new(output));
+          Some(path)
+        })
+        .map_err(|e| format!("{}: {}", dir.display(), e)),
+      None => Err(i18n::no_log_directory().to_string()),
+    };
+  }
+
   let (logger_system, collector_handle) = logger_system.start_collector(collector);
   let main_logger = logger_system.create_logger("main", "main".to_string());
+  if let Some(warning) = filter_warning {
+    main_logger.warn(warning);
+  }
+  match &log_file {
+    Ok(Some(path)) => main_logger.debug(i18n::logging_to(path.display())),
+    Ok(None) => {}
+    Err(e) => main_logger.warn(i18n::not_logged(e)),
+  }
+  for warning in prune_warnings {
+    main_logger.warn(warning);
+  }
 
-  match config::use_config() {
-    Ok(config) => {
-      main_logger.debug(format!("Verwende Konfiguration: {:?}", config.machine));
+  let mut exit_code = 0;
+
+  match cli.prompt_settings() {
+    Ok(settings) => config::interactive::init(settings),
+    Err(e) => {
+      main_logger.critical(e.to_string());
+      if logger_system.shutdown() {
+        let _ = collector_handle.join();
+      }
+      std::process::exit(1);
+    }
+  }
+
+  match loaded {
+    Ok(mut config) => {
+      // Overriding the machine for this run makes comparing with the
+      // detected machine pointless
+      if cli.reconciles_machine()
+        && !cli.overrides_machine()
+        && let Err(e) = config::reconcile_machine(&mut config, &main_logger)
+      {
+        main_logger.error(i18n::config_save_failed(e));
+      }
+
+      let mut machine = config.machine.clone();
+      if cli.overrides_machine() {
+        cli.apply_machine_overrides(&mut machine);
+        main_logger.warn(i18n::machine_override_for_run(&machine));
+      }
+
+      main_logger.debug(i18n::using_machine(format!("{:?}", machine)));
       match &cli.command {
-        Commands::Install { debug, package } => {
+        Commands::Install { package, .. }
+        | Commands::Repair { package, .. }
+        | Commands::Uninstall { package, .. }
+        | Commands::Upgrade { package, .. }
+        | Commands::Status { package, .. }
+          if !config.allows_bundle(package.name()) =>
+        {
+          main_logger.error(i18n::bundle_not_allowed(
+            package.name(),
+            config.allowed_bundles.join(", "),
+          ));
+          exit_code = 1;
+        }
+        Commands::Install { debug, package, force } => {
           // Map the selected bundle enum to its implementation
-          let mut bundle = match *package {
-            Bundles::Projektwoche => bundles::projektwoche::bundle(),
-          };
+          let mut bundle = package.load(&config);
 
           // Display installation mode to user
           if *debug {
-            main_logger.info("==> INSTALLATION (DRY-RUN)");
+            main_logger.info(i18n::install_banner_dry_run());
           } else {
-            main_logger.info("==> INSTALLATION");
+            main_logger.info(i18n::install_banner());
           }
 
           // Execute bundle installation with error handling
-          if let Err(e) = bundle.install(&config.machine.os, *debug, &logger_system) {
-            main_logger.error(format!("Fehler bei der Installation: {}", e));
+          let options = manager::InstallOptions {
+            dry_run: *debug,
+            force: *force,
+          };
+          match bundle.install(&machine, options, &logger_system) {
+            Ok(report) if report.has_failures() => exit_code = 1,
+            Ok(_) => {}
+            Err(e) => {
+              main_logger.error(i18n::install_failed(e));
+              exit_code = 1;
+            }
           }
-          main_logger.info("==> Installation abgeschlossen.");
+          main_logger.info(i18n::install_done());
         }
         Commands::Uninstall { debug, package } => {
           // Map the selected bundle enum to its implementation
-          let mut bundle = match *package {
-            Bundles::Projektwoche => bundles::projektwoche::bundle(),
-          };
+          let mut bundle = package.load(&config);
 
           // Display uninstallation mode to user
           if *debug {
-            main_logger.info("==> DEINSTALLATION (DRY-RUN)");
+            main_logger.info(i18n::uninstall_banner_dry_run());
           } else {
-            main_logger.info("==> DEINSTALLATION");
+            main_logger.info(i18n::uninstall_banner());
           }
 
           // Execute bundle uninstallation with error handling
-          if let Err(e) = bundle.uninstall(&config.machine.os, *debug, &logger_system) {
-            main_logger.error(format!("Fehler bei der Deinstallation: {}", e));
+          if let Err(e) = bundle.uninstall(&machine, *debug, &logger_system) {
+            main_logger.error(i18n::uninstall_failed(e));
+          }
+          main_logger.info(i18n::uninstall_done());
+        }
+        Commands::Repair { package, uninstall_first, debug } => {
+          let mut bundle = package.load(&config);
+
+          // Display repair mode to user
+          if *debug {
+            main_logger.info(i18n::repair_banner_dry_run());
+          } else {
+            main_logger.info(i18n::repair_banner());
+          }
+
+          match bundle.repair(&machine, *debug, *uninstall_first, &logger_system) {
+            Ok(report) if report.has_failures() => exit_code = 1,
+            Ok(_) => {}
+            Err(e) => {
+              main_logger.error(i18n::repair_failed(e));
+              exit_code = 1;
+            }
+          }
+          main_logger.info(i18n::repair_done());
+        }
+        Commands::Upgrade { debug, package } => {
+          let mut bundle = package.load(&config);
+
+          // Display upgrade mode to user
+          if *debug {
+            main_logger.info(i18n::upgrade_banner_dry_run());
+          } else {
+            main_logger.info(i18n::upgrade_banner());
+          }
+
+          // Execute bundle upgrade with error handling
+          match bundle.upgrade(&machine, *debug, &logger_system) {
+            Ok(report) if report.has_failures() => exit_code = 1,
+            Ok(_) => {}
+            Err(e) => {
+              main_logger.error(i18n::upgrade_failed(e));
+              exit_code = 1;
+            }
+          }
+          main_logger.info(i18n::upgrade_done());
+        }
+        Commands::Status { package, json } => {
+          let status = package.load(&config).status(&machine);
+
+          if *json {
+            match status.to_json() {
+              Ok(report) => println!("{}", report),
+              Err(e) => main_logger.error(i18n::status_json_failed(e)),
+            }
+          } else {
+            println!("{}", status.render_table());
+          }
+        }
+        Commands::Doctor => {
+          let report = manager::doctor::run(&machine, &config.network);
+          println!("{}", report.render());
+          if report.has_blocking() {
+            exit_code = 1;
           }
-          main_logger.info("==> Deinstallation abgeschlossen.");
         }
         Commands::SelfUpdate => {
-          main_logger.info("==> SELF-UPDATE (noch nicht implementiert)");
+          main_logger.info(i18n::self_update_banner());
           // TODO: Implement self-update functionality
           // This should download and install the latest version of the CLI tool
         }
-        // Commands::Configure => {
-        //   main_logger.info("==> CONFIGURATION WIZARD");
-        //   if let Some(config) = config::interactive::configuration_wizard() {
-        //     main_logger.info(format!("Configuration saved: {:?}", config));
-        //     main_logger.info("Configuration saved successfully.");
-        //   } else {
-        //     main_logger.info("Configuration cancelled by user.");
-        //   }
-        //   main_logger.info("==> Konfiguration abgeschlossen.");
-        // }
+        Commands::Configure => {
+          main_logger.info(i18n::configure_banner());
+          if let Some(preferences) = config::interactive::configuration_wizard(&config.preferences) {
+            let mut new_config = config.clone();
+            new_config.preferences = preferences;
+
+            match config::save_config(&new_config) {
+              Ok(()) => {
+                main_logger.debug(i18n::preferences_saved(format!("{:?}", new_config.preferences)));
+                main_logger.info(i18n::configuration_saved());
+              }
+              Err(e) => {
+                main_logger.error(i18n::config_save_failed(e));
+                exit_code = 1;
+              }
+            }
+          } else {
+            main_logger.info(i18n::configuration_cancelled());
+          }
+          main_logger.info(i18n::configure_done());
+        }
+        Commands::Logs { action } => {
+          if let Err(e) = run_logs_command(action, &config.logging) {
+            main_logger.error(e.to_string());
+            exit_code = 1;
+          }
+        }
+        Commands::Report { output } => {
+          if !run_report_command(Ok(&config), &machine, output.as_deref(), &main_logger) {
+            exit_code = 1;
+          }
+        }
         Commands::Config { action } => {
           match action {
-            ConfigAction::Loglevel { action } => {
+            ConfigAction::Settings(command) => {
+              if let Err(e) = config::store().run::<config::Config>(command) {
+                main_logger.error(e.to_string());
+                exit_code = 1;
+              }
+            }
+            ConfigAction::Machine { action } => {
               match action {
-                LogLevelAction::Default => {
-                  main_logger.info(format!("Current log level: {:?}", config.log_level));
+                MachineAction::Show => {
+                  let detected = config::machine::Machine::detect();
+                  println!("{}", i18n::machine_stored(&config.machine));
+                  println!("{}", i18n::machine_detected(&detected));
+                  if config.machine_override {
+                    println!("{}", i18n::machine_override_active());
+                  } else if config.machine != detected {
+                    println!("{}", i18n::machine_mismatch_hint());
+                  }
+                  if cli.overrides_machine() {
+                    println!("{}", i18n::machine_this_run(&machine));
+                  }
+                  println!();
+                  let entries = machine.facts().entries();
+                  let width = entries.iter().map(|(name, _)| name.chars().count()).max().unwrap_or(0);
+                  for (name, value) in entries {
+                    println!("{:<width$}{}", format!("{}:", name), value, width = width + 2);
+                  }
                 }
-                LogLevelAction::Set { level } => {
+                MachineAction::Redetect => {
                   let mut new_config = config.clone();
-                  new_config.log_level = level.clone();
-                  
+                  new_config.machine = config::machine::Machine::detect();
+                  new_config.machine_override = false;
+
                   match config::save_config(&new_config) {
                     Ok(()) => {
-                      main_logger.info(format!("Log level set to: {:?}", level));
+                      main_logger.info(i18n::machine_set(&new_config.machine));
                     }
                     Err(e) => {
-                      main_logger.error(format!("Failed to save configuration: {}", e));
+                      main_logger.error(i18n::config_save_failed(e));
+                      exit_code = 1;
+                    }
+                  }
+                }
+                MachineAction::Override => {
+                  if !cli.overrides_machine() {
+                    main_logger.error(i18n::nothing_to_override());
+                    exit_code = 1;
+                  } else {
+                    let mut new_config = config.clone();
+                    cli.apply_machine_overrides(&mut new_config.machine);
+                    new_config.machine_override = true;
+
+                    match config::save_config(&new_config) {
+                      Ok(()) => {
+                        main_logger.info(i18n::machine_overridden(&new_config.machine));
+                      }
+                      Err(e) => {
+                        main_logger.error(i18n::config_save_failed(e));
+                        exit_code = 1;
+                      }
                     }
                   }
                 }
@@ -257,11 +772,129 @@ fn main() {
       }
     }
     Err(e) => {
-      main_logger.critical(format!("Fehler beim Laden/Erstellen der Konfiguration: {}", e));
+      main_logger.critical(i18n::config_load_failed(&e));
+      exit_code = 1;
+
+      // These commands do not need a valid configuration and help fixing it
+      if let Commands::Config {
+        action:
+          ConfigAction::Settings(
+            command @ (SettingsCommand::Path { .. } | SettingsCommand::Edit | SettingsCommand::Reset),
+          ),
+      } = &cli.command
+      {
+        match config::store().run::<config::Config>(command) {
+          Ok(()) => exit_code = 0,
+          Err(e) => main_logger.error(e.to_string()),
+        }
+      } else if let Commands::Report { output } = &cli.command {
+        // The report then carries the raw configuration files instead
+        let mut machine = config::machine::Machine::detect();
+        cli.apply_machine_overrides(&mut machine);
+        if run_report_command(Err(e.as_ref()), &machine, output.as_deref(), &main_logger) {
+          exit_code = 0;
+        }
+      } else {
+        main_logger.info(i18n::config_fix_hint());
+      }
+    }
+  }
+
+  if exit_code != 0
+    && let Ok(Some(path)) = &log_file
+  {
+    main_logger.info(i18n::run_log(path.display()));
+  }
+
+  // Write the remaining messages; loggers still held by this function or
+  // the bundle no longer keep the collector alive
+  if logger_system.shutdown() {
+    let _ = collector_handle.join();
+  }
+
+  if exit_code != 0 {
+    std::process::exit(exit_code);
+  }
+}
+
+/// Writes the support bundle to `output`, or to prowo-report-<time>.zip in
+/// the current directory, and prints its summary.
+///
+/// Returns `false` if the archive could not be written.
+fn run_report_command(
+  config: Result<&config::Config, &dyn std::error::Error>,
+  machine: &config::machine::Machine,
+  output: Option<&std::path::Path>,
+  logger: &prowo_log::Logger,
+) -> bool {
+  let path = output.map(std::path::Path::to_path_buf).unwrap_or_else(|| {
+    format!("prowo-report-{}.zip", chrono::Local::now().format("%Y%m%d-%H%M%S")).into()
+  });
+  match manager::support::create(config, machine, &path) {
+    Ok(summary) => {
+      println!("{}", summary);
+      true
+    }
+    Err(e) => {
+      logger.error(i18n::report_failed(path.display(), e));
+      false
     }
   }
-  
-  // Properly shutdown the logger system
-  logger_system.shutdown();
-  let _ = collector_handle.join();
+}
+
+/// Lists the recorded runs or prints the log of one of them.
+fn run_logs_command(
+  action: &LogsAction,
+  settings: &config::logging::LoggingConfig,
+) -> Result<(), Box<dyn std::error::Error>> {
+  let dir = settings
+    .directory()
+    .ok_or(i18n::no_log_directory())?;
+  let runs = prowo_log::list_runs(&dir)?;
+
+  let run = match action {
+    LogsAction::List => {
+      println!("{}", i18n::log_directory(dir.display()));
+      if runs.is_empty() {
+        println!("{}", i18n::no_runs());
+        return Ok(());
+      }
+      println!();
+      println!(
+        "  #  {:<28} {:<19} {:>9}  {}",
+        i18n::column_run(),
+        i18n::column_started(),
+        i18n::column_size(),
+        i18n::column_problems()
+      );
+      for (index, run) in runs.iter().enumerate() {
+        let problems = match run.problems() {
+          Ok((0, 0)) => "-".to_string(),
+          Ok((warnings, errors)) => i18n::run_problems(errors, warnings),
+          Err(e) => i18n::run_unreadable(e),
+        };
+        println!(
+          "{:>3}  {:<28} {:<19} {:>7} K  {}",
+          index + 1,
+          run.id,
+          run.started(),
+          run.size.div_ceil(1024),
+          problems
+        );
+      }
+      return Ok(());
+    }
+    LogsAction::Last => runs.first().ok_or(i18n::no_runs())?,
+    LogsAction::Show { run } => match run.parse::<usize>() {
+      Ok(number) if (1..=runs.len()).contains(&number) => &runs[number - 1],
+      _ => runs
+        .iter()
+        .find(|candidate| candidate.id == *run)
+        .ok_or_else(|| i18n::run_not_found(run))?,
+    },
+  };
+
+  eprintln!("==> {}", run.path.display());
+  print!("{}", std::fs::read_to_string(&run.path)?);
+  Ok(())
 }
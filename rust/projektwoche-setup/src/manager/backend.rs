@@ -0,0 +1,263 @@
+//! # System Package Manager Backends
+//!
+//! This module knows how to detect and drive the system package managers that
+//! [`InstallApplication`](super::instructions::InstallApplication) relies on.
+//! Keeping the probes in one place lets other features, such as the `doctor`
+//! command, ask the same questions the installer does.
+//!
+//! ## Supported Backends
+//!
+//! - **Linux**: apt, yum, dnf, pacman, zypper
+//! - **macOS**: brew
+//! - **Windows**: choco, winget
+
+use super::template::ExecutionContext;
+use super::version::Version;
+use crate::config::network::NetworkConfig;
+use std::path::Path;
+
+/// A system package manager that can install applications.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Backend {
+  /// Debian/Ubuntu Advanced Package Tool
+  Apt,
+  /// Legacy Red Hat package manager
+  Yum,
+  /// Fedora/RHEL package manager
+  Dnf,
+  /// Arch Linux package manager
+  Pacman,
+  /// openSUSE package manager
+  Zypper,
+  /// Homebrew (macOS and Linux)
+  Brew,
+  /// Chocolatey (Windows)
+  Choco,
+  /// Windows Package Manager
+  Winget,
+}
+
+/// Backends tried on Unix-like systems, in order of preference.
+const UNIX_BACKENDS: &[Backend] = &[
+  Backend::Apt,
+  Backend::Yum,
+  Backend::Dnf,
+  Backend::Pacman,
+  Backend::Zypper,
+  Backend::Brew,
+];
+
+/// Backends tried on Windows, in order of preference.
+const WINDOWS_BACKENDS: &[Backend] = &[Backend::Choco, Backend::Winget];
+
+impl Backend {
+  /// Returns the backends that are relevant on this platform, in the order
+  /// the installer tries them.
+  pub fn candidates() -> &'static [Backend] {
+    if cfg!(windows) { WINDOWS_BACKENDS } else { UNIX_BACKENDS }
+  }
+
+  /// Returns all backends of this platform that are currently installed.
+  pub fn available(context: &ExecutionContext) -> Vec<Backend> {
+    Self::candidates()
+      .iter()
+      .copied()
+      .filter(|backend| backend.is_available(context))
+      .collect()
+  }
+
+  /// Name of the executable behind this backend.
+  pub fn program(&self) -> &'static str {
+    match self {
+      Backend::Apt => "apt",
+      Backend::Yum => "yum",
+      Backend::Dnf => "dnf",
+      Backend::Pacman => "pacman",
+      Backend::Zypper => "zypper",
+      Backend::Brew => "brew",
+      Backend::Choco => "choco",
+      Backend::Winget => "winget",
+    }
+  }
+
+  /// Checks whether the backend's executable can be found.
+  ///
+  /// Unix backends are located with `which`, Windows backends are probed by
+  /// running them with `--version`.
+  pub fn is_available(&self, context: &ExecutionContext) -> bool {
+    let probe = match self {
+      Backend::Choco | Backend::Winget => context.command(self.program()).arg("--version").output(),
+      _ => context.command("which").arg(self.program()).output(),
+    };
+    probe.map(|o| o.status.success()).unwrap_or(false)
+  }
+
+  /// Returns the version string reported by the backend, if it runs.
+  pub fn version(&self, context: &ExecutionContext) -> Option<String> {
+    let output = context.command(self.program()).arg("--version").output().ok()?;
+    if !output.status.success() {
+      return None;
+    }
+    String::from_utf8_lossy(&output.stdout)
+      .lines()
+      .next()
+      .map(|line| line.trim().to_string())
+  }
+
+  /// Builds the command line that installs `package` with this backend,
+  /// passing on the proxy and CA settings of `network`.
+  pub fn install_command(&self, package: &str, network: &NetworkConfig) -> Vec<String> {
+    let args: Vec<&str> = match self {
+      Backend::Apt => vec!["apt", "install", "-y", package],
+      Backend::Yum => vec!["yum", "install", "-y", package],
+      Backend::Dnf => vec!["dnf", "install", "-y", package],
+      Backend::Pacman => vec!["pacman", "-S", "--noconfirm", package],
+      Backend::Zypper => vec!["zypper", "install", "-y", package],
+      Backend::Brew => vec!["brew", "install", package],
+      Backend::Choco => vec!["choco", "install", package, "-y"],
+      Backend::Winget => vec!["winget", "install", "--id", package, "-e"],
+    };
+    self.with_network_options(args, network)
+  }
+
+  /// Builds the command line that refreshes the backend's package lists,
+  /// passing on the proxy and CA settings of `network`.
+  ///
+  /// Returns `None` for backends without a local package index (Chocolatey).
+  pub fn update_command(&self, network: &NetworkConfig) -> Option<Vec<String>> {
+    let args: Vec<&str> = match self {
+      Backend::Apt => vec!["apt", "update"],
+      Backend::Yum => vec!["yum", "makecache"],
+      Backend::Dnf => vec!["dnf", "makecache"],
+      Backend::Pacman => vec!["pacman", "-Sy"],
+      Backend::Zypper => vec!["zypper", "refresh"],
+      Backend::Brew => vec!["brew", "update"],
+      Backend::Winget => vec!["winget", "source", "update"],
+      Backend::Choco => return None,
+    };
+    Some(self.with_network_options(args, network))
+  }
+
+  /// Returns `true` if this backend can install for the current user
+  /// without administrator rights.
+  pub fn supports_user_scope(&self) -> bool {
+    matches!(self, Backend::Winget | Backend::Brew)
+  }
+
+  /// Returns the options that restrict an installation to the current user.
+  pub fn user_scope_options(&self) -> Vec<String> {
+    match self {
+      Backend::Winget => vec!["--scope".to_string(), "user".to_string()],
+      _ => Vec::new(),
+    }
+  }
+
+  /// Returns the options that install `package` into its own directory
+  /// below `install_path`.
+  ///
+  /// Only winget and Chocolatey support this; system package managers always
+  /// install into their fixed locations and get no options.
+  pub fn location_options(&self, package: &str, install_path: &Path) -> Vec<String> {
+    let location = install_path.join(package).display().to_string();
+    match self {
+      Backend::Winget => vec!["--location".to_string(), location],
+      Backend::Choco => vec![format!("--install-directory={}", location)],
+      _ => Vec::new(),
+    }
+  }
+
+  /// Builds the command line that upgrades `package` to the newest version
+  /// this backend offers, passing on the proxy and CA settings of `network`.
+  pub fn upgrade_command(&self, package: &str, network: &NetworkConfig) -> Vec<String> {
+    let args: Vec<&str> = match self {
+      Backend::Apt => vec!["apt", "install", "--only-upgrade", "-y", package],
+      Backend::Yum => vec!["yum", "upgrade", "-y", package],
+      Backend::Dnf => vec!["dnf", "upgrade", "-y", package],
+      Backend::Pacman => vec!["pacman", "-S", "--noconfirm", package],
+      Backend::Zypper => vec!["zypper", "update", "-y", package],
+      Backend::Brew => vec!["brew", "upgrade", package],
+      Backend::Choco => vec!["choco", "upgrade", package, "-y"],
+      Backend::Winget => vec!["winget", "upgrade", "--id", package, "-e"],
+    };
+    self.with_network_options(args, network)
+  }
+
+  /// Inserts the backend's own proxy and CA options after the program name.
+  ///
+  /// Backends that read the standard proxy environment variables get none.
+  fn with_network_options(&self, args: Vec<&str>, network: &NetworkConfig) -> Vec<String> {
+    let mut options = Vec::new();
+    let ca_bundle = network.ca_bundle.as_ref().map(|path| path.display().to_string());
+    match self {
+      Backend::Apt => {
+        if let Some(proxy) = &network.proxy {
+          options.push(format!("-oAcquire::http::Proxy={}", proxy));
+          options.push(format!("-oAcquire::https::Proxy={}", proxy));
+        }
+        if let Some(ca_bundle) = &ca_bundle {
+          options.push(format!("-oAcquire::https::CAInfo={}", ca_bundle));
+        }
+      }
+      Backend::Yum | Backend::Dnf => {
+        if let Some(proxy) = &network.proxy {
+          options.push(format!("--setopt=proxy={}", proxy));
+        }
+        if let Some(ca_bundle) = &ca_bundle {
+          options.push(format!("--setopt=sslcacert={}", ca_bundle));
+        }
+      }
+      Backend::Choco => {
+        if let Some(proxy) = &network.proxy {
+          options.push(format!("--proxy={}", proxy));
+        }
+      }
+      Backend::Pacman | Backend::Zypper | Backend::Brew | Backend::Winget => {}
+    }
+
+    let mut command = vec![args[0].to_string()];
+    command.extend(options);
+    command.extend(args[1..].iter().map(|s| s.to_string()));
+    command
+  }
+
+  /// Queries the newest version of `package` that this backend can install,
+  /// through the proxy and CA settings of `context`.
+  ///
+  /// Returns `None` if the backend does not know the package or its output
+  /// could not be parsed.
+  pub fn latest_version(&self, package: &str, context: &ExecutionContext) -> Option<Version> {
+    let (args, marker): (Vec<&str>, &str) = match self {
+      Backend::Apt => (vec!["apt-cache", "policy", package], "Candidate:"),
+      Backend::Yum => (vec!["yum", "info", "-q", package], "Version"),
+      Backend::Dnf => (vec!["dnf", "info", "-q", package], "Version"),
+      Backend::Pacman => (vec!["pacman", "-Si", package], "Version"),
+      Backend::Zypper => (vec!["zypper", "info", package], "Version"),
+      Backend::Brew => (vec!["brew", "info", package], "stable"),
+      Backend::Choco => (
+        vec!["choco", "search", package, "--exact", "--limit-output"],
+        "|",
+      ),
+      Backend::Winget => (vec!["winget", "show", "--id", package, "-e"], "Version:"),
+    };
+
+    let args = self.with_network_options(args, context.network());
+    let output = context.command(&args[0]).args(&args[1..]).output().ok()?;
+    if !output.status.success() {
+      return None;
+    }
+
+    String::from_utf8_lossy(&output.stdout)
+      .lines()
+      .filter(|line| line.contains(marker))
+      .find_map(|line| {
+        let value = line.split_once(marker).map(|(_, rest)| rest).unwrap_or(line);
+        Version::extract(value)
+      })
+  }
+}
+
+impl std::fmt::Display for Backend {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.program())
+  }
+}
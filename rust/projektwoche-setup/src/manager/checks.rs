@@ -0,0 +1,220 @@
+//! # Checks
+//!
+//! Checks are read-only conditions evaluated against the current machine.
+//! Packages use them for two different purposes:
+//!
+//! - **Installed detection**: decides whether a package is already present
+//!   and its installation can be skipped
+//! - **Prerequisites**: conditions that must hold before installing at all,
+//!   such as "curl is available" or "2 GB of free disk space"
+//!
+//! Checks can be combined with [`Check::all`], [`Check::any`] and
+//! [`Check::not`] to express more complex conditions.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! // Either `code` or `code-insiders` must report a version
+//! let installed = Check::any(vec![
+//!   Instruction::new("Check VS Code").assert("code --version", ".").try_into()?,
+//!   Instruction::new("Check VS Code Insiders").assert("code-insiders --version", ".").try_into()?,
+//! ]);
+//!
+//! // Installing requires curl, administrator rights and 2 GB of free disk space
+//! let prerequisites = Check::all(vec![
+//!   Check::command("curl"),
+//!   Check::admin(),
+//!   Check::free_disk_mb(2048),
+//! ]);
+//! ```
+//!
+//! Checks never change the system, so they are executed for real even in
+//! dry-run mode.
+
+use super::instructions::{Assert, Instructions};
+use super::template::ExecutionContext;
+use crate::config::machine::{Fact, Facts, Privileges, command_exists};
+use crate::i18n;
+use std::path::Path;
+use std::process::Command;
+
+/// A read-only condition that can be evaluated on the current machine.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Check {
+  /// A command's output must contain the expected text
+  Assert(Assert),
+  /// An executable must be available on PATH
+  CommandExists(&'static str),
+  /// The tool must run with administrator rights or be able to use sudo
+  Admin,
+  /// The home directory's drive must have at least this many megabytes free
+  FreeDisk(u64),
+  /// A detected machine fact must hold, e.g. running inside WSL
+  Fact(Fact),
+  /// Every contained check must pass
+  All(Vec<Check>),
+  /// At least one contained check must pass
+  Any(Vec<Check>),
+  /// The contained check must fail
+  Not(Box<Check>),
+}
+
+impl Check {
+  /// Requires an executable to be available on PATH.
+  pub fn command(program: &'static str) -> Self {
+    Check::CommandExists(program)
+  }
+
+  /// Requires administrator rights (root, sudo or an elevated Windows shell).
+  pub fn admin() -> Self {
+    Check::Admin
+  }
+
+  /// Requires at least `megabytes` of free disk space in the home directory.
+  pub fn free_disk_mb(megabytes: u64) -> Self {
+    Check::FreeDisk(megabytes)
+  }
+
+  /// Requires a machine fact, e.g. [`Fact::Wsl`].
+  pub fn fact(fact: Fact) -> Self {
+    Check::Fact(fact)
+  }
+
+  /// Passes only if every check passes.
+  pub fn all(checks: Vec<Check>) -> Self {
+    Check::All(checks)
+  }
+
+  /// Passes if at least one check passes.
+  pub fn any(checks: Vec<Check>) -> Self {
+    Check::Any(checks)
+  }
+
+  /// Inverts a check.
+  #[allow(clippy::should_implement_trait)]
+  pub fn not(check: Check) -> Self {
+    Check::Not(Box::new(check))
+  }
+
+  /// Evaluates the check.
+  ///
+  /// Placeholders in assertions are resolved from `context`.
+  ///
+  /// # Returns
+  ///
+  /// Returns `Ok` with the output of the first passing assertion (if any),
+  /// which callers use to read version numbers. Returns `Err` with a
+  /// human-readable reason if the check does not hold.
+  pub fn evaluate(&self, context: &ExecutionContext) -> Result<Option<String>, String> {
+    match self {
+      Check::Assert(assert) => assert.probe(context).map(Some).map_err(|e| e.to_string()),
+      Check::CommandExists(program) => {
+        if command_exists(program) {
+          Ok(None)
+        } else {
+          Err(format!("'{}' was not found on PATH", program))
+        }
+      }
+      Check::Admin => {
+        if Facts::host().privileges != Privileges::Unprivileged {
+          Ok(None)
+        } else {
+          Err("administrator rights are not available".to_string())
+        }
+      }
+      Check::FreeDisk(required) => {
+        let home = dirs::home_dir().ok_or("home directory could not be determined")?;
+        match free_space_mb(&home) {
+          Some(free) if free >= *required => Ok(None),
+          Some(free) => Err(format!("only {} MB free, {} MB required", free, required)),
+          None => Err("free disk space could not be determined".to_string()),
+        }
+      }
+      Check::Fact(fact) => {
+        if Facts::host().has(fact) {
+          Ok(None)
+        } else {
+          Err(format!("this machine is not: {}", fact))
+        }
+      }
+      Check::All(checks) => {
+        let mut output = None;
+        for check in checks {
+          let result = check.evaluate(context)?;
+          output = output.or(result);
+        }
+        Ok(output)
+      }
+      Check::Any(checks) => {
+        let mut reasons = Vec::new();
+        for check in checks {
+          match check.evaluate(context) {
+            Ok(output) => return Ok(output),
+            Err(reason) => reasons.push(reason),
+          }
+        }
+        Err(reasons.join("; "))
+      }
+      Check::Not(check) => match check.evaluate(context) {
+        Ok(_) => Err(format!("expected not: {}", check.describe())),
+        Err(_) => Ok(None),
+      },
+    }
+  }
+
+  /// Returns a short human-readable description of the condition.
+  pub fn describe(&self) -> String {
+    match self {
+      Check::Assert(assert) => assert.describe(),
+      Check::CommandExists(program) => format!("'{}' is installed", program),
+      Check::Admin => "administrator rights".to_string(),
+      Check::FreeDisk(megabytes) => format!("{} MB free disk space", megabytes),
+      Check::Fact(fact) => fact.to_string(),
+      Check::All(checks) => join_descriptions(checks, " and "),
+      Check::Any(checks) => join_descriptions(checks, " or "),
+      Check::Not(check) => format!("not {}", check.describe()),
+    }
+  }
+}
+
+impl TryFrom<Instructions> for Check {
+  type Error = String;
+
+  /// Converts an [`Assert`] instruction into a check.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error naming the instruction if it is not an Assert, since
+  /// only assertions are free of side effects.
+  fn try_from(instruction: Instructions) -> Result<Self, Self::Error> {
+    match instruction {
+      Instructions::Assert(assert) => Ok(Check::Assert(assert)),
+      other => Err(i18n::not_an_assertion(other.describe())),
+    }
+  }
+}
+
+fn join_descriptions(checks: &[Check], separator: &str) -> String {
+  let parts: Vec<String> = checks.iter().map(|c| c.describe()).collect();
+  format!("({})", parts.join(separator))
+}
+
+/// Returns the free space in megabytes on the filesystem containing `path`.
+pub(crate) fn free_space_mb(path: &Path) -> Option<u64> {
+  if cfg!(windows) {
+    let drive = path.to_string_lossy().chars().next()?;
+    let script = format!("(Get-PSDrive {}).Free", drive);
+    let output = Command::new("powershell")
+      .args(["-NoProfile", "-Command", &script])
+      .output()
+      .ok()?;
+    let bytes: u64 = String::from_utf8_lossy(&output.stdout).trim().parse().ok()?;
+    Some(bytes / 1024 / 1024)
+  } else {
+    let output = Command::new("df").arg("-Pk").arg(path).output().ok()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    // Second line, fourth column: available 1K blocks
+    let available: u64 = stdout.lines().nth(1)?.split_whitespace().nth(3)?.parse().ok()?;
+    Some(available / 1024)
+  }
+}
@@ -0,0 +1,375 @@
+//! # Environment Diagnostics
+//!
+//! Most failed installs are not caused by the packages themselves but by the
+//! environment they run in: missing administrator rights, no package manager,
+//! a proxy the tools do not know about, or a home directory that cannot be
+//! written. This module runs a set of read-only diagnostics that catch those
+//! problems up front and suggest how to fix them.
+//!
+//! Diagnostics are grouped by severity:
+//! - **Ok**: nothing to do
+//! - **Warning**: installs may partially fail or need manual steps
+//! - **Blocking**: installs will fail until the problem is fixed
+
+use super::backend::Backend;
+use super::template::ExecutionContext;
+use crate::config::machine::{Machine, OsCategory, OsMatcher};
+use crate::config::network::NetworkConfig;
+use crate::i18n;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// How severe a diagnosed problem is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+  /// The check passed
+  Ok,
+  /// Something looks off but installations can still proceed
+  Warning,
+  /// Installations will fail until this is fixed
+  Blocking,
+}
+
+impl std::fmt::Display for Severity {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Severity::Ok => write!(f, " OK "),
+      Severity::Warning => write!(f, "WARN"),
+      Severity::Blocking => write!(f, "FAIL"),
+    }
+  }
+}
+
+/// Result of a single diagnostic.
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+  /// Short name of what was checked
+  pub name: &'static str,
+  /// Outcome of the check
+  pub severity: Severity,
+  /// What was found
+  pub message: String,
+  /// Suggested fix, if there is a problem
+  pub fix: Option<String>,
+}
+
+impl Diagnostic {
+  fn ok(name: &'static str, message: impl Into<String>) -> Self {
+    Self {
+      name,
+      severity: Severity::Ok,
+      message: message.into(),
+      fix: None,
+    }
+  }
+
+  fn problem(
+    name: &'static str,
+    severity: Severity,
+    message: impl Into<String>,
+    fix: impl Into<String>,
+  ) -> Self {
+    Self {
+      name,
+      severity,
+      message: message.into(),
+      fix: Some(fix.into()),
+    }
+  }
+}
+
+/// Collected results of a doctor run.
+#[derive(Debug, Clone)]
+pub struct DoctorReport {
+  /// All diagnostics in the order they were run
+  pub diagnostics: Vec<Diagnostic>,
+}
+
+impl DoctorReport {
+  /// Returns `true` if at least one diagnostic blocks installations.
+  pub fn has_blocking(&self) -> bool {
+    self
+      .diagnostics
+      .iter()
+      .any(|d| d.severity == Severity::Blocking)
+  }
+
+  /// Renders the report as human-readable lines, including suggested fixes.
+  pub fn render(&self) -> String {
+    let mut lines = Vec::new();
+    for diagnostic in &self.diagnostics {
+      lines.push(format!(
+        "[{}] {}: {}",
+        diagnostic.severity, diagnostic.name, diagnostic.message
+      ));
+      if let Some(fix) = &diagnostic.fix {
+        lines.push(i18n::doctor_fix(fix));
+      }
+    }
+
+    let blocking = self
+      .diagnostics
+      .iter()
+      .filter(|d| d.severity == Severity::Blocking)
+      .count();
+    let warnings = self
+      .diagnostics
+      .iter()
+      .filter(|d| d.severity == Severity::Warning)
+      .count();
+    lines.push(String::new());
+    lines.push(i18n::doctor_totals(blocking, warnings));
+    lines.join("\n")
+  }
+}
+
+/// Runs every diagnostic for the given machine.
+///
+/// # Arguments
+///
+/// * `machine` - Detected machine information used to pick platform-specific checks
+/// * `network` - Configured proxy and CA settings, used for the connectivity check
+pub fn run(machine: &Machine, network: &NetworkConfig) -> DoctorReport {
+  let windows = OsMatcher::from_category(OsCategory::Windows).matches(&machine.os);
+  let context = ExecutionContext::new(machine, false).with_network(network);
+
+  let diagnostics = vec![
+    check_operating_system(machine),
+    check_package_manager(windows, &context),
+    check_privileges(windows),
+    check_network(network),
+    check_home_writable(),
+    check_bun_path(windows),
+  ];
+
+  DoctorReport { diagnostics }
+}
+
+/// Runs a command and reports whether it exited successfully.
+fn succeeds(program: &str, args: &[&str]) -> bool {
+  Command::new(program)
+    .args(args)
+    .output()
+    .map(|o| o.status.success())
+    .unwrap_or(false)
+}
+
+fn check_operating_system(machine: &Machine) -> Diagnostic {
+  let supported = OsMatcher::from_categories(&[OsCategory::Windows, OsCategory::LinuxBased])
+    .matches(&machine.os);
+  if supported {
+    Diagnostic::ok(i18n::check_os(), i18n::os_supported(machine))
+  } else {
+    Diagnostic::problem(
+      i18n::check_os(),
+      Severity::Warning,
+      i18n::os_unsupported(machine),
+      i18n::os_unsupported_fix(),
+    )
+  }
+}
+
+fn check_package_manager(windows: bool, context: &ExecutionContext) -> Diagnostic {
+  let available = Backend::available(context);
+
+  if windows && !available.contains(&Backend::Winget) {
+    let severity = if available.is_empty() {
+      Severity::Blocking
+    } else {
+      Severity::Warning
+    };
+    return Diagnostic::problem(
+      i18n::check_package_manager(),
+      severity,
+      i18n::winget_missing(),
+      i18n::winget_missing_fix(),
+    );
+  }
+
+  match available.first() {
+    Some(backend) => Diagnostic::ok(
+      i18n::check_package_manager(),
+      i18n::using_package_manager(backend.version(context).unwrap_or_else(|| backend.to_string())),
+    ),
+    None => Diagnostic::problem(
+      i18n::check_package_manager(),
+      Severity::Blocking,
+      i18n::no_package_manager(),
+      i18n::no_package_manager_fix(),
+    ),
+  }
+}
+
+fn check_privileges(windows: bool) -> Diagnostic {
+  if windows {
+    // `net session` only succeeds in an elevated shell
+    return if succeeds("net", &["session"]) {
+      Diagnostic::ok(i18n::check_privileges(), i18n::running_as_admin())
+    } else {
+      Diagnostic::problem(
+        i18n::check_privileges(),
+        Severity::Warning,
+        i18n::not_admin(),
+        i18n::not_admin_fix(),
+      )
+    };
+  }
+
+  let uid = Command::new("id")
+    .arg("-u")
+    .output()
+    .ok()
+    .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string());
+  if uid.as_deref() == Some("0") {
+    return Diagnostic::ok(i18n::check_privileges(), i18n::running_as_root());
+  }
+
+  if !succeeds("which", &["sudo"]) {
+    return Diagnostic::problem(
+      i18n::check_privileges(),
+      Severity::Blocking,
+      i18n::sudo_missing(),
+      i18n::sudo_missing_fix(),
+    );
+  }
+
+  if succeeds("sudo", &["-n", "true"]) {
+    return Diagnostic::ok(i18n::check_privileges(), i18n::sudo_passwordless());
+  }
+
+  let groups = Command::new("id")
+    .arg("-Gn")
+    .output()
+    .map(|o| String::from_utf8_lossy(&o.stdout).to_string())
+    .unwrap_or_default();
+  if groups
+    .split_whitespace()
+    .any(|g| matches!(g, "sudo" | "wheel" | "admin"))
+  {
+    Diagnostic::ok(i18n::check_privileges(), i18n::sudo_available())
+  } else {
+    Diagnostic::problem(
+      i18n::check_privileges(),
+      Severity::Blocking,
+      i18n::no_sudo_rights(),
+      i18n::no_sudo_rights_fix(),
+    )
+  }
+}
+
+fn check_network(network: &NetworkConfig) -> Diagnostic {
+  if !succeeds("curl", &["--version"]) {
+    return Diagnostic::problem(
+      i18n::check_network(),
+      Severity::Blocking,
+      i18n::curl_missing(),
+      i18n::curl_missing_fix(),
+    );
+  }
+
+  if let Some(ca_bundle) = network.ca_bundle.as_ref().filter(|path| !path.is_file()) {
+    return Diagnostic::problem(
+      i18n::check_network(),
+      Severity::Blocking,
+      i18n::ca_bundle_missing(ca_bundle.display()),
+      i18n::ca_bundle_missing_fix(),
+    );
+  }
+
+  let proxy = network.proxy.clone().or_else(|| {
+    [
+      "HTTPS_PROXY",
+      "https_proxy",
+      "HTTP_PROXY",
+      "http_proxy",
+      "ALL_PROXY",
+    ]
+    .iter()
+    .find_map(|name| std::env::var(name).ok().filter(|v| !v.is_empty()))
+  });
+
+  let reachable = Command::new("curl")
+    .args(["-sS", "-I", "--max-time", "10", "-o", null_device(), "https://github.com"])
+    .envs(network.environment())
+    .output()
+    .map(|o| o.status.success())
+    .unwrap_or(false);
+
+  match (reachable, proxy) {
+    (true, Some(proxy)) => Diagnostic::ok(i18n::check_network(), i18n::reachable_via_proxy(proxy)),
+    (true, None) => Diagnostic::ok(i18n::check_network(), i18n::reachable()),
+    (false, Some(proxy)) => Diagnostic::problem(
+      i18n::check_network(),
+      Severity::Blocking,
+      i18n::unreachable_via_proxy(proxy),
+      i18n::unreachable_via_proxy_fix(),
+    ),
+    (false, None) => Diagnostic::problem(
+      i18n::check_network(),
+      Severity::Blocking,
+      i18n::unreachable(),
+      i18n::unreachable_fix(),
+    ),
+  }
+}
+
+fn null_device() -> &'static str {
+  if cfg!(windows) { "NUL" } else { "/dev/null" }
+}
+
+fn check_home_writable() -> Diagnostic {
+  let Some(home) = dirs::home_dir() else {
+    return Diagnostic::problem(
+      i18n::check_home(),
+      Severity::Blocking,
+      i18n::home_unknown(),
+      i18n::home_unknown_fix(),
+    );
+  };
+
+  let test_file = home.join(".prowo-setup-write-test");
+  match std::fs::write(&test_file, "test") {
+    Ok(()) => {
+      let _ = std::fs::remove_file(&test_file);
+      Diagnostic::ok(i18n::check_home(), i18n::home_writable(home.display()))
+    }
+    Err(e) => Diagnostic::problem(
+      i18n::check_home(),
+      Severity::Blocking,
+      i18n::home_not_writable(home.display(), e),
+      i18n::home_not_writable_fix(),
+    ),
+  }
+}
+
+fn check_bun_path(windows: bool) -> Diagnostic {
+  let Some(bun_bin) = dirs::home_dir().map(|home| home.join(".bun").join("bin")) else {
+    return Diagnostic::ok("PATH", i18n::bun_check_skipped());
+  };
+
+  if !bun_bin.exists() {
+    return Diagnostic::ok("PATH", i18n::bun_not_installed());
+  }
+
+  if path_contains(&bun_bin) {
+    Diagnostic::ok("PATH", i18n::on_path(bun_bin.display()))
+  } else {
+    let fix = if windows {
+      i18n::add_to_path_windows(bun_bin.display())
+    } else {
+      i18n::add_to_path_unix().to_string()
+    };
+    Diagnostic::problem(
+      "PATH",
+      Severity::Warning,
+      i18n::not_on_path(bun_bin.display()),
+      fix,
+    )
+  }
+}
+
+fn path_contains(dir: &Path) -> bool {
+  std::env::var_os("PATH")
+    .map(|path| std::env::split_paths(&path).any(|entry: PathBuf| entry == dir))
+    .unwrap_or(false)
+}
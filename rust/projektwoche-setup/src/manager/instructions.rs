@@ -10,6 +10,8 @@
 //! - **Trait-based design**: All instructions implement [`AnyInstruction`]
 //! - **Cross-platform support**: Instructions handle platform differences automatically
 //! - **Dry-run capability**: All instructions support preview mode without making changes
+//! - **Templates**: Instruction strings may contain placeholders such as `{arch}`
+//!   or `{home}` (see [`template`](super::template))
 //! - **Builder pattern**: Instructions are created using a fluent builder API
 //!
 //! ## Available Instruction Types
@@ -23,7 +25,11 @@
 //!
 //! ### System Operations  
 //! - [`Run`]: Execute shell commands
-//! - [`InstallPackage`]: Install packages using system package managers
+//! - [`RunScript`]: Run a script through the platform shell
+//! - [`InstallApplication`]: Install applications using system package managers
+//! - [`UpgradeApplication`]: Upgrade applications using system package managers
+//! - [`UpdatePackageLists`]: Refresh the package lists of the system package manager
+//! - [`InstallPackage`]: Install packages using language package managers
 //! - [`RestartService`]: Restart system services
 //! - [`RequestSudo`]: Request administrator privileges
 //!
@@ -46,14 +52,19 @@
 //!   .download_and_exec_silent("https://nodejs.org/dist/latest/node-x64.msi");
 //!
 //! // Execute with dry-run to preview
-//! install_node.run(true)?; // Prints what would happen
-//! install_node.run(false)?; // Actually executes
+//! install_node.run(&ExecutionContext::new(&machine, true))?; // Prints what would happen
+//! install_node.run(&ExecutionContext::new(&machine, false))?; // Actually executes
 //! ```
 
+use super::backend::Backend;
+use super::template::ExecutionContext;
+use crate::config::interactive::say;
+use crate::config::machine::{Facts, Privileges};
+use crate::i18n;
 use std::fs;
 use std::io::Write;
 use std::path::Path;
-use std::process::Command;
+use std::process::{ExitStatus, Stdio};
 use std::time::{Duration, Instant};
 
 /// Core trait that all instruction types must implement.
@@ -63,14 +74,66 @@ use std::time::{Duration, Instant};
 pub trait AnyInstruction {
   /// Execute the instruction.
   ///
+  /// Placeholders in the instruction's strings are resolved from the context
+  /// first, so dry-run output shows the values that would really be used.
+  ///
   /// # Arguments
   ///
-  /// * `dry_run` - If true, print what would be done without executing
+  /// * `context` - Dry-run flag and placeholder values of the current run
   ///
   /// # Returns
   ///
   /// Returns `Ok(())` on success, or an error describing what went wrong.
-  fn run(&self, dry_run: bool) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+  fn run(&self, context: &ExecutionContext) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+}
+
+/// Formats the exit code of a finished program for messages.
+fn exit_code(status: ExitStatus) -> String {
+  status
+    .code()
+    .map_or_else(|| status.to_string(), |code| code.to_string())
+}
+
+/// Downloads `url` to `path` with curl, reporting the progress to the
+/// context's logger while the download runs.
+///
+/// The size of the file is only known once it is complete, so progress is
+/// reported in bytes received until then.
+fn download(
+  context: &ExecutionContext,
+  url: &str,
+  path: &Path,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+  let mut child = context
+    .command("curl")
+    .arg("-L")
+    .arg("-o")
+    .arg(path)
+    .arg(url)
+    .stdout(Stdio::null())
+    .stderr(Stdio::null())
+    .spawn()?;
+
+  let mut reported = None;
+  let status = loop {
+    if let Some(status) = child.try_wait()? {
+      break status;
+    }
+    let received = fs::metadata(path).map(|metadata| metadata.len()).unwrap_or(0);
+    if reported != Some(received) {
+      context.report_download(received, None);
+      reported = Some(received);
+    }
+    std::thread::sleep(Duration::from_millis(200));
+  };
+
+  if !status.success() {
+    return Err(i18n::download_failed().into());
+  }
+  if let Ok(metadata) = fs::metadata(path) {
+    context.report_download(metadata.len(), Some(metadata.len()));
+  }
+  Ok(())
 }
 
 /// Downloads and executes installers with cross-platform support.
@@ -107,30 +170,22 @@ impl DownloadAndExec {
 }
 
 impl AnyInstruction for DownloadAndExec {
-  fn run(&self, dry_run: bool) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+  fn run(&self, context: &ExecutionContext) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let url = context.resolve_url(self.url)?;
+    let custom_args = match self.custom_args {
+      Some(args) => Some(args.iter().map(|arg| context.resolve(arg)).collect::<Result<Vec<_>, _>>()?),
+      None => None,
+    };
     let temp_dir = std::env::temp_dir();
-    let filename = self.url.split('/').last().unwrap_or("download");
+    let filename = url.split('/').last().unwrap_or("download");
     let file_path = temp_dir.join(filename);
 
-    if dry_run {
-      println!(
-        "Dry run: would download {} to {}",
-        self.url,
-        file_path.display()
-      );
+    if context.dry_run() {
+      say(i18n::dry_run_download(url, file_path.display()));
       return Ok(());
     }
     // Download the file
-    let response = std::process::Command::new("curl")
-      .arg("-L")
-      .arg("-o")
-      .arg(&file_path)
-      .arg(self.url)
-      .output()?;
-
-    if !response.status.success() {
-      return Err("Download failed".into());
-    }
+    download(context, &url, &file_path)?;
 
     let file_extension = file_path
       .extension()
@@ -142,20 +197,20 @@ impl AnyInstruction for DownloadAndExec {
       "exe" => {
         #[cfg(windows)]
         {
-          let mut cmd = Command::new(&file_path);
+          let mut cmd = context.command(&file_path);
 
           // Add custom arguments if provided
-          if let Some(args) = self.custom_args {
+          if let Some(args) = &custom_args {
             cmd.args(args);
           } else if self.silent {
             // Try common silent installation flags for EXE files
             cmd.args(&["/S"]); // NSIS installers
           }
 
-          let status = cmd.status()?;
+          let status = context.status(&mut cmd)?;
           if !status.success() {
             // If /S failed and we're in silent mode, try other common flags
-            if self.silent && self.custom_args.is_none() {
+            if self.silent && custom_args.is_none() {
               let silent_flags = [
                 &["/SILENT"][..],
                 &["/VERYSILENT"][..],
@@ -167,9 +222,9 @@ impl AnyInstruction for DownloadAndExec {
               ];
 
               for flags in &silent_flags {
-                let mut retry_cmd = Command::new(&file_path);
+                let mut retry_cmd = context.command(&file_path);
                 retry_cmd.args(*flags);
-                if let Ok(status) = retry_cmd.status() {
+                if let Ok(status) = context.status(&mut retry_cmd) {
                   if status.success() {
                     break;
                   }
@@ -186,17 +241,17 @@ impl AnyInstruction for DownloadAndExec {
       "msi" => {
         #[cfg(windows)]
         {
-          let mut cmd = Command::new("msiexec");
+          let mut cmd = context.command("msiexec");
           cmd.arg("/i").arg(&file_path);
 
-          if let Some(args) = self.custom_args {
+          if let Some(args) = &custom_args {
             cmd.args(args);
           } else if self.silent {
             // MSI silent installation flags
             cmd.args(&["/quiet", "/qn", "/norestart"]);
           }
 
-          cmd.status()?;
+          context.status(&mut cmd)?;
         }
         #[cfg(not(windows))]
         {
@@ -208,16 +263,16 @@ impl AnyInstruction for DownloadAndExec {
         // Handle Linux and macOS executables (no file extension)
         #[cfg(any(unix, target_os = "macos"))]
         {
-          let mut cmd = Command::new(&file_path);
+          let mut cmd = context.command(&file_path);
 
           // Add custom arguments if provided
-          if let Some(args) = self.custom_args {
+          if let Some(args) = &custom_args {
             cmd.args(args);
           }
 
-          let status = cmd.status()?;
+          let status = context.status(&mut cmd)?;
           if !status.success() {
-            return Err(format!("Execution failed with exit code: {:?}", status.code()).into());
+            return Err(i18n::execution_failed(exit_code(status)).into());
           }
         }
         #[cfg(not(any(unix, target_os = "macos")))]
@@ -229,7 +284,7 @@ impl AnyInstruction for DownloadAndExec {
         // ZIP files should be extracted, not executed
         return Err("ZIP files should be extracted using extract_archive, not executed".into());
       }
-      _ => return Err("Unsupported filetype for execution".into()),
+      _ => return Err(i18n::unsupported_filetype().into()),
     }
 
     // Clean up downloaded file
@@ -266,23 +321,75 @@ impl Run {
 }
 
 impl AnyInstruction for Run {
-  fn run(&self, dry_run: bool) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+  fn run(&self, context: &ExecutionContext) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     if self.command.is_empty() {
-      return Err("Empty command".into());
+      return Err(i18n::empty_command().into());
     }
+    let command = context.resolve_all(&self.command)?;
 
-    if dry_run {
-      println!("Dry run: would execute command: {}", self.command.join(" "));
+    if context.dry_run() {
+      say(i18n::dry_run_execute(command.join(" ")));
       return Ok(());
     }
 
-    let mut cmd = Command::new(&self.command[0]);
-    cmd.args(&self.command[1..]);
+    let mut cmd = context.command(&command[0]);
+    cmd.args(&command[1..]);
 
-    let status = cmd.status()?;
+    let status = context.status(&mut cmd)?;
 
     if !status.success() {
-      return Err(format!("Command failed with exit code: {:?}", status.code()).into());
+      return Err(i18n::command_exit_code(exit_code(status)).into());
+    }
+
+    Ok(())
+  }
+}
+
+/// Runs a script through the platform shell.
+///
+/// Unlike [`Run`], the script is not split on whitespace but handed to
+/// `sh -c` (PowerShell on Windows), so pipes, quotes and `&&` work, e.g. for
+/// installers piped from curl. Shell braces must be written as `{{`/`}}`,
+/// since single braces are placeholders.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// RunScript::new("curl -fsSL https://bun.sh/install | bash")
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RunScript {
+  /// Script passed to the shell as a whole
+  script: &'static str,
+}
+
+impl RunScript {
+  fn new(script: &'static str) -> Self {
+    Self { script }
+  }
+}
+
+impl AnyInstruction for RunScript {
+  fn run(&self, context: &ExecutionContext) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let script = context.resolve(self.script)?;
+
+    if context.dry_run() {
+      say(i18n::dry_run_script(&script));
+      return Ok(());
+    }
+
+    let status = if cfg!(windows) {
+      context.status(
+        context
+          .command("powershell")
+          .args(["-NoProfile", "-ExecutionPolicy", "Bypass", "-Command", &script]),
+      )?
+    } else {
+      context.status(context.command("sh").args(["-c", &script]))?
+    };
+
+    if !status.success() {
+      return Err(i18n::script_exit_code(exit_code(status)).into());
     }
 
     Ok(())
@@ -302,23 +409,15 @@ impl DownloadTo {
 }
 
 impl AnyInstruction for DownloadTo {
-  fn run(&self, dry_run: bool) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    if dry_run {
-      println!("Dry run: would download {} to {}", self.url, self.path);
+  fn run(&self, context: &ExecutionContext) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let url = context.resolve_url(self.url)?;
+    let path = context.resolve(self.path)?;
+
+    if context.dry_run() {
+      say(i18n::dry_run_download(url, path));
       return Ok(());
     }
-    let response = std::process::Command::new("curl")
-      .arg("-L")
-      .arg("-o")
-      .arg(self.path)
-      .arg(self.url)
-      .output()?;
-
-    if !response.status.success() {
-      return Err("Download failed".into());
-    }
-
-    Ok(())
+    download(context, &url, Path::new(&path))
   }
 }
 
@@ -335,39 +434,67 @@ impl Assert {
       expect,
     }
   }
-}
 
-impl AnyInstruction for Assert {
-  fn run(&self, dry_run: bool) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+  /// Runs the command for real and returns its standard output.
+  ///
+  /// Unlike [`AnyInstruction::run`] this ignores dry-run mode, which makes it
+  /// usable for read-only audits such as the `status` command.
+  ///
+  /// # Errors
+  ///
+  /// Fails if a placeholder cannot be resolved, the command cannot be started,
+  /// exits unsuccessfully, or its output does not contain the expected text.
+  pub(crate) fn probe(
+    &self,
+    context: &ExecutionContext,
+  ) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
     if self.command.is_empty() {
-      return Err("Empty command".into());
+      return Err(i18n::empty_command().into());
     }
+    let command = context.resolve_all(&self.command)?;
+    let expect = context.resolve(self.expect)?;
 
-    if dry_run {
-      println!(
-        "Dry run: expect the result of: {} to be {}",
-        self.command.join(" "),
-        self.expect
-      );
-      return Ok(());
-    }
+    let mut cmd = context.command(&command[0]);
+    cmd.args(&command[1..]);
 
-    let mut cmd = Command::new(&self.command[0]);
-    cmd.args(&self.command[1..]);
-
-    let output = cmd.output()?;
+    let output = cmd
+      .output()
+      .map_err(|e| i18n::run_failed(&command[0], e))?;
 
     if !output.status.success() {
-      return Err(format!("Command failed with exit code: {:?}", output.status.code()).into());
+      return Err(i18n::command_exit_code(exit_code(output.status)).into());
     }
 
-    let output_str = String::from_utf8_lossy(&output.stdout);
+    let output_str = String::from_utf8_lossy(&output.stdout).to_string();
 
-    if !output_str.contains(self.expect) {
-      return Err(format!("Expected '{}' but got '{}'", self.expect, output_str).into());
+    if !output_str.contains(&expect) {
+      return Err(i18n::unexpected_output(expect, output_str).into());
     }
 
-    Ok(())
+    Ok(output_str)
+  }
+
+  /// Returns a short description of the assertion for log messages.
+  pub(crate) fn describe(&self) -> String {
+    i18n::assertion(self.command.join(" "), self.expect)
+  }
+}
+
+impl AnyInstruction for Assert {
+  fn run(&self, context: &ExecutionContext) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    if self.command.is_empty() {
+      return Err(i18n::empty_command().into());
+    }
+
+    if context.dry_run() {
+      say(i18n::dry_run_expect(
+        context.resolve_all(&self.command)?.join(" "),
+        context.resolve(self.expect)?,
+      ));
+      return Ok(());
+    }
+
+    self.probe(context).map(|_| ())
   }
 }
 
@@ -387,57 +514,60 @@ impl ExtractArchive {
 }
 
 impl AnyInstruction for ExtractArchive {
-  fn run(&self, dry_run: bool) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    let path = Path::new(self.archive_path);
+  fn run(&self, context: &ExecutionContext) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let archive_path = context.resolve(self.archive_path)?;
+    let destination = context.resolve(self.destination)?;
+    let path = Path::new(&archive_path);
     let extension = path
       .extension()
       .and_then(|s| s.to_str())
-      .ok_or("No file extension")?;
+      .ok_or(i18n::no_file_extension())?;
 
-    if dry_run {
-      println!(
-        "Dry run: would extract {} to {}",
-        self.archive_path, self.destination
-      );
+    if context.dry_run() {
+      say(i18n::dry_run_extract(&archive_path, &destination));
       return Ok(());
     }
 
-    fs::create_dir_all(self.destination)?;
+    fs::create_dir_all(&destination)?;
 
     match extension.to_lowercase().as_str() {
       "zip" => {
-        Command::new("unzip")
-          .arg("-o")
-          .arg(self.archive_path)
-          .arg("-d")
-          .arg(self.destination)
-          .status()?;
+        context.status(
+          context.command("unzip")
+            .arg("-o")
+            .arg(&archive_path)
+            .arg("-d")
+            .arg(&destination),
+        )?;
       }
       "gz" | "tgz" => {
-        Command::new("tar")
-          .arg("-xzf")
-          .arg(self.archive_path)
-          .arg("-C")
-          .arg(self.destination)
-          .status()?;
+        context.status(
+          context.command("tar")
+            .arg("-xzf")
+            .arg(&archive_path)
+            .arg("-C")
+            .arg(&destination),
+        )?;
       }
       "bz2" | "tbz2" => {
-        Command::new("tar")
-          .arg("-xjf")
-          .arg(self.archive_path)
-          .arg("-C")
-          .arg(self.destination)
-          .status()?;
+        context.status(
+          context.command("tar")
+            .arg("-xjf")
+            .arg(&archive_path)
+            .arg("-C")
+            .arg(&destination),
+        )?;
       }
       "xz" | "txz" => {
-        Command::new("tar")
-          .arg("-xJf")
-          .arg(self.archive_path)
-          .arg("-C")
-          .arg(self.destination)
-          .status()?;
+        context.status(
+          context.command("tar")
+            .arg("-xJf")
+            .arg(&archive_path)
+            .arg("-C")
+            .arg(&destination),
+        )?;
       }
-      _ => return Err(format!("Unsupported archive format: {}", extension).into()),
+      _ => return Err(i18n::unsupported_archive(extension).into()),
     }
 
     Ok(())
@@ -457,25 +587,26 @@ impl AddEnvVar {
 }
 
 impl AnyInstruction for AddEnvVar {
-  fn run(&self, dry_run: bool) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    if dry_run {
-      println!(
-        "Dry run: would set environment variable {}={}",
-        self.name, self.value
-      );
+  fn run(&self, context: &ExecutionContext) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let name = context.resolve(self.name)?;
+    let value = context.resolve(self.value)?;
+
+    if context.dry_run() {
+      say(i18n::dry_run_env_var(&name, &value));
       return Ok(());
     }
     {
-      Command::new("setx")
-        .arg(self.name)
-        .arg(self.value)
-        .status()?;
+      context.status(
+        context.command("setx")
+          .arg(&name)
+          .arg(&value),
+      )?;
     }
 
     {
       let home = std::env::var("HOME")?;
       let bashrc_path = format!("{}/.bashrc", home);
-      let env_line = format!("export {}=\"{}\"\n", self.name, self.value);
+      let env_line = format!("export {}=\"{}\"\n", name, value);
 
       let mut file = fs::OpenOptions::new()
         .create(true)
@@ -502,44 +633,43 @@ impl CreateShortcut {
 }
 
 impl AnyInstruction for CreateShortcut {
-  fn run(&self, dry_run: bool) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    if dry_run {
-      println!(
-        "Dry run: would create shortcut '{}' pointing to '{}'{}",
-        self.name,
-        self.target,
-        if let Some(icon) = self.icon {
-          format!(" with icon '{}'", icon)
-        } else {
-          String::new()
-        }
-      );
+  fn run(&self, context: &ExecutionContext) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let name = context.resolve(self.name)?;
+    let target = context.resolve(self.target)?;
+    let icon = self.icon.map(|icon| context.resolve(icon)).transpose()?;
+
+    if context.dry_run() {
+      match &icon {
+        Some(icon) => say(i18n::dry_run_shortcut_icon(&name, &target, icon)),
+        None => say(i18n::dry_run_shortcut(&name, &target)),
+      }
       return Ok(());
     }
     {
       let desktop = std::env::var("USERPROFILE")? + "\\Desktop";
-      let shortcut_path = format!("{}\\{}.lnk", desktop, self.name);
+      let shortcut_path = format!("{}\\{}.lnk", desktop, name);
 
       let ps_script = format!(
         r#"$WshShell = New-Object -comObject WScript.Shell; $Shortcut = $WshShell.CreateShortcut("{}"); $Shortcut.TargetPath = "{}"; $Shortcut.Save()"#,
-        shortcut_path, self.target
+        shortcut_path, target
       );
 
-      Command::new("powershell")
-        .arg("-Command")
-        .arg(&ps_script)
-        .status()?;
+      context.status(
+        context.command("powershell")
+          .arg("-Command")
+          .arg(&ps_script),
+      )?;
     }
 
     {
       let home = std::env::var("HOME")?;
-      let desktop_path = format!("{}/Desktop/{}.desktop", home, self.name);
+      let desktop_path = format!("{}/Desktop/{}.desktop", home, name);
 
       let desktop_entry = format!(
         "[Desktop Entry]\nVersion=1.0\nType=Application\nName={}\nExec={}\n{}Terminal=false\n",
-        self.name,
-        self.target,
-        if let Some(icon) = self.icon {
+        name,
+        target,
+        if let Some(icon) = &icon {
           format!("Icon={}\n", icon)
         } else {
           String::new()
@@ -547,10 +677,11 @@ impl AnyInstruction for CreateShortcut {
       );
 
       fs::write(&desktop_path, desktop_entry)?;
-      Command::new("chmod")
-        .arg("+x")
-        .arg(&desktop_path)
-        .status()?;
+      context.status(
+        context.command("chmod")
+          .arg("+x")
+          .arg(&desktop_path),
+      )?;
     }
 
     Ok(())
@@ -576,27 +707,25 @@ impl WaitForCondition {
 }
 
 impl AnyInstruction for WaitForCondition {
-  fn run(&self, dry_run: bool) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    if dry_run {
-      println!(
-        "Dry run: would wait up to {} seconds for command '{}' to succeed",
-        self.timeout_secs,
-        self.check_command.join(" ")
-      );
+  fn run(&self, context: &ExecutionContext) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let check_command = context.resolve_all(&self.check_command)?;
+
+    if context.dry_run() {
+      say(i18n::dry_run_wait(self.timeout_secs, check_command.join(" ")));
       return Ok(());
     }
     let start = Instant::now();
     let timeout = Duration::from_secs(self.timeout_secs);
 
     while start.elapsed() < timeout {
-      if self.check_command.is_empty() {
-        return Err("Empty check command".into());
+      if check_command.is_empty() {
+        return Err(i18n::empty_check_command().into());
       }
 
-      let mut cmd = Command::new(&self.check_command[0]);
-      cmd.args(&self.check_command[1..]);
+      let mut cmd = context.command(&check_command[0]);
+      cmd.args(&check_command[1..]);
 
-      if let Ok(status) = cmd.status() {
+      if let Ok(status) = context.status(&mut cmd) {
         if status.success() {
           return Ok(());
         }
@@ -605,7 +734,7 @@ impl AnyInstruction for WaitForCondition {
       std::thread::sleep(Duration::from_secs(1));
     }
 
-    Err("Timeout waiting for condition".into())
+    Err(i18n::condition_timeout().into())
   }
 }
 
@@ -629,77 +758,144 @@ impl InstallApplication {
   fn new(package_name: &'static str) -> Self {
     Self { package_name }
   }
+
+  /// Name of the package passed to the system package manager.
+  pub(crate) fn package_name(&self) -> &'static str {
+    self.package_name
+  }
+
+  /// Builds the matching upgrade instruction for this package.
+  ///
+  /// Used as a fallback when a package defines no upgrade instructions of its own.
+  pub(crate) fn upgrade_instruction(&self) -> Instructions {
+    Instructions::UpgradeApplication(UpgradeApplication::new(self.package_name))
+  }
 }
 
 impl AnyInstruction for InstallApplication {
-  fn run(&self, dry_run: bool) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    if dry_run {
-      println!("Dry run: would install package '{}'", self.package_name);
+  fn run(&self, context: &ExecutionContext) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let package_name = context.resolve(self.package_name)?;
+
+    if context.dry_run() {
+      match context.install_path() {
+        Some(path) => say(i18n::dry_run_install_into(&package_name, path.display())),
+        None => say(i18n::dry_run_install(&package_name)),
+      }
       return Ok(());
     }
 
-    #[cfg(not(windows))]
-    {
-      let package_managers = [
-        ("apt", vec!["apt", "install", "-y", self.package_name]),
-        ("yum", vec!["yum", "install", "-y", self.package_name]),
-        ("dnf", vec!["dnf", "install", "-y", self.package_name]),
-        (
-          "pacman",
-          vec!["pacman", "-S", "--noconfirm", self.package_name],
-        ),
-        ("zypper", vec!["zypper", "install", "-y", self.package_name]),
-        ("brew", vec!["brew", "install", self.package_name]),
-      ];
-
-      for (pm, args) in &package_managers {
-        if Command::new("which")
-          .arg(pm)
-          .output()
-          .map(|o| o.status.success())
-          .unwrap_or(false)
-        {
-          let status = Command::new(args[0]).args(&args[1..]).status()?;
+    for backend in backends_for(context) {
+      let mut args = backend.install_command(&package_name, context.network());
+      if let Some(path) = context.install_path() {
+        args.extend(backend.location_options(&package_name, path));
+      }
+      if context.user_scope() {
+        args.extend(backend.user_scope_options());
+      }
+      let status = context.status(context.command(&args[0]).args(&args[1..]))?;
 
-          if status.success() {
-            return Ok(());
-          }
-        }
+      if status.success() {
+        return Ok(());
       }
     }
 
-    #[cfg(windows)]
-    {
-      if Command::new("choco")
-        .arg("--version")
-        .output()
-        .map(|o| o.status.success())
-        .unwrap_or(false)
-      {
-        let status = Command::new("choco")
-          .args(&["install", self.package_name, "-y"])
-          .status()?;
-        if status.success() {
-          return Ok(());
-        }
+    Err(no_backend_error(context))
+  }
+}
+
+/// Upgrades an application using the system's package manager.
+///
+/// This is the counterpart of [`InstallApplication`] and uses the same
+/// package manager detection, running each backend's upgrade verb
+/// (e.g. `apt install --only-upgrade`, `winget upgrade`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UpgradeApplication {
+  /// Name of the package to upgrade
+  package_name: &'static str,
+}
+
+impl UpgradeApplication {
+  fn new(package_name: &'static str) -> Self {
+    Self { package_name }
+  }
+}
+
+impl AnyInstruction for UpgradeApplication {
+  fn run(&self, context: &ExecutionContext) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let package_name = context.resolve(self.package_name)?;
+
+    if context.dry_run() {
+      say(i18n::dry_run_upgrade(&package_name));
+      return Ok(());
+    }
+
+    for backend in backends_for(context) {
+      let mut args = backend.upgrade_command(&package_name, context.network());
+      if context.user_scope() {
+        args.extend(backend.user_scope_options());
       }
+      let status = context.status(context.command(&args[0]).args(&args[1..]))?;
 
-      if Command::new("winget")
-        .arg("--version")
-        .output()
-        .map(|o| o.status.success())
-        .unwrap_or(false)
-      {
-        let status = Command::new("winget")
-          .args(&["install", "--id", self.package_name, "-e"])
-          .status()?;
-        if status.success() {
-          return Ok(());
-        }
+      if status.success() {
+        return Ok(());
       }
     }
 
-    Err("No suitable package manager found".into())
+    Err(no_backend_error(context))
+  }
+}
+
+/// Refreshes the package lists of the system package manager.
+///
+/// Use this instead of running `sudo apt update` as a command: the proxy and
+/// CA options of the network settings are passed on the command line, since
+/// sudo drops the environment they are otherwise read from. Without
+/// administrator rights of its own, the tool runs the command through sudo.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UpdatePackageLists;
+
+impl AnyInstruction for UpdatePackageLists {
+  fn run(&self, context: &ExecutionContext) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    if context.dry_run() {
+      say(i18n::dry_run_update_lists());
+      return Ok(());
+    }
+
+    let needs_sudo = Facts::host().privileges == Privileges::Sudo;
+    for backend in backends_for(context) {
+      let Some(mut args) = backend.update_command(context.network()) else {
+        continue;
+      };
+      if needs_sudo && !backend.supports_user_scope() {
+        args.insert(0, "sudo".to_string());
+      }
+      let status = context.status(context.command(&args[0]).args(&args[1..]))?;
+
+      if status.success() {
+        return Ok(());
+      }
+    }
+
+    Err(no_backend_error(context))
+  }
+}
+
+/// Returns the installed package managers usable in the context's scope.
+///
+/// In user scope, system package managers that need administrator rights
+/// (apt, dnf, Chocolatey, ...) are left out.
+fn backends_for(context: &ExecutionContext) -> Vec<Backend> {
+  Backend::available(context)
+    .into_iter()
+    .filter(|backend| !context.user_scope() || backend.supports_user_scope())
+    .collect()
+}
+
+fn no_backend_error(context: &ExecutionContext) -> Box<dyn std::error::Error + Send + Sync> {
+  if context.user_scope() {
+    i18n::no_user_package_manager().into()
+  } else {
+    i18n::no_suitable_package_manager().into()
   }
 }
 
@@ -728,40 +924,40 @@ impl InstallPackage {
 }
 
 impl AnyInstruction for InstallPackage {
-  fn run(&self, dry_run: bool) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    if dry_run {
-      println!(
-        "Dry run: would install package '{}' using language package manager",
-        self.package_name
-      );
+  fn run(&self, context: &ExecutionContext) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let package_name = context.resolve(self.package_name)?;
+    let package_name = package_name.as_str();
+
+    if context.dry_run() {
+      say(i18n::dry_run_install_language(package_name));
       return Ok(());
     }
 
     let package_managers = [
       // JavaScript/TypeScript package managers
-      ("npm", vec!["npm", "install", "-g", self.package_name]),
-      ("yarn", vec!["yarn", "global", "add", self.package_name]),
-      ("bun", vec!["bun", "add", "-g", self.package_name]),
-      ("pnpm", vec!["pnpm", "add", "-g", self.package_name]),
+      ("npm", vec!["npm", "install", "-g", package_name]),
+      ("yarn", vec!["yarn", "global", "add", package_name]),
+      ("bun", vec!["bun", "add", "-g", package_name]),
+      ("pnpm", vec!["pnpm", "add", "-g", package_name]),
       // Rust package manager
-      ("cargo", vec!["cargo", "install", self.package_name]),
+      ("cargo", vec!["cargo", "install", package_name]),
       // Python package managers
-      ("pipx", vec!["pipx", "install", self.package_name]),
-      ("pip", vec!["pip", "install", "--user", self.package_name]),
+      ("pipx", vec!["pipx", "install", package_name]),
+      ("pip", vec!["pip", "install", "--user", package_name]),
       // Ruby package manager
-      ("gem", vec!["gem", "install", self.package_name]),
+      ("gem", vec!["gem", "install", package_name]),
     ];
 
     for (pm, args) in &package_managers {
       // Check if package manager is available
       let check_cmd = if *pm == "go" {
-        Command::new("go").arg("version").output()
+        context.command("go").arg("version").output()
       } else {
-        Command::new(pm).arg("--version").output()
+        context.command(pm).arg("--version").output()
       };
 
       if check_cmd.map(|o| o.status.success()).unwrap_or(false) {
-        let status = Command::new(args[0]).args(&args[1..]).status()?;
+        let status = context.status(context.command(args[0]).args(&args[1..]))?;
 
         if status.success() {
           return Ok(());
@@ -770,21 +966,22 @@ impl AnyInstruction for InstallPackage {
     }
 
     // Special case for Go (different command structure)
-    if Command::new("go")
+    if context.command("go")
       .arg("version")
       .output()
       .map(|o| o.status.success())
       .unwrap_or(false)
     {
-      let status = Command::new("go")
-        .args(&["install", &format!("{}@latest", self.package_name)])
-        .status()?;
+      let status = context.status(
+        context.command("go")
+          .args(&["install", &format!("{}@latest", package_name)]),
+      )?;
       if status.success() {
         return Ok(());
       }
     }
 
-    Err("No suitable language package manager found".into())
+    Err(i18n::no_language_package_manager().into())
   }
 }
 
@@ -801,30 +998,28 @@ impl CloneRepository {
 }
 
 impl AnyInstruction for CloneRepository {
-  fn run(&self, dry_run: bool) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    if dry_run {
-      println!(
-        "Dry run: would clone repository '{}' {}",
-        self.url,
-        if let Some(path) = self.path {
-          format!("to '{}'", path)
-        } else {
-          "to current directory".to_string()
-        }
-      );
+  fn run(&self, context: &ExecutionContext) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let url = context.resolve_url(self.url)?;
+    let path = self.path.map(|path| context.resolve(path)).transpose()?;
+
+    if context.dry_run() {
+      match &path {
+        Some(path) => say(i18n::dry_run_clone_to(&url, path)),
+        None => say(i18n::dry_run_clone(&url)),
+      }
       return Ok(());
     }
-    let mut cmd = Command::new("git");
-    cmd.arg("clone").arg(self.url);
+    let mut cmd = context.command("git");
+    cmd.arg("clone").arg(&url);
 
-    if let Some(path) = self.path {
+    if let Some(path) = &path {
       cmd.arg(path);
     }
 
-    let status = cmd.status()?;
+    let status = context.status(&mut cmd)?;
 
     if !status.success() {
-      return Err("Git clone failed".into());
+      return Err(i18n::clone_failed().into());
     }
 
     Ok(())
@@ -843,23 +1038,22 @@ impl RequestSudo {
 }
 
 impl AnyInstruction for RequestSudo {
-  fn run(&self, dry_run: bool) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    if dry_run {
-      println!(
-        "Dry run: would request administrator privileges: {}",
-        self.reason
-      );
+  fn run(&self, context: &ExecutionContext) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let reason = context.resolve(self.reason)?;
+
+    if context.dry_run() {
+      say(i18n::dry_run_sudo(&reason));
       return Ok(());
     }
-    println!("Administrator privileges required: {}", self.reason);
+    say(i18n::sudo_required(&reason));
 
     {
-      Command::new("sudo").arg("-v").status()?;
+      context.status(context.command("sudo").arg("-v"))?;
     }
 
     {
       // On Windows, this would typically be handled by UAC prompts in individual commands
-      println!("Please ensure you are running as Administrator or have UAC enabled");
+      say(i18n::sudo_windows_hint());
     }
 
     Ok(())
@@ -878,44 +1072,51 @@ impl RestartService {
 }
 
 impl AnyInstruction for RestartService {
-  fn run(&self, dry_run: bool) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    if dry_run {
-      println!("Dry run: would restart service '{}'", self.service_name);
+  fn run(&self, context: &ExecutionContext) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let service_name = context.resolve(self.service_name)?;
+    let service_name = service_name.as_str();
+
+    if context.dry_run() {
+      say(i18n::dry_run_restart(service_name));
       return Ok(());
     }
     {
-      Command::new("sc")
-        .args(&["stop", self.service_name])
-        .status()?;
+      context.status(
+        context.command("sc")
+          .args(&["stop", service_name]),
+      )?;
 
       std::thread::sleep(Duration::from_secs(2));
 
-      Command::new("sc")
-        .args(&["start", self.service_name])
-        .status()?;
+      context.status(
+        context.command("sc")
+          .args(&["start", service_name]),
+      )?;
     }
 
     {
-      if Command::new("systemctl")
+      if context.command("systemctl")
         .arg("--version")
         .output()
         .map(|o| o.status.success())
         .unwrap_or(false)
       {
-        Command::new("systemctl")
-          .args(&["restart", self.service_name])
-          .status()?;
-      } else if Command::new("service")
+        context.status(
+          context.command("systemctl")
+            .args(&["restart", service_name]),
+        )?;
+      } else if context.command("service")
         .arg("--version")
         .output()
         .map(|o| o.status.success())
         .unwrap_or(false)
       {
-        Command::new("service")
-          .args(&[self.service_name, "restart"])
-          .status()?;
+        context.status(
+          context.command("service")
+            .args(&[service_name, "restart"]),
+        )?;
       } else {
-        return Err("No service manager found".into());
+        return Err(i18n::no_service_manager().into());
       }
     }
 
@@ -935,12 +1136,14 @@ impl BackupFile {
 }
 
 impl AnyInstruction for BackupFile {
-  fn run(&self, dry_run: bool) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    if dry_run {
-      println!("Dry run: would backup file '{}'", self.path);
+  fn run(&self, context: &ExecutionContext) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let path = context.resolve(self.path)?;
+
+    if context.dry_run() {
+      say(i18n::dry_run_backup(&path));
       return Ok(());
     }
-    if !Path::new(self.path).exists() {
+    if !Path::new(&path).exists() {
       return Ok(()); // Nothing to backup
     }
 
@@ -948,10 +1151,10 @@ impl AnyInstruction for BackupFile {
       .duration_since(std::time::UNIX_EPOCH)?
       .as_secs();
 
-    let backup_path = format!("{}.backup.{}", self.path, timestamp);
-    fs::copy(self.path, &backup_path)?;
+    let backup_path = format!("{}.backup.{}", path, timestamp);
+    fs::copy(&path, &backup_path)?;
 
-    println!("Backed up {} to {}", self.path, backup_path);
+    say(i18n::backed_up(&path, &backup_path));
     Ok(())
   }
 }
@@ -974,17 +1177,18 @@ impl EditFile {
 }
 
 impl AnyInstruction for EditFile {
-  fn run(&self, dry_run: bool) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    if dry_run {
-      println!(
-        "Dry run: would edit file '{}' replacing '{}' with '{}'",
-        self.path, self.find, self.replace
-      );
+  fn run(&self, context: &ExecutionContext) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let path = context.resolve(self.path)?;
+    let find = context.resolve(self.find)?;
+    let replace = context.resolve(self.replace)?;
+
+    if context.dry_run() {
+      say(i18n::dry_run_edit(&path, &find, &replace));
       return Ok(());
     }
-    let content = fs::read_to_string(self.path)?;
-    let new_content = content.replace(self.find, self.replace);
-    fs::write(self.path, new_content)?;
+    let content = fs::read_to_string(&path)?;
+    let new_content = content.replace(&find, &replace);
+    fs::write(&path, new_content)?;
 
     Ok(())
   }
@@ -1003,6 +1207,8 @@ pub enum Instructions {
   DownloadAndExec(DownloadAndExec),
   /// Run a shell command
   Run(Run),
+  /// Run a script through the platform shell
+  RunScript(RunScript),
   /// Download a file to a specific location
   DownloadTo(DownloadTo),
   /// Assert that a command produces expected output
@@ -1017,6 +1223,10 @@ pub enum Instructions {
   WaitForCondition(WaitForCondition),
   /// Install an application using system package manager
   InstallApplication(InstallApplication),
+  /// Upgrade an application using system package manager
+  UpgradeApplication(UpgradeApplication),
+  /// Refresh the package lists of the system package manager
+  UpdatePackageLists(UpdatePackageLists),
   /// Install a package using language package manager
   InstallPackage(InstallPackage),
   /// Clone a Git repository
@@ -1040,24 +1250,62 @@ impl Instructions {
   }
 }
 
+impl Instructions {
+  /// Returns a short description of the step for progress displays.
+  ///
+  /// Placeholders are shown unresolved.
+  pub(crate) fn describe(&self) -> String {
+    match self {
+      Instructions::DownloadAndExec(inst) => i18n::step_download_run(file_name(inst.url)),
+      Instructions::Run(inst) => i18n::step_run(inst.command.join(" ")),
+      Instructions::RunScript(inst) => {
+        i18n::step_run_script(inst.script.lines().next().unwrap_or_default().trim())
+      }
+      Instructions::DownloadTo(inst) => i18n::step_download(file_name(inst.url)),
+      Instructions::Assert(inst) => i18n::step_check(inst.describe()),
+      Instructions::ExtractArchive(inst) => i18n::step_extract(file_name(inst.archive_path)),
+      Instructions::AddEnvVar(inst) => i18n::step_set(inst.name),
+      Instructions::CreateShortcut(inst) => i18n::step_shortcut(inst.name),
+      Instructions::WaitForCondition(inst) => i18n::step_wait(inst.check_command.join(" ")),
+      Instructions::InstallApplication(inst) => i18n::step_install(inst.package_name),
+      Instructions::UpgradeApplication(inst) => i18n::step_upgrade(inst.package_name),
+      Instructions::UpdatePackageLists(_) => i18n::step_update_lists().to_string(),
+      Instructions::InstallPackage(inst) => i18n::step_install(inst.package_name),
+      Instructions::CloneRepository(inst) => i18n::step_clone(inst.url),
+      Instructions::RequestSudo(_) => i18n::step_sudo().to_string(),
+      Instructions::RestartService(inst) => i18n::step_restart(inst.service_name),
+      Instructions::BackupFile(inst) => i18n::step_backup(inst.path),
+      Instructions::EditFile(inst) => i18n::step_edit(inst.path),
+    }
+  }
+}
+
+/// Last segment of a URL or path, for short descriptions.
+fn file_name(location: &str) -> &str {
+  location.rsplit(['/', '\\']).next().unwrap_or(location)
+}
+
 impl AnyInstruction for Instructions {
-  fn run(&self, dry_run: bool) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+  fn run(&self, context: &ExecutionContext) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     match self {
-      Instructions::DownloadAndExec(inst) => inst.run(dry_run),
-      Instructions::Run(inst) => inst.run(dry_run),
-      Instructions::DownloadTo(inst) => inst.run(dry_run),
-      Instructions::Assert(inst) => inst.run(dry_run),
-      Instructions::ExtractArchive(inst) => inst.run(dry_run),
-      Instructions::AddEnvVar(inst) => inst.run(dry_run),
-      Instructions::CreateShortcut(inst) => inst.run(dry_run),
-      Instructions::WaitForCondition(inst) => inst.run(dry_run),
-      Instructions::InstallApplication(inst) => inst.run(dry_run),
-      Instructions::InstallPackage(inst) => inst.run(dry_run),
-      Instructions::CloneRepository(inst) => inst.run(dry_run),
-      Instructions::RequestSudo(inst) => inst.run(dry_run),
-      Instructions::RestartService(inst) => inst.run(dry_run),
-      Instructions::BackupFile(inst) => inst.run(dry_run),
-      Instructions::EditFile(inst) => inst.run(dry_run),
+      Instructions::DownloadAndExec(inst) => inst.run(context),
+      Instructions::Run(inst) => inst.run(context),
+      Instructions::RunScript(inst) => inst.run(context),
+      Instructions::DownloadTo(inst) => inst.run(context),
+      Instructions::Assert(inst) => inst.run(context),
+      Instructions::ExtractArchive(inst) => inst.run(context),
+      Instructions::AddEnvVar(inst) => inst.run(context),
+      Instructions::CreateShortcut(inst) => inst.run(context),
+      Instructions::WaitForCondition(inst) => inst.run(context),
+      Instructions::InstallApplication(inst) => inst.run(context),
+      Instructions::UpgradeApplication(inst) => inst.run(context),
+      Instructions::UpdatePackageLists(inst) => inst.run(context),
+      Instructions::InstallPackage(inst) => inst.run(context),
+      Instructions::CloneRepository(inst) => inst.run(context),
+      Instructions::RequestSudo(inst) => inst.run(context),
+      Instructions::RestartService(inst) => inst.run(context),
+      Instructions::BackupFile(inst) => inst.run(context),
+      Instructions::EditFile(inst) => inst.run(context),
     }
   }
 }
@@ -1074,13 +1322,13 @@ impl AnyInstruction for Instructions {
 /// let instruction = Instruction::new("Install Node.js")
 ///   .download_and_exec_silent("https://nodejs.org/dist/latest/node-x64.msi");
 ///
-/// instruction.run(false)?; // Execute the instruction
+/// instruction.run(&context)?; // Execute the instruction
 /// ```
 ///
 /// # Available Methods
 ///
 /// - **File Operations**: `download_and_exec`, `download_to`, `extract_archive`
-/// - **Commands**: `cmd`, `install_package`, `clone_repository`  
+/// - **Commands**: `cmd`, `script`, `install_package`, `update_package_lists`, `clone_repository`  
 /// - **System**: `add_env_var`, `create_shortcut`, `restart_service`
 /// - **Validation**: `check`, `wait_for_condition`
 /// - **Utilities**: `backup_file`, `edit_file`, `request_sudo`
@@ -1161,6 +1409,48 @@ impl Instruction {
     Instructions::from_instruction(self)
   }
 
+  /// Run a script through the platform shell (`sh -c` or PowerShell).
+  ///
+  /// # Arguments
+  ///
+  /// * `script` - Script to run as a whole, pipes and quotes included
+  pub fn script(mut self, script: &'static str) -> Instructions {
+    self.instruction = Some(Instructions::RunScript(RunScript::new(script)));
+    Instructions::from_instruction(self)
+  }
+
+  /// Download a file to a specific location.
+  ///
+  /// # Arguments
+  ///
+  /// * `url` - URL to download the file from
+  /// * `path` - Path to save the file to
+  pub fn download_to(mut self, url: &'static str, path: &'static str) -> Instructions {
+    self.instruction = Some(Instructions::DownloadTo(DownloadTo::new(url, path)));
+    Instructions::from_instruction(self)
+  }
+
+  /// Extract an archive (zip, tar.gz, tar.bz2, tar.xz) into a directory.
+  ///
+  /// # Arguments
+  ///
+  /// * `archive_path` - Path of the archive; its extension selects the format
+  /// * `destination` - Directory to extract into, created if missing
+  pub fn extract_archive(mut self, archive_path: &'static str, destination: &'static str) -> Instructions {
+    self.instruction = Some(Instructions::ExtractArchive(ExtractArchive::new(
+      archive_path,
+      destination,
+    )));
+    Instructions::from_instruction(self)
+  }
+
+  /// Refresh the package lists of the system package manager, e.g. before
+  /// installing from a newly added repository.
+  pub fn update_package_lists(mut self) -> Instructions {
+    self.instruction = Some(Instructions::UpdatePackageLists(UpdatePackageLists));
+    Instructions::from_instruction(self)
+  }
+
   /// Install an application using the system package manager.
   ///
   /// Automatically detects and uses the appropriate package manager
@@ -1176,6 +1466,21 @@ impl Instruction {
     Instructions::from_instruction(self)
   }
 
+  /// Upgrade an application using the system package manager.
+  ///
+  /// Runs the upgrade verb of the first available package manager,
+  /// e.g. `apt install --only-upgrade` or `winget upgrade`.
+  ///
+  /// # Arguments
+  ///
+  /// * `package_name` - Name of the application to upgrade
+  pub fn upgrade_application(mut self, package_name: &'static str) -> Instructions {
+    self.instruction = Some(Instructions::UpgradeApplication(UpgradeApplication::new(
+      package_name,
+    )));
+    Instructions::from_instruction(self)
+  }
+
   /// Install a package using language package managers.
   ///
   /// Automatically detects and uses available language package managers
@@ -1193,7 +1498,7 @@ impl Instruction {
 
   /// Create an assertion that checks if a command produces expected output.
   ///
-  /// This is commonly used for prerequisite checks to verify if software
+  /// This is commonly used for installed checks to verify if software
   /// is already installed.
   ///
   /// # Arguments
@@ -1220,10 +1525,10 @@ impl Instruction {
   ///
   /// # Arguments
   ///
-  /// * `dry_run` - If true, only print what would be done
-  pub fn execute(&self, dry_run: bool) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+  /// * `context` - Dry-run flag and placeholder values of the current run
+  pub fn execute(&self, context: &ExecutionContext) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     if let Some(ref instruction) = self.instruction {
-      instruction.run(dry_run)?;
+      instruction.run(context)?;
     }
 
     Ok(())
@@ -17,12 +17,27 @@
 //! The system uses multi-threading to install multiple packages concurrently within a bundle,
 //! significantly reducing overall installation time. Each package is processed in its own thread.
 
+pub mod backend;
+pub mod checks;
+pub mod doctor;
 pub mod instructions;
+pub mod report;
+pub mod status;
+pub mod support;
+pub mod template;
+pub mod version;
 
 use crate::config;
-use crate::logger::{Logger, LoggerSystem, LogCollector, ConsoleOutput, LevelFilter, LogLevel};
+use crate::i18n;
+use crate::config::network::NetworkConfig;
+use prowo_log::{Logger, LoggerSystem, LogCollector, ConsoleOutput, LevelFilter, LogEvent, LogLevel};
+use crate::manager::backend::Backend;
+use crate::manager::checks::Check;
 use crate::manager::instructions::AnyInstruction;
-use std::collections::HashMap;
+use crate::manager::report::{OperationReport, Outcome, PackageOutcome};
+use crate::manager::template::ExecutionContext;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
 
 /// A set of instructions for a specific operation (install/uninstall/configure).
 ///
@@ -56,6 +71,60 @@ type InstallationInstructions = InstructionSet<instructions::Instructions>;
 /// Type alias for instruction sets used during software configuration.
 type ConfigurationInstructions = InstructionSet<instructions::Instructions>;
 
+/// Instructions and log messages of an operation that runs every step of a
+/// package's mapping without further checks.
+struct Phase {
+  /// Name of the operation in lifecycle events
+  operation: &'static str,
+  /// Selects the steps of the operation from a mapping
+  steps: fn(&InstructionMapping) -> &Vec<instructions::Instructions>,
+  started: fn() -> &'static str,
+  failed: fn() -> &'static str,
+  step_done: fn() -> &'static str,
+  completed: fn() -> &'static str,
+}
+
+impl Phase {
+  const CONFIGURE: Phase = Phase {
+    operation: "configure",
+    steps: |mapping| &mapping.configuration_instructions.install,
+    started: i18n::configure_started,
+    failed: i18n::configure_failed,
+    step_done: i18n::configure_step_done,
+    completed: i18n::configure_completed,
+  };
+
+  const UNINSTALL: Phase = Phase {
+    operation: "uninstall",
+    steps: |mapping| &mapping.uninstall_instructions.install,
+    started: i18n::uninstall_started,
+    failed: i18n::uninstall_phase_failed,
+    step_done: i18n::uninstall_step_done,
+    completed: i18n::uninstall_completed,
+  };
+
+  const DECONFIGURE: Phase = Phase {
+    operation: "deconfigure",
+    steps: |mapping| &mapping.deconfiguration_instructions.install,
+    started: i18n::deconfigure_started,
+    failed: i18n::deconfigure_failed,
+    step_done: i18n::deconfigure_step_done,
+    completed: i18n::deconfigure_completed,
+  };
+}
+
+/// A command whose output reveals the installed version of a package.
+///
+/// Used by the `status` command to tell installed packages apart from
+/// outdated ones.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct VersionProbe {
+  /// Command split into program and arguments
+  command: Vec<String>,
+  /// Lowest version that counts as up to date
+  minimum: Option<&'static str>,
+}
+
 /// Maps different types of operations to their corresponding instruction sets.
 ///
 /// This structure organizes all the different operations that can be performed
@@ -63,23 +132,37 @@ type ConfigurationInstructions = InstructionSet<instructions::Instructions>;
 ///
 /// # Operations Supported
 ///
-/// - **Prerequisites**: Check if software is already installed before proceeding
+/// - **Installed detection**: Check if software is already installed before proceeding
+/// - **Prerequisites**: Conditions that must hold before installing, e.g. curl or admin rights
 /// - **Installation**: Download and install the software
 /// - **Uninstallation**: Remove the software from the system  
+/// - **Upgrade**: Bring an installed package to its newest version
 /// - **Configuration**: Apply settings and configurations after installation
 /// - **Deconfiguration**: Revert configurations during uninstallation
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct InstructionMapping {
-  /// Instructions for checking if the software is already installed
-  prerequisite_checks: Vec<instructions::Instructions>,
+  /// Check deciding whether the software is already installed
+  installed_check: Option<Check>,
+  /// Checks that must all pass before the software can be installed
+  prerequisites: Vec<Check>,
   /// Instructions for installing the software
   install_instructions: InstallationInstructions,
   /// Instructions for uninstalling the software
   uninstall_instructions: InstallationInstructions,
+  /// Instructions for upgrading the software to its newest version
+  upgrade_instructions: InstallationInstructions,
   /// Instructions for configuring the software after installation
   configuration_instructions: ConfigurationInstructions,
   /// Instructions for reverting configuration during uninstallation
   deconfiguration_instructions: ConfigurationInstructions,
+  /// Optional command used to read the installed version
+  version_probe: Option<VersionProbe>,
+  /// Placeholder values defined by the package, e.g. a pinned `version`
+  variables: BTreeMap<String, String>,
+  /// Installs for the current user only (see [`Package::add_user_mapping`])
+  user_scope: bool,
+  /// Reason why the mapping was defined incorrectly, if it was
+  invalid: Option<String>,
 }
 
 impl InstructionMapping {
@@ -89,40 +172,147 @@ impl InstructionMapping {
   /// using the builder methods.
   pub(crate) fn new() -> Self {
     Self {
-      prerequisite_checks: Vec::new(),
+      installed_check: None,
+      prerequisites: Vec::new(),
       install_instructions: InstallationInstructions::new(),
       uninstall_instructions: InstallationInstructions::new(),
+      upgrade_instructions: InstallationInstructions::new(),
       configuration_instructions: ConfigurationInstructions::new(),
       deconfiguration_instructions: ConfigurationInstructions::new(),
+      version_probe: None,
+      variables: BTreeMap::new(),
+      user_scope: false,
+      invalid: None,
     }
   }
 
-  /// Adds prerequisite check instructions to this mapping.
+  /// Adds checks that detect whether the software is already installed.
   ///
-  /// These instructions will be executed before installation to check if
-  /// the software is already installed or if prerequisites are met.
-  /// Only Assert instructions are accepted for prerequisite checks.
+  /// The software counts as installed if **any** of the given checks passes,
+  /// in which case the installation is skipped. Only Assert instructions are
+  /// accepted, since detection must not change the system. Use
+  /// [`detect_installed`](Self::detect_installed) for other combinations.
   ///
   /// # Arguments
   ///
-  /// * `checks` - Vector of Assert instructions to check prerequisites
+  /// * `checks` - Vector of Assert instructions, any of which marks the software as installed
   ///
   /// # Returns
   ///
-  /// Returns `self` for method chaining.
-  pub(crate) fn add_prerequisite_checks(mut self, checks: Vec<instructions::Instructions>) -> Self {
-    // Validate that all instructions are Assert variants
-    for check in &checks {
-      match check {
-        instructions::Instructions::Assert(_) => {
-          // Valid - this is an Assert instruction
-        }
-        _ => {
-          panic!("Only Assert instructions are allowed for prerequisite checks");
-        }
+  /// Returns `self` for method chaining. If any instruction is not an
+  /// Assert, the mapping is marked invalid and
+  /// [`Package::mapping_for`] reports the error instead of returning it.
+  pub(crate) fn add_installed_checks(mut self, checks: Vec<instructions::Instructions>) -> Self {
+    match checks.into_iter().map(Check::try_from).collect() {
+      Ok(checks) => self.detect_installed(Check::any(checks)),
+      Err(e) => {
+        self.invalid = Some(e);
+        self
       }
     }
-    self.prerequisite_checks.extend(checks);
+  }
+
+  /// Sets an arbitrary check that detects whether the software is installed.
+  ///
+  /// If a detection check already exists, both must pass.
+  ///
+  /// # Arguments
+  ///
+  /// * `check` - Check that passes when the software is installed
+  ///
+  /// # Returns
+  ///
+  /// Returns `self` for method chaining.
+  pub(crate) fn detect_installed(mut self, check: Check) -> Self {
+    self.installed_check = Some(match self.installed_check.take() {
+      Some(existing) => Check::all(vec![existing, check]),
+      None => check,
+    });
+    self
+  }
+
+  /// Adds prerequisites that must hold before the software can be installed.
+  ///
+  /// **All** prerequisites must pass. If one fails, the installation of this
+  /// package is aborted with a message naming the missing condition.
+  ///
+  /// # Arguments
+  ///
+  /// * `prerequisites` - Checks such as [`Check::command`] or [`Check::admin`]
+  ///
+  /// # Returns
+  ///
+  /// Returns `self` for method chaining.
+  pub(crate) fn add_prerequisites(mut self, prerequisites: Vec<Check>) -> Self {
+    self.prerequisites.extend(prerequisites);
+    self
+  }
+
+  /// Returns the first prerequisite that does not hold, with the reason.
+  fn unmet_prerequisite(&self, context: &ExecutionContext) -> Option<(String, String)> {
+    self.prerequisites.iter().find_map(|prerequisite| {
+      prerequisite
+        .evaluate(context)
+        .err()
+        .map(|reason| (prerequisite.describe(), reason))
+    })
+  }
+
+  /// Defines a placeholder value for this mapping's instructions.
+  ///
+  /// The variable can be used as `{name}` in any instruction string and
+  /// replaces a built-in value of the same name. Users can still override
+  /// it in the `[variables]` table of their configuration.
+  ///
+  /// # Arguments
+  ///
+  /// * `name` - Placeholder name without braces
+  /// * `value` - Value inserted for the placeholder
+  ///
+  /// # Returns
+  ///
+  /// Returns `self` for method chaining.
+  pub(crate) fn add_variable(mut self, name: &str, value: &str) -> Self {
+    self.variables.insert(name.to_string(), value.to_string());
+    self
+  }
+
+  /// Pins the version installed by this mapping, available as `{version}`.
+  ///
+  /// # Returns
+  ///
+  /// Returns `self` for method chaining.
+  pub(crate) fn pin_version(self, version: &str) -> Self {
+    self.add_variable("version", version)
+  }
+
+  /// Extends a run's context with the variables and scope of this mapping.
+  fn context(&self, base: &ExecutionContext) -> ExecutionContext {
+    base
+      .clone()
+      .with_variables(&self.variables)
+      .with_user_scope(self.user_scope)
+  }
+
+  /// Sets the command used to read the installed version of the package.
+  ///
+  /// The first dotted number in the command's output is treated as the
+  /// installed version. Without a probe, the output of the passing
+  /// installed check is used instead.
+  ///
+  /// # Arguments
+  ///
+  /// * `command` - Command to execute (will be split on whitespace)
+  /// * `minimum` - Lowest version considered up to date, if any
+  ///
+  /// # Returns
+  ///
+  /// Returns `self` for method chaining.
+  pub(crate) fn add_version_probe(mut self, command: &str, minimum: Option<&'static str>) -> Self {
+    self.version_probe = Some(VersionProbe {
+      command: command.split_whitespace().map(|s| s.to_string()).collect(),
+      minimum,
+    });
     self
   }
 
@@ -164,6 +354,62 @@ impl InstructionMapping {
     self
   }
 
+  /// Adds upgrade instructions to this mapping.
+  ///
+  /// These instructions will be executed when an installed package is being
+  /// upgraded. Packages without upgrade instructions fall back to the upgrade
+  /// verb of the system package manager for every
+  /// [`install_application`](instructions::Instruction::install_application)
+  /// step of their installation.
+  ///
+  /// # Arguments
+  ///
+  /// * `instructions` - Vector of instructions to add to the upgrade process
+  ///
+  /// # Returns
+  ///
+  /// Returns `self` for method chaining.
+  pub(crate) fn add_upgrade_instructions(
+    mut self,
+    instructions: Vec<instructions::Instructions>,
+  ) -> Self {
+    self.upgrade_instructions.install.extend(instructions);
+    self
+  }
+
+  /// Returns the instructions that upgrade this package.
+  ///
+  /// Explicit upgrade instructions take precedence; otherwise every
+  /// system package manager installation is turned into an upgrade.
+  fn upgrade_plan(&self) -> Vec<instructions::Instructions> {
+    if !self.upgrade_instructions.install.is_empty() {
+      return self.upgrade_instructions.install.clone();
+    }
+
+    self
+      .install_instructions
+      .install
+      .iter()
+      .filter_map(|instruction| match instruction {
+        instructions::Instructions::InstallApplication(app) => Some(app.upgrade_instruction()),
+        _ => None,
+      })
+      .collect()
+  }
+
+  /// Returns the system package manager name of this package, if it is
+  /// installed through one.
+  fn backend_package(&self) -> Option<&'static str> {
+    self
+      .install_instructions
+      .install
+      .iter()
+      .find_map(|instruction| match instruction {
+        instructions::Instructions::InstallApplication(app) => Some(app.package_name()),
+        _ => None,
+      })
+  }
+
   /// Adds configuration instructions to this mapping.
   ///
   /// These instructions will be executed after the package is installed
@@ -232,8 +478,8 @@ pub struct Package {
   name: &'static str,
   /// Brief description of what the package provides
   description: &'static str,
-  /// OS-specific instruction mappings for this package
-  mapping: HashMap<config::machine::OS, InstructionMapping>,
+  /// Instruction mappings with the systems they apply to, in the order they were added
+  mapping: Vec<(config::machine::OsMatcher, InstructionMapping)>,
 }
 
 impl Package {
@@ -250,14 +496,16 @@ impl Package {
     Self {
       name,
       description,
-      mapping: HashMap::new(),
+      mapping: Vec::new(),
     }
   }
 
   /// Adds an instruction mapping for specific operating systems.
   ///
   /// This method associates a set of installation/configuration instructions
-  /// with one or more operating systems using an OS matcher.
+  /// with one or more operating systems using an OS matcher. Mappings may
+  /// overlap, e.g. a generic Debian mapping and one restricted to ARM; the
+  /// most specific match is used (see [`mapping_for`](Self::mapping_for)).
   ///
   /// # Arguments
   ///
@@ -272,11 +520,80 @@ impl Package {
     os: config::machine::OsMatcher,
     mapping: InstructionMapping,
   ) -> Self {
-    for os_type in os.get_list() {
-      self.mapping.insert(*os_type, mapping.clone());
-    }
+    self.mapping.push((os, mapping));
     self
   }
+
+  /// Adds an instruction mapping that installs for the current user only.
+  ///
+  /// The mapping is used instead of the regular ones when no administrator
+  /// rights are available, e.g. for students on school machines. It should
+  /// install into the user's home, e.g. portable archives into `{user_opt}`,
+  /// Node.js through nvm or the per-user setup of an application.
+  /// [`InstallApplication`](instructions::InstallApplication) only uses
+  /// package managers that support per-user installs (winget, Homebrew).
+  ///
+  /// # Arguments
+  ///
+  /// * `os` - An OS matcher that specifies which operating systems this mapping applies to
+  /// * `mapping` - The instruction mapping for installs without administrator rights
+  ///
+  /// # Returns
+  ///
+  /// Returns `self` for method chaining.
+  pub(crate) fn add_user_mapping(
+    self,
+    os: config::machine::OsMatcher,
+    mut mapping: InstructionMapping,
+  ) -> Self {
+    mapping.user_scope = true;
+    self.add_mapping(os.without_fact(config::machine::Fact::Admin), mapping)
+  }
+
+  /// Selects the instruction mapping for a machine.
+  ///
+  /// Among all mappings whose matcher accepts the machine, the most specific
+  /// one is chosen (see [`OsMatcher::specificity`](config::machine::OsMatcher::specificity)).
+  /// If two matchers are equally specific, the one added first wins.
+  ///
+  /// # Errors
+  ///
+  /// Returns a message naming the machine and the supported systems if no
+  /// mapping matches, or the reason why the matching mapping is invalid.
+  pub(crate) fn mapping_for(
+    &self,
+    machine: &config::machine::Machine,
+  ) -> Result<&InstructionMapping, String> {
+    self
+      .mapping
+      .iter()
+      .rev()
+      .filter(|(matcher, _)| matcher.matches_machine(machine))
+      .max_by_key(|(matcher, _)| matcher.specificity())
+      .map(|(_, mapping)| mapping)
+      .ok_or_else(|| {
+        let supported: Vec<String> = self
+          .mapping
+          .iter()
+          .map(|(matcher, _)| matcher.to_string())
+          .collect();
+        i18n::unsupported_machine(self.name, machine, supported.join("; "))
+      })
+      .and_then(|mapping| match &mapping.invalid {
+        Some(reason) => Err(format!("{}: {}", self.name, reason)),
+        None => Ok(mapping),
+      })
+  }
+}
+
+/// Options controlling how packages are installed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InstallOptions {
+  /// Only print what would be done without making changes
+  pub dry_run: bool,
+  /// Run the installation instructions even if the installed checks
+  /// report the package as already installed
+  pub force: bool,
 }
 
 /// A collection of related software packages that are installed together.
@@ -309,6 +626,12 @@ pub struct SoftwareBundle {
   programs: Vec<Package>,
   /// Logger system for thread communication
   logger_system: Option<LoggerSystem>,
+  /// User-defined placeholder values from the configuration
+  variables: BTreeMap<String, String>,
+  /// Proxy, CA and mirror settings passed on to every instruction
+  network: NetworkConfig,
+  /// Directory for portable installs chosen by the user
+  install_path: Option<PathBuf>,
 }
 
 impl SoftwareBundle {
@@ -324,9 +647,56 @@ impl SoftwareBundle {
       description,
       programs: Vec::new(),
       logger_system: None,
+      variables: BTreeMap::new(),
+      network: NetworkConfig::default(),
+      install_path: None,
     }
   }
 
+  /// Sets the user-defined placeholder values for all instructions.
+  ///
+  /// These come from the `[variables]` table of the configuration and take
+  /// precedence over built-in and package variables.
+  ///
+  /// # Returns
+  ///
+  /// Returns `self` for method chaining.
+  pub fn with_variables(mut self, variables: BTreeMap<String, String>) -> Self {
+    self.variables = variables;
+    self
+  }
+
+  /// Sets the proxy, CA and mirror settings used by all instructions.
+  ///
+  /// # Returns
+  ///
+  /// Returns `self` for method chaining.
+  pub fn with_network(mut self, network: NetworkConfig) -> Self {
+    self.network = network;
+    self
+  }
+
+  /// Sets the directory for portable installs.
+  ///
+  /// Package managers that support it (winget, Chocolatey) install into a
+  /// subdirectory per package; system packages on Linux keep their location.
+  ///
+  /// # Returns
+  ///
+  /// Returns `self` for method chaining.
+  pub fn with_install_path(mut self, install_path: Option<PathBuf>) -> Self {
+    self.install_path = install_path;
+    self
+  }
+
+  /// Builds the execution context shared by all packages of a run.
+  fn context(&self, machine: &config::machine::Machine, dry_run: bool) -> ExecutionContext {
+    ExecutionContext::new(machine, dry_run)
+      .with_user_variables(&self.variables)
+      .with_network(&self.network)
+      .with_install_path(self.install_path.as_deref())
+  }
+
   /// Initializes the logger system for this bundle.
   /// 
   /// This sets up the logging infrastructure that will be used by all
@@ -334,8 +704,8 @@ impl SoftwareBundle {
   pub(crate) fn init_logger(&mut self, log_level: LogLevel) -> LogCollector {
     let (logger_system, mut collector) = LoggerSystem::new();
     
-    // Add console output with colors
-    collector.add_output(Box::new(ConsoleOutput::new(true)));
+    // Add console output, with colors where the terminal supports them
+    collector.add_output(Box::new(ConsoleOutput::new(prowo_log::colors_supported())));
     
     // Add level filter using the provided log level
     collector.add_filter(Box::new(LevelFilter::new(log_level)));
@@ -361,67 +731,228 @@ impl SoftwareBundle {
     self
   }
 
-  fn installer_thread(program: &Package, os: &config::machine::OS, dry_run: bool, logger: Logger) {
-    logger.info(format!("Installing program: {}", program.name));
-    let commands = program
-      .mapping
-      .get(os)
-      .expect(&format!("No installation commands found for OS: {:?}", os));
-
-    // Check prerequisites first
-    if !commands.prerequisite_checks.is_empty() {
-      logger.info("Checking prerequisites...");
-      for check in &commands.prerequisite_checks {
-        match check.run(dry_run) {
-          Ok(_) => {
-            logger.info("Program already installed, skipping installation.");
-            return;
-          }
-          Err(_) => {
-            // Prerequisites not met, continue with installation
-            logger.info("Prerequisites not met, proceeding with installation.");
-          }
+  /// Runs the steps of `phase` for one package, logging its lifecycle events.
+  fn run_phase(
+    program: &Package,
+    context: &ExecutionContext,
+    phase: &Phase,
+    logger: &Logger,
+  ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    logger.event(
+      LogLevel::Info,
+      format!("{}: {}", (phase.started)(), program.name),
+      LogEvent::Started { package: program.name, operation: phase.operation },
+    );
+    let commands = match program.mapping_for(context.machine()) {
+      Ok(commands) => commands,
+      Err(e) => {
+        let event = LogEvent::Failed {
+          package: program.name,
+          operation: phase.operation,
+          error: e.clone(),
+        };
+        logger.event(LogLevel::Error, format!("{}: {}", (phase.failed)(), e), event);
+        return Err(e.into());
+      }
+    };
+    let context = commands.context(context).with_logger(logger.clone());
+
+    let steps = (phase.steps)(commands);
+    for (index, instruction) in steps.iter().enumerate() {
+      let logger = logger.clone().with_field("step", index + 1);
+      let description = instruction.describe();
+      logger.event(
+        LogLevel::Info,
+        i18n::step(index + 1, steps.len(), &description),
+        LogEvent::StepStarted {
+          package: program.name,
+          operation: phase.operation,
+          step: index + 1,
+          total: steps.len(),
+          description,
+        },
+      );
+      if let Err(e) = instruction.run(&context) {
+        let event = LogEvent::Failed {
+          package: program.name,
+          operation: phase.operation,
+          error: e.to_string(),
+        };
+        logger.event(LogLevel::Error, format!("{}: {}", (phase.failed)(), e), event);
+        return Err(e);
+      }
+      logger.event(
+        LogLevel::Info,
+        (phase.step_done)(),
+        LogEvent::Step {
+          package: program.name,
+          operation: phase.operation,
+          step: index + 1,
+          total: steps.len(),
+        },
+      );
+    }
+
+    logger.event(
+      LogLevel::Info,
+      format!("{}: {}", (phase.completed)(), program.name),
+      LogEvent::Finished {
+        package: program.name,
+        operation: phase.operation,
+        outcome: "ok",
+        detail: String::new(),
+      },
+    );
+    Ok(())
+  }
+
+  fn installer_thread(
+    program: &Package,
+    context: &ExecutionContext,
+    force: bool,
+    logger: Logger,
+  ) -> PackageOutcome {
+    logger.event(
+      LogLevel::Info,
+      i18n::installing_program(program.name),
+      LogEvent::Started { package: program.name, operation: "install" },
+    );
+    let commands = match program.mapping_for(context.machine()) {
+      Ok(commands) => commands,
+      Err(e) => {
+        let outcome = PackageOutcome::skipped(
+          program.name,
+          i18n::no_instructions(context.machine()),
+        );
+        logger.event(LogLevel::Warning, e, outcome.event("install"));
+        return outcome;
+      }
+    };
+    let context = commands.context(context).with_logger(logger.clone());
+    if context.user_scope() {
+      logger.info(i18n::user_scope());
+    }
+
+    // Detect an existing installation first, unless a reinstall is forced
+    if force {
+      logger.info(i18n::force_mode());
+    } else if let Some(check) = &commands.installed_check {
+      logger.info(i18n::checking_installed());
+      match check.evaluate(&context) {
+        Ok(_) => {
+          let outcome = PackageOutcome::skipped(program.name, i18n::already_installed());
+          logger.event(
+            LogLevel::Info,
+            i18n::skipping_installed(),
+            outcome.event("install"),
+          );
+          return outcome;
+        }
+        Err(reason) => {
+          logger.debug(i18n::not_installed(reason));
+          logger.info(i18n::proceeding_install());
         }
       }
     }
 
-    for instruction in &commands.install_instructions.install {
-      if let Err(e) = instruction.run(dry_run) {
-        logger.error(format!("Command failed: {}", e));
-        return;
+    // True prerequisites block the installation when they are not met
+    if !commands.prerequisites.is_empty() {
+      logger.info(i18n::checking_prerequisites());
+      if let Some((prerequisite, reason)) = commands.unmet_prerequisite(&context) {
+        let outcome = PackageOutcome::failed(
+          program.name,
+          i18n::prerequisite_unmet(&prerequisite),
+        );
+        logger.event(
+          LogLevel::Error,
+          i18n::prerequisite_unmet_because(prerequisite, reason),
+          outcome.event("install"),
+        );
+        return outcome;
       }
-      logger.info("Command executed successfully.");
     }
-    
-    logger.info(format!("Completed installation of: {}", program.name));
+
+    let steps = &commands.install_instructions.install;
+    for (index, instruction) in steps.iter().enumerate() {
+      let logger = logger.clone().with_field("step", index + 1);
+      let description = instruction.describe();
+      logger.event(
+        LogLevel::Info,
+        i18n::step(index + 1, steps.len(), &description),
+        LogEvent::StepStarted {
+          package: program.name,
+          operation: "install",
+          step: index + 1,
+          total: steps.len(),
+          description,
+        },
+      );
+      if let Err(e) = instruction.run(&context) {
+        let outcome = PackageOutcome::failed(program.name, e.to_string());
+        logger.event(LogLevel::Error, i18n::command_failed(e), outcome.event("install"));
+        return outcome;
+      }
+      logger.event(
+        LogLevel::Info,
+        i18n::command_done(),
+        LogEvent::Step {
+          package: program.name,
+          operation: "install",
+          step: index + 1,
+          total: steps.len(),
+        },
+      );
+    }
+
+    let outcome = PackageOutcome::succeeded(program.name, i18n::installed());
+    logger.event(
+      LogLevel::Info,
+      i18n::install_completed(program.name),
+      outcome.event("install"),
+    );
+    outcome
   }
 
   fn installer(
     &self,
-    os: &config::machine::OS,
-    dry_run: bool,
+    machine: &config::machine::Machine,
+    options: InstallOptions,
+    report: &mut OperationReport,
   ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let context = self.context(machine, options.dry_run);
     let mut handles = vec![];
 
     for program in &self.programs {
+      if report.outcome_of(program.name) == Some(Outcome::Failed) {
+        continue;
+      }
+
       if let Some(ref logger_system) = self.logger_system {
-        let logger = logger_system.create_logger("installer", format!("install-{}", program.name));
-        let os = os.clone();
+        let logger = logger_system
+          .create_logger("installer", format!("install-{}", program.name))
+          .with_field("package", program.name)
+          .with_field("phase", "install");
+        let context = context.clone();
         let program = program.clone();
+        let name = program.name;
         let handle = std::thread::spawn(move || {
-          Self::installer_thread(&program, &os, dry_run, logger);
+          Self::installer_thread(&program, &context, options.force, logger)
         });
-        handles.push(handle);
+        handles.push((name, handle));
       } else {
-        return Err("Logger system not initialized. Call init_logger() first.".into());
+        return Err(i18n::logger_not_initialized().into());
       }
     }
 
-    for handle in handles {
-      if let Err(e) = handle.join() {
-        if let Some(ref logger_system) = self.logger_system {
-          let logger = logger_system.create_logger("installer", "main".to_string());
-          logger.error(format!("Thread panicked during installation: {:?}", e));
+    for (name, handle) in handles {
+      match handle.join() {
+        Ok(outcome) => report.record(outcome),
+        Err(e) => {
+          if let Some(ref logger_system) = self.logger_system {
+            let logger = logger_system.create_logger("installer", "main".to_string());
+            logger.error(i18n::install_thread_panicked(format!("{:?}", e)));
+          }
+          report.record(PackageOutcome::failed(name, i18n::installer_panicked()));
         }
       }
     }
@@ -431,67 +962,66 @@ impl SoftwareBundle {
 
   fn configurator_thread(
     program: &Package,
-    os: &config::machine::OS,
-    dry_run: bool,
+    context: &ExecutionContext,
     logger: Logger,
   ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    logger.info(format!("Configuring program: {}", program.name));
-    let commands = program
-      .mapping
-      .get(os)
-      .expect(&format!("No configuration commands found for OS: {:?}", os));
-
-    for instruction in &commands.configuration_instructions.install {
-      if let Err(e) = instruction.run(dry_run) {
-        logger.error(format!("Configuration failed: {}", e));
-        return Err(e);
-      }
-      logger.info("Configuration applied successfully.");
-    }
-    
-    logger.info(format!("Completed configuration of: {}", program.name));
-    Ok(())
+    Self::run_phase(program, context, &Phase::CONFIGURE, &logger)
   }
 
   fn configurator(
     &self,
-    os: &config::machine::OS,
+    machine: &config::machine::Machine,
     dry_run: bool,
+    report: &mut OperationReport,
   ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let context = self.context(machine, dry_run);
     let mut handles = vec![];
 
     for program in &self.programs {
-      if let Some(commands) = program.mapping.get(os) {
+      if report.outcome_of(program.name) == Some(Outcome::Failed) {
+        continue;
+      }
+
+      if let Ok(commands) = program.mapping_for(machine) {
         if commands.configuration_instructions.install.is_empty() {
           if let Some(ref logger_system) = self.logger_system {
             let logger = logger_system.create_logger("configurator", "main".to_string());
-            logger.info(format!("No configuration functions for program: {}", program.name));
+            logger.info(i18n::no_configuration(program.name));
           }
           continue;
         }
 
         if let Some(ref logger_system) = self.logger_system {
-          let logger = logger_system.create_logger("configurator", format!("config-{}", program.name));
-          let os = os.clone();
+          let logger = logger_system
+            .create_logger("configurator", format!("config-{}", program.name))
+            .with_field("package", program.name)
+            .with_field("phase", "configure");
+          let context = context.clone();
           let program = program.clone();
-          let handle = std::thread::spawn(move || Self::configurator_thread(&program, &os, dry_run, logger));
-          handles.push(handle);
+          let name = program.name;
+          let handle = std::thread::spawn(move || Self::configurator_thread(&program, &context, logger));
+          handles.push((name, handle));
         } else {
-          return Err("Logger system not initialized. Call init_logger() first.".into());
+          return Err(i18n::logger_not_initialized().into());
         }
       } else {
         if let Some(ref logger_system) = self.logger_system {
           let logger = logger_system.create_logger("configurator", "main".to_string());
-          logger.warn(format!("No configuration mapping found for program: {}", program.name));
+          logger.warn(i18n::no_configuration_mapping(program.name));
         }
       }
     }
 
-    for handle in handles {
-      if let Err(e) = handle.join() {
-        if let Some(ref logger_system) = self.logger_system {
-          let logger = logger_system.create_logger("configurator", "main".to_string());
-          logger.error(format!("Thread panicked during configuration: {:?}", e));
+    for (name, handle) in handles {
+      match handle.join() {
+        Ok(Ok(())) => {}
+        Ok(Err(e)) => report.mark_failed(name, i18n::configuration_failed(e)),
+        Err(e) => {
+          if let Some(ref logger_system) = self.logger_system {
+            let logger = logger_system.create_logger("configurator", "main".to_string());
+            logger.error(i18n::configure_thread_panicked(format!("{:?}", e)));
+          }
+          report.mark_failed(name, i18n::configurator_panicked());
         }
       }
     }
@@ -499,111 +1029,394 @@ impl SoftwareBundle {
     Ok(())
   }
 
-  pub(crate) fn install(
+  pub fn install(
     &mut self,
-    os: &config::machine::OS,
-    dry_run: bool,
+    machine: &config::machine::Machine,
+    options: InstallOptions,
     logger_system: &LoggerSystem,
-  ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+  ) -> Result<OperationReport, Box<dyn std::error::Error + Send + Sync>> {
     // Use the provided logger system instead of creating our own
     self.logger_system = Some(logger_system.clone());
+    let mut report = OperationReport::new(i18n::operation_installation());
     
     if let Some(ref logger_system) = self.logger_system {
       let main_logger = logger_system.create_logger("bundle", "main".to_string());
       
-      main_logger.info(format!("Installing bundle: {}", self.name));
-      main_logger.info(format!("Description: {}", self.description));
+      main_logger.info(i18n::installing_bundle(self.name));
+      main_logger.info(i18n::bundle_description(self.description));
 
       // Show packages to be installed
-      main_logger.info("The following packages will be installed:");
+      main_logger.info(i18n::packages_to_install());
       for program in &self.programs {
         main_logger.info(format!("  - {} ({})", program.name, program.description));
       }
+      if options.force {
+        main_logger.info(i18n::force_reinstall());
+      }
 
-      // Interactive confirmation unless in dry-run mode
-      if !dry_run {
-        let response = crate::config::interactive::ask_yes_no(
-          "Do you want to continue with the installation?",
+      // Confirmation unless in dry-run mode (answered by --yes or the answer file when unattended)
+      if !options.dry_run {
+        let response = crate::config::interactive::confirm(
+          "install.confirm",
+          i18n::install_confirm(),
           true,
         );
         if !response {
-          main_logger.info("Installation cancelled by user.");
-          return Ok(());
+          main_logger.info(i18n::install_cancelled());
+          return Ok(report);
         }
       }
 
-      self.installer(os, dry_run)?;
-      self.configurator(os, dry_run)?;
+      self.installer(machine, options, &mut report)?;
+      self.configurator(machine, options.dry_run, &mut report)?;
       
-      main_logger.info("Bundle installation completed successfully!");
+      report.log(&main_logger);
+      if report.has_failures() {
+        main_logger.warn(i18n::bundle_install_failed());
+      } else {
+        main_logger.info(i18n::bundle_install_done());
+      }
     }
     
-    Ok(())
+    Ok(report)
   }
 
-  fn uninstaller_thread(
-    program: &Package,
-    os: &config::machine::OS,
+  /// Reinstalls and reconfigures every package of the bundle.
+  ///
+  /// Unlike [`install`](Self::install), the installed checks are ignored,
+  /// so packages that are present but broken are installed again. With
+  /// `uninstall_first`, each package is removed and deconfigured beforehand;
+  /// a package that cannot be removed is reported as failed and left alone.
+  pub fn repair(
+    &mut self,
+    machine: &config::machine::Machine,
     dry_run: bool,
+    uninstall_first: bool,
+    logger_system: &LoggerSystem,
+  ) -> Result<OperationReport, Box<dyn std::error::Error + Send + Sync>> {
+    self.logger_system = Some(logger_system.clone());
+    let mut report = OperationReport::new(i18n::operation_repair());
+    let options = InstallOptions {
+      dry_run,
+      force: true,
+    };
+
+    if let Some(ref logger_system) = self.logger_system {
+      let main_logger = logger_system.create_logger("bundle", "main".to_string());
+
+      main_logger.info(i18n::repairing_bundle(self.name));
+      main_logger.info(i18n::bundle_description(self.description));
+
+      main_logger.info(i18n::packages_to_repair());
+      for program in &self.programs {
+        main_logger.info(format!("  - {} ({})", program.name, program.description));
+      }
+      if uninstall_first {
+        main_logger.info(i18n::uninstall_first());
+      }
+
+      // Confirmation unless in dry-run mode (answered by --yes or the answer file when unattended)
+      if !dry_run {
+        let response = crate::config::interactive::confirm(
+          "repair.confirm",
+          i18n::repair_confirm(),
+          true,
+        );
+        if !response {
+          main_logger.info(i18n::repair_cancelled());
+          return Ok(report);
+        }
+      }
+
+      if uninstall_first {
+        self.uninstaller(machine, dry_run, &mut report)?;
+        self.deconfigurator(machine, dry_run, &mut report)?;
+      }
+      self.installer(machine, options, &mut report)?;
+      self.configurator(machine, dry_run, &mut report)?;
+
+      report.log(&main_logger);
+      if report.has_failures() {
+        main_logger.warn(i18n::bundle_repair_failed());
+      } else {
+        main_logger.info(i18n::bundle_repair_done());
+      }
+    }
+
+    Ok(report)
+  }
+
+  fn upgrader_thread(
+    program: &Package,
+    context: &ExecutionContext,
     logger: Logger,
+  ) -> PackageOutcome {
+    logger.event(
+      LogLevel::Info,
+      i18n::checking_upgrades(program.name),
+      LogEvent::Started { package: program.name, operation: "upgrade" },
+    );
+    let commands = match program.mapping_for(context.machine()) {
+      Ok(commands) => commands,
+      Err(e) => {
+        let outcome = PackageOutcome::skipped(
+          program.name,
+          i18n::no_instructions(context.machine()),
+        );
+        logger.event(LogLevel::Warning, e, outcome.event("upgrade"));
+        return outcome;
+      }
+    };
+    let context = commands.context(context).with_logger(logger.clone());
+
+    let (state, installed, _) = commands.status(&context);
+    match state {
+      status::InstallState::Missing => {
+        let outcome = PackageOutcome::skipped(program.name, i18n::not_installed_detail());
+        logger.event(
+          LogLevel::Info,
+          i18n::skipping_not_installed(),
+          outcome.event("upgrade"),
+        );
+        return outcome;
+      }
+      status::InstallState::Unknown => {
+        logger.warn(i18n::state_unknown());
+      }
+      _ => {}
+    }
+
+    let plan = commands.upgrade_plan();
+    if plan.is_empty() {
+      let outcome = PackageOutcome::skipped(program.name, i18n::no_upgrade_instructions());
+      logger.event(
+        LogLevel::Info,
+        i18n::skipping_no_upgrade(),
+        outcome.event("upgrade"),
+      );
+      return outcome;
+    }
+
+    let latest = commands.backend_package().and_then(|package| {
+      Backend::available(&context)
+        .iter()
+        .find_map(|backend| backend.latest_version(package, &context))
+    });
+
+    let installed_label = installed
+      .as_ref()
+      .map(|v| v.to_string())
+      .unwrap_or_else(|| i18n::unknown_version().to_string());
+    match (&installed, &latest) {
+      (Some(installed_version), Some(latest_version)) if installed_version >= latest_version => {
+        let outcome = PackageOutcome::skipped(program.name, i18n::already_at(installed_version));
+        logger.event(
+          LogLevel::Info,
+          i18n::up_to_date(installed_version),
+          outcome.event("upgrade"),
+        );
+        return outcome;
+      }
+      (_, Some(latest_version)) => {
+        logger.info(i18n::upgrading_from_to(&installed_label, latest_version));
+      }
+      (_, None) => {
+        logger.info(i18n::upgrading_to_latest(&installed_label));
+      }
+    }
+
+    for (index, instruction) in plan.iter().enumerate() {
+      let logger = logger.clone().with_field("step", index + 1);
+      let description = instruction.describe();
+      logger.event(
+        LogLevel::Info,
+        i18n::step(index + 1, plan.len(), &description),
+        LogEvent::StepStarted {
+          package: program.name,
+          operation: "upgrade",
+          step: index + 1,
+          total: plan.len(),
+          description,
+        },
+      );
+      if let Err(e) = instruction.run(&context) {
+        let outcome = PackageOutcome::failed(program.name, e.to_string());
+        logger.event(LogLevel::Error, i18n::upgrade_step_failed(e), outcome.event("upgrade"));
+        return outcome;
+      }
+      logger.event(
+        LogLevel::Info,
+        i18n::upgrade_step_done(),
+        LogEvent::Step {
+          package: program.name,
+          operation: "upgrade",
+          step: index + 1,
+          total: plan.len(),
+        },
+      );
+    }
+
+    let target = latest
+      .map(|v| v.to_string())
+      .unwrap_or_else(|| i18n::latest_version().to_string());
+    let outcome = PackageOutcome::succeeded(program.name, format!("{} -> {}", installed_label, target));
+    logger.event(
+      LogLevel::Info,
+      i18n::upgrade_completed(program.name),
+      outcome.event("upgrade"),
+    );
+    outcome
+  }
+
+  fn upgrader(
+    &self,
+    machine: &config::machine::Machine,
+    dry_run: bool,
+    report: &mut OperationReport,
   ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    logger.info(format!("Uninstalling program: {}", program.name));
-    let commands = program.mapping.get(os).expect(&format!(
-      "No uninstallation commands found for OS: {:?}",
-      os
-    ));
-
-    for instruction in &commands.uninstall_instructions.install {
-      if let Err(e) = instruction.run(dry_run) {
-        logger.error(format!("Uninstallation failed: {}", e));
-        return Err(e);
+    let context = self.context(machine, dry_run);
+    let mut handles = vec![];
+
+    for program in &self.programs {
+      if let Some(ref logger_system) = self.logger_system {
+        let logger = logger_system
+          .create_logger("upgrader", format!("upgrade-{}", program.name))
+          .with_field("package", program.name)
+          .with_field("phase", "upgrade");
+        let context = context.clone();
+        let program = program.clone();
+        let name = program.name;
+        let handle = std::thread::spawn(move || Self::upgrader_thread(&program, &context, logger));
+        handles.push((name, handle));
+      } else {
+        return Err(i18n::logger_not_initialized().into());
       }
-      logger.info("Uninstallation executed successfully.");
     }
-    
-    logger.info(format!("Completed uninstallation of: {}", program.name));
+
+    for (name, handle) in handles {
+      match handle.join() {
+        Ok(outcome) => report.record(outcome),
+        Err(e) => {
+          if let Some(ref logger_system) = self.logger_system {
+            let logger = logger_system.create_logger("upgrader", "main".to_string());
+            logger.error(i18n::upgrade_thread_panicked(format!("{:?}", e)));
+          }
+          report.record(PackageOutcome::failed(name, i18n::upgrader_panicked()));
+        }
+      }
+    }
+
     Ok(())
   }
 
+  pub fn upgrade(
+    &mut self,
+    machine: &config::machine::Machine,
+    dry_run: bool,
+    logger_system: &LoggerSystem,
+  ) -> Result<OperationReport, Box<dyn std::error::Error + Send + Sync>> {
+    self.logger_system = Some(logger_system.clone());
+    let mut report = OperationReport::new(i18n::operation_upgrade());
+
+    if let Some(ref logger_system) = self.logger_system {
+      let main_logger = logger_system.create_logger("bundle", "main".to_string());
+
+      main_logger.info(i18n::upgrading_bundle(self.name));
+      main_logger.info(i18n::bundle_description(self.description));
+
+      main_logger.info(i18n::packages_to_upgrade());
+      for program in &self.programs {
+        main_logger.info(format!("  - {} ({})", program.name, program.description));
+      }
+
+      // Confirmation unless in dry-run mode (answered by --yes or the answer file when unattended)
+      if !dry_run {
+        let response = crate::config::interactive::confirm(
+          "upgrade.confirm",
+          i18n::upgrade_confirm(),
+          true,
+        );
+        if !response {
+          main_logger.info(i18n::upgrade_cancelled());
+          return Ok(report);
+        }
+      }
+
+      self.upgrader(machine, dry_run, &mut report)?;
+
+      report.log(&main_logger);
+      if report.has_failures() {
+        main_logger.warn(i18n::bundle_upgrade_failed());
+      } else {
+        main_logger.info(i18n::bundle_upgrade_done());
+      }
+    }
+
+    Ok(report)
+  }
+
+  fn uninstaller_thread(
+    program: &Package,
+    context: &ExecutionContext,
+    logger: Logger,
+  ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    Self::run_phase(program, context, &Phase::UNINSTALL, &logger)
+  }
+
   fn uninstaller(
     &self,
-    os: &config::machine::OS,
+    machine: &config::machine::Machine,
     dry_run: bool,
+    report: &mut OperationReport,
   ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let context = self.context(machine, dry_run);
     let mut handles = vec![];
 
     for program in &self.programs {
-      if let Some(commands) = program.mapping.get(os) {
+      if report.outcome_of(program.name) == Some(Outcome::Failed) {
+        continue;
+      }
+
+      if let Ok(commands) = program.mapping_for(machine) {
         if commands.uninstall_instructions.install.is_empty() {
           if let Some(ref logger_system) = self.logger_system {
             let logger = logger_system.create_logger("uninstaller", "main".to_string());
-            logger.info(format!("No uninstallation functions for program: {}", program.name));
+            logger.info(i18n::no_uninstallation(program.name));
           }
           continue;
         }
 
         if let Some(ref logger_system) = self.logger_system {
-          let logger = logger_system.create_logger("uninstaller", format!("uninstall-{}", program.name));
-          let os = os.clone();
+          let logger = logger_system
+            .create_logger("uninstaller", format!("uninstall-{}", program.name))
+            .with_field("package", program.name)
+            .with_field("phase", "uninstall");
+          let context = context.clone();
           let program = program.clone();
-          let handle = std::thread::spawn(move || Self::uninstaller_thread(&program, &os, dry_run, logger));
-          handles.push(handle);
+          let name = program.name;
+          let handle = std::thread::spawn(move || Self::uninstaller_thread(&program, &context, logger));
+          handles.push((name, handle));
         } else {
-          return Err("Logger system not initialized. Call init_logger() first.".into());
+          return Err(i18n::logger_not_initialized().into());
         }
       } else {
         if let Some(ref logger_system) = self.logger_system {
           let logger = logger_system.create_logger("uninstaller", "main".to_string());
-          logger.warn(format!("No uninstallation mapping found for program: {}", program.name));
+          logger.warn(i18n::no_uninstallation_mapping(program.name));
         }
       }
     }
 
-    for handle in handles {
-      if let Err(e) = handle.join() {
-        if let Some(ref logger_system) = self.logger_system {
-          let logger = logger_system.create_logger("uninstaller", "main".to_string());
-          logger.error(format!("Thread panicked during uninstallation: {:?}", e));
+    for (name, handle) in handles {
+      match handle.join() {
+        Ok(Ok(())) => {}
+        Ok(Err(e)) => report.mark_failed(name, i18n::uninstallation_failed(e)),
+        Err(e) => {
+          if let Some(ref logger_system) = self.logger_system {
+            let logger = logger_system.create_logger("uninstaller", "main".to_string());
+            logger.error(i18n::uninstall_thread_panicked(format!("{:?}", e)));
+          }
+          report.mark_failed(name, i18n::uninstaller_panicked());
         }
       }
     }
@@ -613,67 +1426,66 @@ impl SoftwareBundle {
 
   fn deconfigurator_thread(
     program: &Package,
-    os: &config::machine::OS,
-    dry_run: bool,
+    context: &ExecutionContext,
     logger: Logger,
   ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    logger.info(format!("Deconfiguring program: {}", program.name));
-    let commands = program.mapping.get(os).expect(&format!(
-      "No deconfiguration commands found for OS: {:?}",
-      os
-    ));
-
-    for instruction in &commands.deconfiguration_instructions.install {
-      if let Err(e) = instruction.run(dry_run) {
-        logger.error(format!("Deconfiguration failed: {}", e));
-        return Err(e);
-      }
-      logger.info("Deconfiguration applied successfully.");
-    }
-    
-    logger.info(format!("Completed deconfiguration of: {}", program.name));
-    Ok(())
+    Self::run_phase(program, context, &Phase::DECONFIGURE, &logger)
   }
 
   fn deconfigurator(
     &self,
-    os: &config::machine::OS,
+    machine: &config::machine::Machine,
     dry_run: bool,
+    report: &mut OperationReport,
   ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let context = self.context(machine, dry_run);
     let mut handles = vec![];
 
     for program in &self.programs {
-      if let Some(commands) = program.mapping.get(os) {
+      if report.outcome_of(program.name) == Some(Outcome::Failed) {
+        continue;
+      }
+
+      if let Ok(commands) = program.mapping_for(machine) {
         if commands.deconfiguration_instructions.install.is_empty() {
           if let Some(ref logger_system) = self.logger_system {
             let logger = logger_system.create_logger("deconfigurator", "main".to_string());
-            logger.info(format!("No deconfiguration functions for program: {}", program.name));
+            logger.info(i18n::no_deconfiguration(program.name));
           }
           continue;
         }
 
         if let Some(ref logger_system) = self.logger_system {
-          let logger = logger_system.create_logger("deconfigurator", format!("deconfig-{}", program.name));
-          let os = os.clone();
+          let logger = logger_system
+            .create_logger("deconfigurator", format!("deconfig-{}", program.name))
+            .with_field("package", program.name)
+            .with_field("phase", "deconfigure");
+          let context = context.clone();
           let program = program.clone();
-          let handle = std::thread::spawn(move || Self::deconfigurator_thread(&program, &os, dry_run, logger));
-          handles.push(handle);
+          let name = program.name;
+          let handle = std::thread::spawn(move || Self::deconfigurator_thread(&program, &context, logger));
+          handles.push((name, handle));
         } else {
-          return Err("Logger system not initialized. Call init_logger() first.".into());
+          return Err(i18n::logger_not_initialized().into());
         }
       } else {
         if let Some(ref logger_system) = self.logger_system {
           let logger = logger_system.create_logger("deconfigurator", "main".to_string());
-          logger.warn(format!("No deconfiguration mapping found for program: {}", program.name));
+          logger.warn(i18n::no_deconfiguration_mapping(program.name));
         }
       }
     }
 
-    for handle in handles {
-      if let Err(e) = handle.join() {
-        if let Some(ref logger_system) = self.logger_system {
-          let logger = logger_system.create_logger("deconfigurator", "main".to_string());
-          logger.error(format!("Thread panicked during deconfiguration: {:?}", e));
+    for (name, handle) in handles {
+      match handle.join() {
+        Ok(Ok(())) => {}
+        Ok(Err(e)) => report.mark_failed(name, i18n::deconfiguration_failed(e)),
+        Err(e) => {
+          if let Some(ref logger_system) = self.logger_system {
+            let logger = logger_system.create_logger("deconfigurator", "main".to_string());
+            logger.error(i18n::deconfigure_thread_panicked(format!("{:?}", e)));
+          }
+          report.mark_failed(name, i18n::deconfigurator_panicked());
         }
       }
     }
@@ -681,9 +1493,9 @@ impl SoftwareBundle {
     Ok(())
   }
 
-  pub(crate) fn uninstall(
+  pub fn uninstall(
     &mut self,
-    os: &config::machine::OS,
+    machine: &config::machine::Machine,
     dry_run: bool,
     logger_system: &LoggerSystem,
   ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
@@ -693,31 +1505,38 @@ impl SoftwareBundle {
     if let Some(ref logger_system) = self.logger_system {
       let main_logger = logger_system.create_logger("bundle", "main".to_string());
       
-      main_logger.info(format!("Uninstalling bundle: {}", self.name));
-      main_logger.info(format!("Description: {}", self.description));
+      main_logger.info(i18n::uninstalling_bundle(self.name));
+      main_logger.info(i18n::bundle_description(self.description));
 
       // Show packages to be uninstalled
-      main_logger.info("The following packages will be uninstalled:");
+      main_logger.info(i18n::packages_to_uninstall());
       for program in &self.programs {
         main_logger.info(format!("  - {} ({})", program.name, program.description));
       }
 
-      // Interactive confirmation unless in dry-run mode
+      // Confirmation unless in dry-run mode (answered by --yes or the answer file when unattended)
       if !dry_run {
-        let response = crate::config::interactive::ask_yes_no(
-          "Are you sure you want to uninstall these packages?",
+        let response = crate::config::interactive::confirm(
+          "uninstall.confirm",
+          i18n::uninstall_confirm(),
           false,
         );
         if !response {
-          main_logger.info("Uninstallation cancelled by user.");
+          main_logger.info(i18n::uninstall_cancelled());
           return Ok(());
         }
       }
 
-      self.uninstaller(os, dry_run)?;
-      self.deconfigurator(os, dry_run)?;
+      let mut report = OperationReport::new(i18n::operation_uninstallation());
+      self.uninstaller(machine, dry_run, &mut report)?;
+      self.deconfigurator(machine, dry_run, &mut report)?;
       
-      main_logger.info("Bundle uninstallation completed successfully!");
+      if report.has_failures() {
+        report.log(&main_logger);
+        main_logger.warn(i18n::bundle_uninstall_failed());
+      } else {
+        main_logger.info(i18n::bundle_uninstall_done());
+      }
     }
     
     Ok(())
@@ -0,0 +1,154 @@
+//! # Operation Reports
+//!
+//! Summaries of what happened to each package during a bundle operation
+//! (install, upgrade, ...). Installer threads record one outcome per package,
+//! and the finished report is logged at the end of the run so users see at a
+//! glance which packages succeeded, were skipped, or failed.
+
+use crate::i18n;
+use prowo_log::{LogEvent, Logger};
+
+/// Final result of an operation on a single package.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+  /// The operation ran and finished successfully
+  Succeeded,
+  /// Nothing had to be done, e.g. the package was already installed
+  Skipped,
+  /// The operation was attempted and failed
+  Failed,
+}
+
+impl std::fmt::Display for Outcome {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Outcome::Succeeded => write!(f, "{}", i18n::outcome_ok()),
+      Outcome::Skipped => write!(f, "{}", i18n::outcome_skipped()),
+      Outcome::Failed => write!(f, "{}", i18n::outcome_failed()),
+    }
+  }
+}
+
+/// Outcome of an operation on one package, with a short explanation.
+#[derive(Debug, Clone)]
+pub struct PackageOutcome {
+  /// Display name of the package
+  pub name: &'static str,
+  /// What happened
+  pub outcome: Outcome,
+  /// Why it happened, e.g. the error message of a failed command
+  pub detail: String,
+}
+
+impl PackageOutcome {
+  /// Creates a successful outcome.
+  pub fn succeeded(name: &'static str, detail: impl Into<String>) -> Self {
+    Self {
+      name,
+      outcome: Outcome::Succeeded,
+      detail: detail.into(),
+    }
+  }
+
+  /// Creates a skipped outcome.
+  pub fn skipped(name: &'static str, detail: impl Into<String>) -> Self {
+    Self {
+      name,
+      outcome: Outcome::Skipped,
+      detail: detail.into(),
+    }
+  }
+
+  /// Creates a failed outcome.
+  pub fn failed(name: &'static str, detail: impl Into<String>) -> Self {
+    Self {
+      name,
+      outcome: Outcome::Failed,
+      detail: detail.into(),
+    }
+  }
+
+  /// Returns the lifecycle event that ends `operation` with this outcome.
+  pub fn event(&self, operation: &'static str) -> LogEvent {
+    match self.outcome {
+      Outcome::Failed => LogEvent::Failed {
+        package: self.name,
+        operation,
+        error: self.detail.clone(),
+      },
+      Outcome::Succeeded | Outcome::Skipped => LogEvent::Finished {
+        package: self.name,
+        operation,
+        outcome: if self.outcome == Outcome::Skipped { "skipped" } else { "ok" },
+        detail: self.detail.clone(),
+      },
+    }
+  }
+}
+
+/// Summary of a bundle operation across all of its packages.
+#[derive(Debug, Clone)]
+pub struct OperationReport {
+  /// Name of the operation, e.g. "Installation"
+  pub operation: &'static str,
+  /// Outcomes in the order packages appear in the bundle
+  pub packages: Vec<PackageOutcome>,
+}
+
+impl OperationReport {
+  /// Creates an empty report for the given operation.
+  pub fn new(operation: &'static str) -> Self {
+    Self {
+      operation,
+      packages: Vec::new(),
+    }
+  }
+
+  /// Records the outcome of a package.
+  pub fn record(&mut self, outcome: PackageOutcome) {
+    self.packages.push(outcome);
+  }
+
+  /// Marks an already recorded package as failed, e.g. when its
+  /// configuration fails after a successful installation.
+  pub fn mark_failed(&mut self, name: &'static str, detail: impl Into<String>) {
+    let detail = detail.into();
+    match self.packages.iter_mut().find(|p| p.name == name) {
+      Some(entry) => {
+        entry.outcome = Outcome::Failed;
+        entry.detail = detail;
+      }
+      None => self.packages.push(PackageOutcome::failed(name, detail)),
+    }
+  }
+
+  /// Returns the recorded outcome of a package, if any.
+  pub fn outcome_of(&self, name: &str) -> Option<Outcome> {
+    self
+      .packages
+      .iter()
+      .find(|p| p.name == name)
+      .map(|p| p.outcome)
+  }
+
+  /// Returns `true` if any package failed.
+  pub fn has_failures(&self) -> bool {
+    self.packages.iter().any(|p| p.outcome == Outcome::Failed)
+  }
+
+  /// Writes the summary to the given logger, one line per package.
+  pub fn log(&self, logger: &Logger) {
+    logger.info(i18n::operation_summary(self.operation));
+    for package in &self.packages {
+      let line = if package.detail.is_empty() {
+        format!("  - {}: {}", package.name, package.outcome)
+      } else {
+        format!("  - {}: {} ({})", package.name, package.outcome, package.detail)
+      };
+      match package.outcome {
+        Outcome::Failed => logger.error(line),
+        _ => logger.info(line),
+      }
+    }
+  }
+}
@@ -0,0 +1,281 @@
+//! # Bundle Status Audit
+//!
+//! Read-only inspection of a bundle against the current machine. Every package's
+//! installed checks, prerequisites and version probe are executed for real, but no
+//! installation, configuration or removal instructions are touched.
+//!
+//! The result can be rendered as a plain-text table for humans or serialized
+//! to JSON for scripts that collect the state of many lab machines.
+
+use super::template::ExecutionContext;
+use super::version::Version;
+use super::{InstructionMapping, Package, SoftwareBundle};
+use crate::config;
+use crate::i18n;
+use serde::Serialize;
+use std::process::Command;
+
+/// Installation state of a single package.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum InstallState {
+  /// The installed check passed and the version is recent enough
+  Installed,
+  /// The installed check did not pass
+  Missing,
+  /// The package is present but older than the required minimum version
+  Outdated,
+  /// The package has no instructions for this operating system
+  Unsupported,
+  /// The package defines no checks, so its state cannot be determined
+  Unknown,
+}
+
+impl std::fmt::Display for InstallState {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      InstallState::Installed => write!(f, "{}", i18n::state_installed()),
+      InstallState::Missing => write!(f, "{}", i18n::state_missing()),
+      InstallState::Outdated => write!(f, "{}", i18n::state_outdated()),
+      InstallState::Unsupported => write!(f, "{}", i18n::state_unsupported()),
+      InstallState::Unknown => write!(f, "{}", i18n::state_unknown_short()),
+    }
+  }
+}
+
+/// Audit result for a single package.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PackageStatus {
+  /// Display name of the package
+  pub name: &'static str,
+  /// Detected installation state
+  pub state: InstallState,
+  /// Version reported by the package, if it could be determined
+  pub installed_version: Option<String>,
+  /// Minimum version required by the package definition
+  pub minimum_version: Option<String>,
+  /// Additional explanation, e.g. why a check failed
+  pub detail: Option<String>,
+}
+
+/// Audit result for a whole bundle.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleStatus {
+  /// Display name of the audited bundle
+  pub bundle: &'static str,
+  /// Machine the audit ran on, e.g. "Ubuntu 24.04 (x86_64)"
+  pub os: String,
+  /// Per-package results in bundle order
+  pub packages: Vec<PackageStatus>,
+}
+
+impl BundleStatus {
+  /// Serializes the report as pretty-printed JSON.
+  pub fn to_json(&self) -> Result<String, serde_json::Error> {
+    serde_json::to_string_pretty(self)
+  }
+
+  /// Renders the report as an aligned plain-text table.
+  pub fn render_table(&self) -> String {
+    let headers = [
+      i18n::column_package(),
+      i18n::column_status(),
+      i18n::column_version(),
+      i18n::column_detail(),
+    ];
+    let rows: Vec<[String; 4]> = self
+      .packages
+      .iter()
+      .map(|p| {
+        let version = match (&p.installed_version, &p.minimum_version) {
+          (Some(installed), Some(minimum)) => format!("{} (>= {})", installed, minimum),
+          (Some(installed), None) => installed.clone(),
+          (None, Some(minimum)) => format!("- (>= {})", minimum),
+          (None, None) => "-".to_string(),
+        };
+        [
+          p.name.to_string(),
+          p.state.to_string(),
+          version,
+          p.detail.clone().unwrap_or_default(),
+        ]
+      })
+      .collect();
+
+    let mut widths = headers.map(|h| h.len());
+    for row in &rows {
+      for (i, cell) in row.iter().enumerate() {
+        widths[i] = widths[i].max(cell.chars().count());
+      }
+    }
+
+    let format_row = |cells: [&str; 4]| {
+      let mut line = String::new();
+      for (i, cell) in cells.iter().enumerate() {
+        if i == cells.len() - 1 {
+          line.push_str(cell);
+        } else {
+          line.push_str(&format!("{:<width$}  ", cell, width = widths[i]));
+        }
+      }
+      line.trim_end().to_string()
+    };
+
+    let mut lines = vec![
+      i18n::status_bundle(self.bundle, &self.os),
+      format_row(headers),
+    ];
+    for row in &rows {
+      lines.push(format_row([&row[0], &row[1], &row[2], &row[3]]));
+    }
+    lines.join("\n")
+  }
+}
+
+/// Runs a version probe command and extracts the version from its output.
+fn read_version(command: &[String], context: &ExecutionContext) -> Result<Version, String> {
+  if command.is_empty() {
+    return Err(i18n::empty_version_probe().to_string());
+  }
+  let command = context.resolve_all(command).map_err(|e| e.to_string())?;
+
+  let output = Command::new(&command[0])
+    .args(&command[1..])
+    .output()
+    .map_err(i18n::version_probe_failed)?;
+  let stdout = String::from_utf8_lossy(&output.stdout);
+
+  Version::extract(&stdout).ok_or_else(|| i18n::no_version_found(stdout.trim()))
+}
+
+impl InstructionMapping {
+  /// Determines the installation state described by this mapping.
+  pub(super) fn status(
+    &self,
+    context: &ExecutionContext,
+  ) -> (InstallState, Option<Version>, Option<String>) {
+    let Some(check) = &self.installed_check else {
+      return (
+        InstallState::Unknown,
+        None,
+        Some(i18n::no_installed_checks().to_string()),
+      );
+    };
+
+    let check_output = match check.evaluate(context) {
+      Ok(output) => output.unwrap_or_default(),
+      Err(reason) => {
+        // Explain why an install would fail rather than why the check did
+        let detail = match self.unmet_prerequisite(context) {
+          Some((prerequisite, _)) => i18n::status_prerequisite(prerequisite),
+          None => reason,
+        };
+        return (InstallState::Missing, None, Some(detail));
+      }
+    };
+
+    let installed = match &self.version_probe {
+      Some(probe) => match read_version(&probe.command, context) {
+        Ok(version) => Some(version),
+        Err(e) => return (InstallState::Installed, None, Some(e)),
+      },
+      None => Version::extract(&check_output),
+    };
+
+    let minimum = self
+      .version_probe
+      .as_ref()
+      .and_then(|probe| probe.minimum)
+      .and_then(Version::extract);
+
+    match (&installed, &minimum) {
+      (Some(installed_version), Some(minimum_version)) if installed_version < minimum_version => (
+        InstallState::Outdated,
+        installed,
+        Some(i18n::requires_at_least(minimum_version)),
+      ),
+      _ => (InstallState::Installed, installed, None),
+    }
+  }
+}
+
+impl Package {
+  /// Audits this package on the given operating system without changing anything.
+  ///
+  /// # Arguments
+  ///
+  /// * `context` - Machine to select the instruction mapping for, and the
+  ///   variables used to resolve placeholders in its checks
+  pub fn status(&self, context: &ExecutionContext) -> PackageStatus {
+    let mapping = match self.mapping_for(context.machine()) {
+      Ok(mapping) => mapping,
+      Err(e) => {
+        return PackageStatus {
+          name: self.name,
+          state: InstallState::Unsupported,
+          installed_version: None,
+          minimum_version: None,
+          detail: Some(e),
+        };
+      }
+    };
+
+    let (state, installed, detail) = mapping.status(&mapping.context(context));
+    PackageStatus {
+      name: self.name,
+      state,
+      installed_version: installed.map(|v| v.to_string()),
+      minimum_version: mapping
+        .version_probe
+        .as_ref()
+        .and_then(|probe| probe.minimum)
+        .map(|m| m.to_string()),
+      detail,
+    }
+  }
+}
+
+impl SoftwareBundle {
+  /// Audits every package of this bundle concurrently.
+  ///
+  /// Each package is checked in its own thread, just like during installation,
+  /// so a slow version probe does not hold up the rest of the report.
+  ///
+  /// # Arguments
+  ///
+  /// * `machine` - Machine to audit against
+  pub fn status(&self, machine: &config::machine::Machine) -> BundleStatus {
+    let context = self.context(machine, false);
+    let handles: Vec<_> = self
+      .programs
+      .iter()
+      .map(|program| {
+        let program = program.clone();
+        let context = context.clone();
+        std::thread::spawn(move || program.status(&context))
+      })
+      .collect();
+
+    let packages = handles
+      .into_iter()
+      .zip(&self.programs)
+      .map(|(handle, program)| {
+        handle.join().unwrap_or_else(|_| PackageStatus {
+          name: program.name,
+          state: InstallState::Unknown,
+          installed_version: None,
+          minimum_version: None,
+          detail: Some(i18n::status_panicked().to_string()),
+        })
+      })
+      .collect();
+
+    BundleStatus {
+      bundle: self.name,
+      os: machine.to_string(),
+      packages,
+    }
+  }
+}
@@ -0,0 +1,353 @@
+//! # Instruction Templates
+//!
+//! Instruction strings (commands, URLs, paths, ...) may contain placeholders
+//! in curly braces that are resolved when the instruction runs:
+//!
+//! | Placeholder      | Value                                                   |
+//! |------------------|---------------------------------------------------------|
+//! | `{arch}`         | CPU architecture (`x86_64`, `aarch64`, `armv7`)         |
+//! | `{deb_arch}`     | Debian architecture name (`amd64`, `arm64`, `armhf`)    |
+//! | `{os}`           | Operating system, e.g. `Ubuntu`                         |
+//! | `{os_version}`   | Operating system version, e.g. `22.04`                  |
+//! | `{os_codename}`  | Release codename, e.g. `jammy` (only if detected)       |
+//! | `{home}`         | Home directory of the current user                      |
+//! | `{config_dir}`   | User configuration directory, e.g. `~/.config`          |
+//! | `{temp_dir}`     | Directory for temporary files                           |
+//! | `{user_opt}`     | Per-user directory for portable apps, e.g. `~/.local/opt` |
+//! | `{user_bin}`     | Per-user directory on the PATH, e.g. `~/.local/bin` (not on Windows) |
+//! | `{version}`      | Version pinned by the package mapping, if any           |
+//! | `{install_path}` | Directory for portable installs, if one was chosen      |
+//!
+//! Packages can define further variables per mapping, and users can define
+//! their own in the `[variables]` table of the configuration file. Later
+//! sources win: built-in values, then package variables, then user variables.
+//!
+//! Use `{{` and `}}` for literal braces. Referencing a placeholder that has
+//! no value is an error, so typos do not silently produce broken commands.
+//!
+//! Download and clone URLs are resolved with [`ExecutionContext::resolve_url`],
+//! which also rewrites them with a configured mirror prefix (see
+//! [`NetworkConfig::rewrite`]). Other strings, such as commands or paths,
+//! are never rewritten: scripts that download something should get their
+//! URL from a preceding download step, and tools like nvm find the mirror in
+//! their environment (see [`MIRROR_VARIABLES`](crate::config::network::MIRROR_VARIABLES)).
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! Instruction::new("Download Chrome").download_and_exec(
+//!   "https://dl.google.com/linux/direct/google-chrome-stable_current_{deb_arch}.deb",
+//! )
+//! ```
+
+use crate::config::machine::{Architectures, Machine};
+use crate::config::network::NetworkConfig;
+use crate::i18n;
+use crate::progress;
+use prowo_log::{LogEvent, LogLevel, Logger};
+use regex::{Captures, Regex};
+use std::collections::BTreeMap;
+use std::ffi::OsStr;
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus, Stdio};
+use std::sync::OnceLock;
+use std::thread::JoinHandle;
+
+fn placeholder_pattern() -> &'static Regex {
+  static PATTERN: OnceLock<Regex> = OnceLock::new();
+  PATTERN.get_or_init(|| {
+    Regex::new(r"\{\{|\}\}|\{([A-Za-z_][A-Za-z0-9_]*)\}").expect("placeholder pattern is valid")
+  })
+}
+
+/// Everything an instruction needs to know about the run it is part of.
+///
+/// The context carries the dry-run flag, the variables used to resolve
+/// placeholders in instruction strings and the network settings passed on
+/// to every started program.
+#[derive(Debug, Clone)]
+pub struct ExecutionContext {
+  /// Machine the instructions run on (possibly overridden)
+  machine: Machine,
+  /// Only print what would be done without making changes
+  dry_run: bool,
+  /// Built-in and package-defined values for placeholders, by name
+  variables: BTreeMap<String, String>,
+  /// User-defined values, which take precedence over all others
+  user_variables: BTreeMap<String, String>,
+  /// Proxy, CA and mirror settings
+  network: NetworkConfig,
+  /// Directory for portable installs, if the user chose one
+  install_path: Option<PathBuf>,
+  /// Install for the current user only, without administrator rights
+  user_scope: bool,
+  /// Logger of the package the instructions belong to, for progress reports
+  logger: Option<Logger>,
+}
+
+impl ExecutionContext {
+  /// Creates a context with the built-in variables of `machine`.
+  ///
+  /// # Arguments
+  ///
+  /// * `machine` - Machine the instructions run on
+  /// * `dry_run` - If true, instructions only print what they would do
+  pub fn new(machine: &Machine, dry_run: bool) -> Self {
+    let mut variables = BTreeMap::new();
+    let deb_arch = match machine.arch {
+      Architectures::X86_64 => "amd64",
+      Architectures::AArch64 => "arm64",
+      Architectures::Armv7 => "armhf",
+    };
+
+    variables.insert("arch".to_string(), machine.arch.to_string());
+    variables.insert("deb_arch".to_string(), deb_arch.to_string());
+    variables.insert("os".to_string(), machine.os.to_string());
+    variables.insert("os_version".to_string(), machine.version.to_string());
+    if let Some(codename) = &machine.facts().codename {
+      variables.insert("os_codename".to_string(), codename.clone());
+    }
+    if let Some(home) = dirs::home_dir() {
+      variables.insert("home".to_string(), home.display().to_string());
+    }
+    if let Some(config_dir) = dirs::config_dir() {
+      variables.insert("config_dir".to_string(), config_dir.display().to_string());
+    }
+    variables.insert(
+      "temp_dir".to_string(),
+      std::env::temp_dir().display().to_string(),
+    );
+    if cfg!(windows) {
+      if let Some(local) = dirs::data_local_dir() {
+        variables.insert("user_opt".to_string(), local.join("Programs").display().to_string());
+      }
+    } else if let Some(home) = dirs::home_dir() {
+      let local = home.join(".local");
+      variables.insert("user_opt".to_string(), local.join("opt").display().to_string());
+      variables.insert("user_bin".to_string(), local.join("bin").display().to_string());
+    }
+
+    Self {
+      machine: machine.clone(),
+      dry_run,
+      variables,
+      user_variables: BTreeMap::new(),
+      network: NetworkConfig::default(),
+      install_path: None,
+      user_scope: false,
+      logger: None,
+    }
+  }
+
+  /// Adds or replaces variables defined by a package mapping.
+  pub fn with_variables<'a>(
+    mut self,
+    variables: impl IntoIterator<Item = (&'a String, &'a String)>,
+  ) -> Self {
+    for (name, value) in variables {
+      self.variables.insert(name.clone(), value.clone());
+    }
+    self
+  }
+
+  /// Adds variables from the user configuration.
+  ///
+  /// These win over built-in and package variables, no matter in which
+  /// order they were added.
+  pub fn with_user_variables<'a>(
+    mut self,
+    variables: impl IntoIterator<Item = (&'a String, &'a String)>,
+  ) -> Self {
+    for (name, value) in variables {
+      self.user_variables.insert(name.clone(), value.clone());
+    }
+    self
+  }
+
+  fn lookup(&self, name: &str) -> Option<&String> {
+    self
+      .user_variables
+      .get(name)
+      .or_else(|| self.variables.get(name))
+  }
+
+  /// Sets the proxy, CA and mirror settings of this run.
+  pub fn with_network(mut self, network: &NetworkConfig) -> Self {
+    self.network = network.clone();
+    self
+  }
+
+  /// Sets the directory for portable installs of this run.
+  ///
+  /// The directory is also available as the `{install_path}` placeholder.
+  pub fn with_install_path(mut self, install_path: Option<&Path>) -> Self {
+    if let Some(path) = install_path {
+      self
+        .variables
+        .insert("install_path".to_string(), path.display().to_string());
+    }
+    self.install_path = install_path.map(Path::to_path_buf);
+    self
+  }
+
+  /// Restricts installations to the current user.
+  ///
+  /// Set for user-scope mappings, which are chosen when no administrator
+  /// rights are available.
+  pub fn with_user_scope(mut self, user_scope: bool) -> Self {
+    self.user_scope = user_scope;
+    self
+  }
+
+  /// Reports the progress of long-running instructions, such as downloads,
+  /// through `logger`.
+  pub fn with_logger(mut self, logger: Logger) -> Self {
+    self.logger = Some(logger);
+    self
+  }
+
+  /// Reports that a download has received `received` of `total` bytes.
+  ///
+  /// Does nothing unless a logger was set with [`ExecutionContext::with_logger`].
+  pub fn report_download(&self, received: u64, total: Option<u64>) {
+    if let Some(logger) = &self.logger {
+      let message = match total {
+        Some(total) if total > 0 => i18n::downloaded_percent(received * 100 / total),
+        _ => i18n::downloaded_kb(received / 1024),
+      };
+      logger.event(LogLevel::Info, message, LogEvent::Download { received, total });
+    }
+  }
+
+  /// Machine the instructions run on.
+  pub fn machine(&self) -> &Machine {
+    &self.machine
+  }
+
+  /// Whether instructions should only print what they would do.
+  pub fn dry_run(&self) -> bool {
+    self.dry_run
+  }
+
+  /// Proxy, CA and mirror settings of this run.
+  pub fn network(&self) -> &NetworkConfig {
+    &self.network
+  }
+
+  /// Directory for portable installs, if the user chose one.
+  pub fn install_path(&self) -> Option<&Path> {
+    self.install_path.as_deref()
+  }
+
+  /// Whether installations are restricted to the current user.
+  pub fn user_scope(&self) -> bool {
+    self.user_scope
+  }
+
+  /// Creates a command that inherits the network settings of this run.
+  ///
+  /// Every program an instruction starts should be created through this, so
+  /// downloads by curl, git or npm go through the configured proxy.
+  pub fn command(&self, program: impl AsRef<OsStr>) -> Command {
+    let mut command = Command::new(program);
+    command.envs(self.network.environment());
+    command
+  }
+
+  /// Runs `command` to completion and returns its exit status.
+  ///
+  /// While the live progress display is shown, the program's output is
+  /// captured and logged line by line instead of being written over the
+  /// display; its input is closed. Programs started through `sudo` may ask
+  /// for a password, so the display is suspended for them instead.
+  pub fn status(&self, command: &mut Command) -> std::io::Result<ExitStatus> {
+    if !progress::is_active() {
+      return command.status();
+    }
+    if command.get_program() == "sudo" {
+      let _suspended = progress::suspend();
+      return command.status();
+    }
+
+    let mut child = command
+      .stdin(Stdio::null())
+      .stdout(Stdio::piped())
+      .stderr(Stdio::piped())
+      .spawn()?;
+    let stdout = child.stdout.take().map(|out| self.forward(out));
+    let stderr = child.stderr.take().map(|err| self.forward(err));
+    let status = child.wait();
+    for reader in stdout.into_iter().chain(stderr) {
+      let _ = reader.join();
+    }
+    status
+  }
+
+  /// Logs every line read from `output` on a separate thread.
+  fn forward(&self, output: impl Read + Send + 'static) -> JoinHandle<()> {
+    let logger = self.logger.clone();
+    std::thread::spawn(move || {
+      for line in BufReader::new(output).lines().map_while(Result::ok) {
+        if let Some(logger) = &logger
+          && !line.trim().is_empty()
+        {
+          logger.info(line);
+        }
+      }
+    })
+  }
+
+  /// Replaces all placeholders in `template` with their values.
+  ///
+  /// # Errors
+  ///
+  /// Fails if a placeholder has no value, naming the placeholder and the
+  /// variables that are available.
+  pub fn resolve(&self, template: &str) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+    let mut unknown = Vec::new();
+    let resolved = placeholder_pattern().replace_all(template, |caps: &Captures| {
+      match caps.get(1) {
+        Some(name) => match self.lookup(name.as_str()) {
+          Some(value) => value.clone(),
+          None => {
+            unknown.push(name.as_str().to_string());
+            String::new()
+          }
+        },
+        None => caps[0][..1].to_string(),
+      }
+    });
+
+    if let Some(name) = unknown.first() {
+      let mut available: Vec<&str> = self
+        .variables
+        .keys()
+        .chain(self.user_variables.keys())
+        .map(|k| k.as_str())
+        .collect();
+      available.sort_unstable();
+      available.dedup();
+      return Err(i18n::unknown_placeholder(name, template, available.join(", ")).into());
+    }
+
+    Ok(resolved.into_owned())
+  }
+
+  /// Resolves a download or clone URL and applies the configured mirrors.
+  ///
+  /// # Errors
+  ///
+  /// Fails like [`resolve`](Self::resolve) if a placeholder has no value.
+  pub fn resolve_url(&self, template: &str) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+    Ok(self.network.rewrite(&self.resolve(template)?))
+  }
+
+  /// Resolves every element of a split command line.
+  pub fn resolve_all(
+    &self,
+    parts: &[String],
+  ) -> Result<Vec<String>, Box<dyn std::error::Error + Send + Sync>> {
+    parts.iter().map(|part| self.resolve(part)).collect()
+  }
+}
@@ -0,0 +1,83 @@
+//! # Version Parsing
+//!
+//! Lightweight version handling for comparing the output of version probes
+//! such as `node --version` or `git --version` against minimum requirements.
+//!
+//! Versions are extracted leniently: the first dotted number sequence in a
+//! string is used, so `v20.11.1`, `git version 2.43.0` and `1.2` all parse.
+//! Missing components compare as zero (`1.2` equals `1.2.0`).
+
+use regex::Regex;
+use std::cmp::Ordering;
+use std::fmt;
+use std::sync::OnceLock;
+
+/// A dotted numeric version such as `20.11.1`.
+#[derive(Debug, Clone)]
+pub struct Version {
+  /// Numeric components in order of significance
+  parts: Vec<u64>,
+}
+
+fn version_pattern() -> &'static Regex {
+  static PATTERN: OnceLock<Regex> = OnceLock::new();
+  PATTERN.get_or_init(|| Regex::new(r"\d+(?:\.\d+)*").expect("version pattern is valid"))
+}
+
+impl Version {
+  /// Extracts the first version number found in arbitrary text.
+  ///
+  /// # Arguments
+  ///
+  /// * `text` - Text to search, typically the output of a `--version` command
+  ///
+  /// # Returns
+  ///
+  /// Returns `None` if the text contains no digits.
+  pub fn extract(text: &str) -> Option<Self> {
+    let found = version_pattern().find(text)?;
+    let parts = found
+      .as_str()
+      .split('.')
+      .map(|part| part.parse::<u64>())
+      .collect::<Result<Vec<_>, _>>()
+      .ok()?;
+    Some(Self { parts })
+  }
+}
+
+impl fmt::Display for Version {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let parts: Vec<String> = self.parts.iter().map(|p| p.to_string()).collect();
+    write!(f, "{}", parts.join("."))
+  }
+}
+
+impl Ord for Version {
+  fn cmp(&self, other: &Self) -> Ordering {
+    let len = self.parts.len().max(other.parts.len());
+    for i in 0..len {
+      let left = self.parts.get(i).copied().unwrap_or(0);
+      let right = other.parts.get(i).copied().unwrap_or(0);
+      match left.cmp(&right) {
+        Ordering::Equal => continue,
+        ordering => return ordering,
+      }
+    }
+    Ordering::Equal
+  }
+}
+
+impl PartialOrd for Version {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl PartialEq for Version {
+  fn eq(&self, other: &Self) -> bool {
+    self.cmp(other) == Ordering::Equal
+  }
+}
+
+impl Eq for Version {}
@@ -6,6 +6,7 @@
 //! ## Available Packages
 //!
 //! - **Google Chrome**: Popular web browser with developer tools
+//! - **Mozilla Firefox**: Open-source web browser with developer tools
 //! - **Git**: Distributed version control system
 //!
 //! ## Installation Strategy
@@ -16,7 +17,8 @@
 //!
 //! Additional platform support can be added by extending the OS mappings.
 
-use crate::config::machine::{OsCategory, OsMatcher};
+use crate::config::machine::{Architectures, OsCategory, OsMatcher};
+use crate::manager::checks::Check;
 use crate::manager::instructions::Instruction;
 use crate::manager::{InstructionMapping, Package};
 
@@ -33,14 +35,17 @@ use crate::manager::{InstructionMapping, Package};
 /// # Platform Support
 ///
 /// - **Windows**: Uses package managers (winget/chocolatey) for installation
-/// - **Debian-based Linux**: Downloads and installs .deb package directly
-/// - **RHEL-based Linux**: Downloads and installs .rpm package directly
+/// - **Debian-based Linux (x86_64)**: Downloads and installs .deb package directly
+/// - **RHEL-based Linux (x86_64)**: Downloads and installs .rpm package directly
+/// - **Linux on ARM**: Google does not ship Chrome for ARM Linux, so Chromium
+///   is installed from the system package manager instead
 ///
 /// # Installation Methods
 ///
 /// - **Windows**: Uses [`install_application`] with Google.Chrome package ID
 /// - **Debian**: Uses [`download_and_exec`] for .deb package installation
 /// - **RHEL**: Uses [`download_and_exec`] for .rpm package installation
+/// - **ARM Linux**: Uses [`install_application`] with the chromium package name
 ///
 /// # Returns
 ///
@@ -55,7 +60,7 @@ pub fn chrome() -> Package {
     .add_mapping(
       OsMatcher::from_category(OsCategory::Windows),
       InstructionMapping::new()
-        .add_prerequisite_checks(vec![
+        .add_installed_checks(vec![
           Instruction::new("Check if Chrome is installed")
             .assert("chrome --version", "Google Chrome"),
         ])
@@ -64,26 +69,116 @@ pub fn chrome() -> Package {
         ]),
     )
     .add_mapping(
-      OsMatcher::from_category(OsCategory::DebianBased),
+      OsMatcher::from_category(OsCategory::DebianBased).for_arch(&[Architectures::X86_64]),
       InstructionMapping::new()
-        .add_prerequisite_checks(vec![
+        .add_installed_checks(vec![
           Instruction::new("Check if Chrome is installed")
             .assert("google-chrome --version", "Google Chrome"),
         ])
+        .add_prerequisites(vec![Check::command("curl"), Check::admin()])
         .add_install_instructions(vec![Instruction::new("Download Chrome").download_and_exec(
-          "https://dl.google.com/linux/direct/google-chrome-stable_current_amd64.deb",
-        )]),
+          "https://dl.google.com/linux/direct/google-chrome-stable_current_{deb_arch}.deb",
+        )])
+        .add_upgrade_instructions(vec![
+          Instruction::new("Upgrade Chrome").upgrade_application("google-chrome-stable"),
+        ]),
     )
     .add_mapping(
-      OsMatcher::from_category(OsCategory::RHELBased),
+      OsMatcher::from_category(OsCategory::RHELBased).for_arch(&[Architectures::X86_64]),
       InstructionMapping::new()
-        .add_prerequisite_checks(vec![
+        .add_installed_checks(vec![
           Instruction::new("Check if Chrome is installed")
             .assert("google-chrome --version", "Google Chrome"),
         ])
+        .add_prerequisites(vec![Check::command("curl"), Check::admin()])
         .add_install_instructions(vec![Instruction::new("Download Chrome").download_and_exec(
-          "https://dl.google.com/linux/direct/google-chrome-stable_current_x86_64.rpm",
-        )]),
+          "https://dl.google.com/linux/direct/google-chrome-stable_current_{arch}.rpm",
+        )])
+        .add_upgrade_instructions(vec![
+          Instruction::new("Upgrade Chrome").upgrade_application("google-chrome-stable"),
+        ]),
+    )
+    .add_mapping(
+      OsMatcher::from_category(OsCategory::LinuxBased)
+        .for_arch(&[Architectures::AArch64, Architectures::Armv7]),
+      InstructionMapping::new()
+        .add_installed_checks(vec![
+          Instruction::new("Check if Chromium is installed")
+            .assert("chromium --version", "Chromium"),
+          Instruction::new("Check if Chromium is installed")
+            .assert("chromium-browser --version", "Chromium"),
+        ])
+        .add_prerequisites(vec![Check::admin()])
+        .add_install_instructions(vec![
+          Instruction::new("Install Chromium").install_application("chromium"),
+        ]),
+    )
+}
+
+/// Creates a Mozilla Firefox package with cross-platform installation instructions.
+///
+/// Firefox is an open-source web browser developed by Mozilla. It is installed
+/// instead of Chrome when Firefox was chosen in the configuration wizard.
+///
+/// # Platform Support
+///
+/// - **Windows**: Uses [`install_application`] with the Mozilla.Firefox package ID
+/// - **Ubuntu-based Linux**: Installs the firefox package
+/// - **Debian**: Installs the firefox-esr package, as Debian ships the
+///   extended support release
+/// - **Other Linux**: Installs the firefox package
+///
+/// # Returns
+///
+/// Returns a configured [`Package`] with platform-specific installation instructions.
+pub fn firefox() -> Package {
+  Package::new("Mozilla Firefox", "Web browser")
+    .add_mapping(
+      OsMatcher::from_category(OsCategory::Windows),
+      InstructionMapping::new()
+        .add_installed_checks(vec![
+          Instruction::new("Check if Firefox is installed")
+            .assert("firefox --version", "Mozilla Firefox"),
+        ])
+        .add_install_instructions(vec![
+          Instruction::new("Install Firefox").install_application("Mozilla.Firefox"),
+        ]),
+    )
+    .add_mapping(
+      OsMatcher::from_category(OsCategory::UbuntuBased),
+      InstructionMapping::new()
+        .add_installed_checks(vec![
+          Instruction::new("Check if Firefox is installed")
+            .assert("firefox --version", "Mozilla Firefox"),
+        ])
+        .add_prerequisites(vec![Check::admin()])
+        .add_install_instructions(vec![
+          Instruction::new("Install Firefox").install_application("firefox"),
+        ]),
+    )
+    .add_mapping(
+      OsMatcher::from_category(OsCategory::DebianBased),
+      InstructionMapping::new()
+        .add_installed_checks(vec![
+          Instruction::new("Check if Firefox is installed")
+            .assert("firefox --version", "Mozilla Firefox"),
+        ])
+        .add_prerequisites(vec![Check::admin()])
+        .add_install_instructions(vec![
+          Instruction::new("Install Firefox").install_application("firefox-esr"),
+        ]),
+    )
+    .add_mapping(
+      OsMatcher::from_category(OsCategory::LinuxBased),
+      InstructionMapping::new()
+        .add_installed_checks(vec![
+          Instruction::new("Check if Firefox is installed")
+            .assert("firefox --version", "Mozilla Firefox"),
+        ])
+        .add_prerequisites(vec![Check::admin()])
+        .add_install_instructions(vec![
+          Instruction::new("Install Firefox").install_application("firefox"),
+        ]),
     )
 }
 
@@ -120,7 +215,7 @@ pub fn git() -> Package {
     .add_mapping(
       OsMatcher::from_category(OsCategory::Windows),
       InstructionMapping::new()
-        .add_prerequisite_checks(vec![
+        .add_installed_checks(vec![
           Instruction::new("Check if Git is installed").assert("git --version", "git version"),
         ])
         .add_install_instructions(vec![
@@ -130,9 +225,10 @@ pub fn git() -> Package {
     .add_mapping(
       OsMatcher::from_category(OsCategory::LinuxBased),
       InstructionMapping::new()
-        .add_prerequisite_checks(vec![
+        .add_installed_checks(vec![
           Instruction::new("Check if Git is installed").assert("git --version", "git version"),
         ])
+        .add_prerequisites(vec![Check::admin()])
         .add_install_instructions(vec![
           Instruction::new("Install Git").install_application("git"),
         ]),
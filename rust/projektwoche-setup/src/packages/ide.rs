@@ -12,10 +12,13 @@
 //! Different installation methods are used based on the operating system:
 //! - **Windows**: Direct download and execution of installer packages
 //! - **Linux (RHEL-based)**: Package manager installation using system repositories
+//! - **Without admin rights**: The per-user setup on Windows and the portable
+//!   archive in `~/.local/opt` on Linux
 //!
 //! Additional platform support can be added by extending the OS mappings.
 
-use crate::config::machine::{OsCategory, OsMatcher};
+use crate::config::machine::{Architectures, Fact, OsCategory, OsMatcher};
+use crate::manager::checks::Check;
 use crate::manager::instructions::Instruction;
 use crate::manager::{InstructionMapping, Package};
 
@@ -34,6 +37,12 @@ use crate::manager::{InstructionMapping, Package};
 ///
 /// - **Windows**: Downloads and executes the official Windows installer
 /// - **RHEL-based Linux**: Installs via system package manager (yum/dnf)
+/// - **Windows without admin rights**: Installs the per-user setup via winget
+/// - **Linux without admin rights (x86_64, aarch64)**: Extracts the portable
+///   archive into `~/.local/opt` and links `code` into `~/.local/bin`
+///
+/// Inside WSL, the system installation is refused: VS Code belongs on the
+/// Windows side, where its WSL extension connects to the Linux environment.
 ///
 /// # Installation Methods
 ///
@@ -53,7 +62,7 @@ pub fn vscode() -> Package {
   .add_mapping(
     OsMatcher::from_category(OsCategory::Windows),
     InstructionMapping::new()
-      .add_prerequisite_checks(vec![
+      .add_installed_checks(vec![
         Instruction::new("Check if VSCode is installed").assert("code --version", "."),
       ])
        .add_install_instructions(vec![
@@ -63,8 +72,13 @@ pub fn vscode() -> Package {
   .add_mapping(
     OsMatcher::from_category(OsCategory::RHELBased),
     InstructionMapping::new()
-      .add_prerequisite_checks(vec![
+      .add_installed_checks(vec![
         Instruction::new("Check if VSCode is installed").assert("code --version", "."),
+      ])
+      .add_prerequisites(vec![
+        Check::not(Check::fact(Fact::Wsl)),
+        Check::admin(),
+        Check::free_disk_mb(1024),
       ])
        .add_install_instructions(vec![
          Instruction::new("Install VSCode").install_application("code"),
@@ -73,15 +87,65 @@ pub fn vscode() -> Package {
   .add_mapping(
     OsMatcher::from_category(OsCategory::DebianBased),
     InstructionMapping::new()
-      .add_prerequisite_checks(vec![
+      .add_installed_checks(vec![
         Instruction::new("Check if VSCode is installed").assert("code --version", "."),
       ])
+      .add_prerequisites(vec![
+        Check::not(Check::fact(Fact::Wsl)),
+        Check::command("wget"),
+        Check::command("gpg"),
+        Check::admin(),
+        Check::free_disk_mb(1024),
+      ])
       .add_install_instructions(vec![
         Instruction::new("Install dependencies and GPG key").cmd("wget -qO- https://packages.microsoft.com/keys/microsoft.asc | gpg --dearmor > packages.microsoft.gpg"),
         Instruction::new("Install GPG key").cmd("sudo install -o root -g root -m 644 packages.microsoft.gpg /etc/apt/trusted.gpg.d/"),
         Instruction::new("Add Microsoft repository").cmd("sudo sh -c 'echo \"deb [arch=amd64,arm64,armhf signed-by=/etc/apt/trusted.gpg.d/packages.microsoft.gpg] https://packages.microsoft.com/repos/code stable main\" > /etc/apt/sources.list.d/vscode.list'"),
-        Instruction::new("Update package list").cmd("sudo apt update"),
+        Instruction::new("Update package list").update_package_lists(),
         Instruction::new("Install VSCode").install_application("code"),
       ]),
   )
+  .add_user_mapping(
+    OsMatcher::from_category(OsCategory::Windows),
+    InstructionMapping::new()
+      .add_installed_checks(vec![
+        Instruction::new("Check if VSCode is installed").assert("code --version", "."),
+      ])
+      .add_install_instructions(vec![
+        Instruction::new("Install VSCode for the current user")
+          .install_application("Microsoft.VisualStudioCode"),
+      ]),
+  )
+  .add_user_mapping(
+    OsMatcher::from_category(OsCategory::LinuxBased).for_arch(&[Architectures::X86_64]),
+    portable_vscode("x64"),
+  )
+  .add_user_mapping(
+    OsMatcher::from_category(OsCategory::LinuxBased).for_arch(&[Architectures::AArch64]),
+    portable_vscode("arm64"),
+  )
+}
+
+/// Builds the mapping that installs the portable VS Code archive for
+/// `vscode_arch` (`x64`, `arm64`) into the user's home.
+fn portable_vscode(vscode_arch: &str) -> InstructionMapping {
+  InstructionMapping::new()
+    .add_variable("vscode_arch", vscode_arch)
+    .add_installed_checks(vec![
+      Instruction::new("Check if VSCode is installed").assert("code --version", "."),
+      Instruction::new("Check if portable VSCode is installed")
+        .assert("{user_opt}/VSCode-linux-{vscode_arch}/bin/code --version", "."),
+    ])
+    .add_prerequisites(vec![Check::command("curl"), Check::free_disk_mb(1024)])
+    .add_install_instructions(vec![
+      Instruction::new("Download VSCode").download_to(
+        "https://update.code.visualstudio.com/latest/linux-{vscode_arch}/stable",
+        "{temp_dir}/vscode-linux-{vscode_arch}.tar.gz",
+      ),
+      Instruction::new("Extract VSCode")
+        .extract_archive("{temp_dir}/vscode-linux-{vscode_arch}.tar.gz", "{user_opt}"),
+      Instruction::new("Create bin directory").cmd("mkdir -p {user_bin}"),
+      Instruction::new("Link VSCode")
+        .cmd("ln -sf {user_opt}/VSCode-linux-{vscode_arch}/bin/code {user_bin}/code"),
+    ])
 }
@@ -15,9 +15,12 @@
 //! - **Bun**: Installed directly using official installation scripts
 //!
 //! Both packages support Windows and Linux platforms with appropriate
-//! installation methods for each operating system.
+//! installation methods for each operating system. Without administrator
+//! rights, Node.js is installed through nvm and Bun through its own per-user
+//! installer.
 
 use crate::config::machine::{OsCategory, OsMatcher};
+use crate::manager::checks::Check;
 use crate::manager::instructions::Instruction;
 use crate::manager::{InstructionMapping, Package};
 
@@ -35,6 +38,12 @@ use crate::manager::{InstructionMapping, Package};
 ///
 /// - **Windows**: Uses nvm-windows with PowerShell scripts and environment variables
 /// - **Linux**: Uses standard nvm with bash configuration and shell reloading
+/// - **Linux without admin rights**: Installs the pinned nvm release and the
+///   Node.js LTS release into the home directory
+///
+/// The setup scripts are fetched as downloads, so a mirror configured for
+/// their URLs applies; nvm fetches Node.js itself from the mirror of
+/// `https://nodejs.org/dist`, if one is configured.
 ///
 /// # Returns
 ///
@@ -44,9 +53,10 @@ pub fn nodejs() -> Package {
     .add_mapping(
       OsMatcher::from_category(OsCategory::Windows),
       InstructionMapping::new()
-        .add_prerequisite_checks(vec![
+        .add_installed_checks(vec![
           Instruction::new("Check if Node.js is installed").assert("node --version", "v"),
         ])
+        .add_version_probe("node --version", Some("20.0.0"))
         .add_install_instructions(vec![
           Instruction::new("Install Node.js").install_application("OpenJS.NodeJS"),
         ]),
@@ -54,17 +64,41 @@ pub fn nodejs() -> Package {
     .add_mapping(
       OsMatcher::from_category(OsCategory::LinuxBased),
       InstructionMapping::new()
-        .add_prerequisite_checks(vec![
+        .add_installed_checks(vec![
           Instruction::new("Check if Node.js is installed").assert("node --version", "v"),
         ])
+        .add_version_probe("node --version", Some("20.0.0"))
+        .add_prerequisites(vec![Check::admin()])
         .add_install_instructions(vec![
-          Instruction::new("Install curl if needed")
-            .cmd("sudo apt update && sudo apt install -y curl"),
+          Instruction::new("Update package lists").update_package_lists(),
+          Instruction::new("Install curl if needed").install_application("curl"),
+          Instruction::new("Download NodeSource setup script")
+            .download_to("https://deb.nodesource.com/setup_lts.x", "{temp_dir}/nodesource_setup.sh"),
           Instruction::new("Setup NodeSource repository")
-            .cmd("curl -fsSL https://deb.nodesource.com/setup_lts.x | sudo -E bash -"),
+            .cmd("sudo -E bash {temp_dir}/nodesource_setup.sh"),
           Instruction::new("Install Node.js").install_application("nodejs"),
         ]),
     )
+    .add_user_mapping(
+      OsMatcher::from_category(OsCategory::LinuxBased),
+      InstructionMapping::new()
+        .pin_version("0.40.1")
+        .add_installed_checks(vec![
+          Instruction::new("Check if Node.js is installed").assert("node --version", "v"),
+          Instruction::new("Check if Node.js is installed with nvm")
+            .assert("ls {home}/.nvm/versions/node", "v"),
+        ])
+        .add_prerequisites(vec![Check::command("curl")])
+        .add_install_instructions(vec![
+          Instruction::new("Download nvm installer").download_to(
+            "https://raw.githubusercontent.com/nvm-sh/nvm/v{version}/install.sh",
+            "{temp_dir}/nvm-install.sh",
+          ),
+          Instruction::new("Install nvm").script("bash {temp_dir}/nvm-install.sh"),
+          Instruction::new("Install Node.js LTS")
+            .script(". {home}/.nvm/nvm.sh && nvm install --lts"),
+        ]),
+    )
 }
 
 /// Creates a Bun package with cross-platform installation instructions.
@@ -83,7 +117,10 @@ pub fn nodejs() -> Package {
 /// # Platform Support
 ///
 /// - **Windows**: Uses PowerShell installation script from bun.sh
-/// - **Linux**: Uses bash installation script with curl
+/// - **Linux**: Uses bash installation script with curl (installs into the
+///   home directory, so no administrator rights are needed)
+/// - **Windows without admin rights**: Uses the PowerShell installation script
+///   from bun.sh, which installs for the current user
 ///
 /// # Returns
 ///
@@ -93,22 +130,33 @@ pub fn bun() -> Package {
     .add_mapping(
       OsMatcher::from_category(OsCategory::Windows),
       InstructionMapping::new()
-        .add_prerequisite_checks(vec![
+        .add_installed_checks(vec![
           Instruction::new("Check if Bun is installed").assert("bun --version", "."),
         ])
         .add_install_instructions(vec![
           Instruction::new("Install Bun").install_application("Oven-sh.Bun"),
         ]),
     )
+    .add_user_mapping(
+      OsMatcher::from_category(OsCategory::Windows),
+      InstructionMapping::new()
+        .add_installed_checks(vec![
+          Instruction::new("Check if Bun is installed").assert("bun --version", "."),
+        ])
+        .add_install_instructions(vec![
+          Instruction::new("Install Bun").script("irm bun.sh/install.ps1 | iex"),
+        ]),
+    )
     .add_mapping(
       OsMatcher::from_category(OsCategory::LinuxBased),
       InstructionMapping::new()
-        .add_prerequisite_checks(vec![
+        .add_installed_checks(vec![
           Instruction::new("Check if Bun is installed").assert("bun --version", "."),
         ])
+        .add_prerequisites(vec![Check::command("curl"), Check::command("unzip")])
         .add_install_instructions(vec![
-          Instruction::new("Install Bun").cmd("curl -fsSL https://bun.sh/install | bash"),
-          Instruction::new("Source Bun environment").cmd("bash -c 'source ~/.bashrc'"),
-        ]),
+          Instruction::new("Install Bun").script("curl -fsSL https://bun.sh/install | bash"),
+        ])
+        .add_upgrade_instructions(vec![Instruction::new("Upgrade Bun").cmd("bun upgrade")]),
     )
 }
@@ -1,3 +1,4 @@
 pub mod apps;
 pub mod ide;
 pub mod js;
+pub mod python;
@@ -0,0 +1,88 @@
+//! # Python Packages
+//!
+//! This module defines packages for Python development. They are added to a
+//! bundle when Python was chosen in the configuration wizard.
+//!
+//! ## Available Packages
+//!
+//! - **Python**: Python 3 interpreter with pip and venv
+//!
+//! ## Installation Strategy
+//!
+//! - **Windows**: Package manager installation using winget/chocolatey
+//! - **Linux**: System package manager installation, including pip and venv,
+//!   which several distributions ship as separate packages
+
+use crate::config::machine::{OsCategory, OsMatcher};
+use crate::manager::checks::Check;
+use crate::manager::instructions::Instruction;
+use crate::manager::{InstructionMapping, Package};
+
+/// Creates a Python package with cross-platform installation instructions.
+///
+/// Python is a beginner-friendly programming language used for scripting,
+/// data analysis and web backends. The installation includes:
+///
+/// - **Interpreter**: The Python 3 interpreter
+/// - **pip**: The package installer for Python
+/// - **venv**: Virtual environments to keep project dependencies apart
+///
+/// # Platform Support
+///
+/// - **Windows**: Uses [`install_application`] with the Python.Python.3.12 package ID
+/// - **Debian-based Linux**: Installs python3, python3-pip and python3-venv
+/// - **RHEL-based Linux**: Installs python3 and python3-pip (venv is included)
+/// - **Other Linux**: Installs python3 from the system package manager
+///
+/// # Returns
+///
+/// Returns a configured [`Package`] with platform-specific installation instructions.
+pub fn python() -> Package {
+  Package::new("Python", "Python 3 interpreter with pip")
+    .add_mapping(
+      OsMatcher::from_category(OsCategory::Windows),
+      InstructionMapping::new()
+        .add_installed_checks(vec![
+          Instruction::new("Check if Python is installed").assert("python --version", "Python 3"),
+        ])
+        .add_install_instructions(vec![
+          Instruction::new("Install Python").install_application("Python.Python.3.12"),
+        ]),
+    )
+    .add_mapping(
+      OsMatcher::from_category(OsCategory::DebianBased),
+      InstructionMapping::new()
+        .add_installed_checks(vec![
+          Instruction::new("Check if Python is installed").assert("python3 --version", "Python 3"),
+        ])
+        .add_prerequisites(vec![Check::admin()])
+        .add_install_instructions(vec![
+          Instruction::new("Install Python").install_application("python3"),
+          Instruction::new("Install pip").install_application("python3-pip"),
+          Instruction::new("Install venv").install_application("python3-venv"),
+        ]),
+    )
+    .add_mapping(
+      OsMatcher::from_category(OsCategory::RHELBased),
+      InstructionMapping::new()
+        .add_installed_checks(vec![
+          Instruction::new("Check if Python is installed").assert("python3 --version", "Python 3"),
+        ])
+        .add_prerequisites(vec![Check::admin()])
+        .add_install_instructions(vec![
+          Instruction::new("Install Python").install_application("python3"),
+          Instruction::new("Install pip").install_application("python3-pip"),
+        ]),
+    )
+    .add_mapping(
+      OsMatcher::from_category(OsCategory::LinuxBased),
+      InstructionMapping::new()
+        .add_installed_checks(vec![
+          Instruction::new("Check if Python is installed").assert("python3 --version", "Python 3"),
+        ])
+        .add_prerequisites(vec![Check::admin()])
+        .add_install_instructions(vec![
+          Instruction::new("Install Python").install_application("python3"),
+        ]),
+    )
+}
@@ -0,0 +1,402 @@
+//! # Live Progress Display
+//!
+//! The packages of a bundle are installed concurrently, so their log lines
+//! interleave into a wall of text. On a terminal, [`ProgressOutput`] shows one
+//! live line per package instead, fed by the [`LogEvent`]s of the package
+//! loggers:
+//!
+//! ```text
+//! ⠹ Node.js            install    2/3 Download node-v20.tar.xz  45%     12.3s
+//! ✔ Git                install    installed                              3.1s
+//! ```
+//!
+//! Warnings, errors and messages that belong to no package are printed above
+//! the live lines. Once every package of the block is done, its lines stay on
+//! the screen as a summary and the next phase starts a new block.
+//!
+//! Programs started by instructions would write over the live lines, so while
+//! the display is shown their output is captured (see
+//! [`ExecutionContext::status`](crate::manager::template::ExecutionContext::status)).
+//! Steps that need the terminal, such as the `sudo` password prompt, take it
+//! over with [`suspend`].
+//!
+//! The display rewrites its lines with ANSI cursor movement, so it is only
+//! used where [`live_display_supported`](prowo_log::live_display_supported)
+//! says so; piped output and CI logs get plain lines from
+//! [`ConsoleOutput`].
+
+use crate::i18n;
+use prowo_log::{ConsoleOutput, LogEvent, LogLevel, LogMessage, LogOutput};
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex, MutexGuard, Weak};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const SPINNER: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+
+/// How often the spinner and the elapsed times are redrawn
+const TICK: Duration = Duration::from_millis(100);
+
+/// Width used when the terminal does not report its size
+const DEFAULT_WIDTH: usize = 100;
+
+enum Outcome {
+  Ok(String),
+  Skipped(String),
+  Failed(String),
+}
+
+/// The live line of one package
+struct PackageLine {
+  package: &'static str,
+  operation: &'static str,
+  started: Instant,
+  /// Current step, total steps and what the step does
+  step: Option<(usize, usize, String)>,
+  /// Bytes received and expected of a running download
+  download: Option<(u64, Option<u64>)>,
+  /// Last informational message of the package
+  status: Option<String>,
+  finished: Option<(Outcome, Duration)>,
+}
+
+impl PackageLine {
+  fn new(package: &'static str, operation: &'static str) -> Self {
+    Self {
+      package,
+      operation,
+      started: Instant::now(),
+      step: None,
+      download: None,
+      status: None,
+      finished: None,
+    }
+  }
+
+  fn finish(&mut self, outcome: Outcome) {
+    self.finished = Some((outcome, self.started.elapsed()));
+  }
+
+  fn render(&self, tick: usize, use_colors: bool, width: usize) -> String {
+    let paint = |code: &str, text: String| {
+      if use_colors {
+        format!("\x1b[{}m{}\x1b[0m", code, text)
+      } else {
+        text
+      }
+    };
+
+    let (symbol, text, elapsed) = match &self.finished {
+      Some((Outcome::Ok(detail), elapsed)) => (paint("32", "✔".into()), detail.clone(), *elapsed),
+      Some((Outcome::Skipped(detail), elapsed)) => {
+        (paint("90", "–".into()), i18n::progress_skipped(detail), *elapsed)
+      }
+      Some((Outcome::Failed(error), elapsed)) => {
+        (paint("31", "✖".into()), i18n::progress_failed(error), *elapsed)
+      }
+      None => {
+        let mut text = match (&self.step, &self.status) {
+          (Some((step, total, description)), _) => format!("{}/{} {}", step, total, description),
+          (None, Some(status)) => status.clone(),
+          (None, None) => i18n::progress_starting().to_string(),
+        };
+        match self.download {
+          Some((received, Some(total))) if total > 0 => {
+            text.push_str(&format!("  {:>3}%", (received * 100 / total).min(100)));
+          }
+          Some((received, _)) => text.push_str(&format!("  {} KB", received / 1024)),
+          None => {}
+        }
+        (
+          paint("36", SPINNER[tick % SPINNER.len()].to_string()),
+          text,
+          self.started.elapsed(),
+        )
+      }
+    };
+
+    // Symbol, name, operation and elapsed time take 43 columns; the text gets
+    // the rest so the line never wraps
+    let elapsed = format!("{:>7.1}s", elapsed.as_secs_f64());
+    let room = width.saturating_sub(44).max(10);
+    format!(
+      "{} {:<18} {:<10} {:<room$} {}",
+      symbol,
+      truncate(self.package, 18),
+      self.operation,
+      truncate(&text, room),
+      elapsed,
+      room = room
+    )
+  }
+}
+
+/// Cuts `text` to at most `width` characters, ending in `…` when cut.
+fn truncate(text: &str, width: usize) -> String {
+  let text = text.lines().next().unwrap_or_default();
+  if text.chars().count() <= width {
+    text.to_string()
+  } else {
+    let mut cut: String = text.chars().take(width.saturating_sub(1)).collect();
+    cut.push('…');
+    cut
+  }
+}
+
+fn terminal_width() -> usize {
+  terminal_size::terminal_size()
+    .map(|(width, _)| width.0 as usize)
+    .unwrap_or(DEFAULT_WIDTH)
+}
+
+struct Display {
+  lines: Vec<PackageLine>,
+  /// Number of lines drawn below the cursor's row at the last redraw
+  drawn: usize,
+  tick: usize,
+  use_colors: bool,
+  /// Number of [`Suspension`]s alive; the lines are hidden while it is not 0
+  suspended: usize,
+}
+
+/// The display of the running [`ProgressOutput`], if there is one
+static ACTIVE: Mutex<Weak<Mutex<Display>>> = Mutex::new(Weak::new());
+
+fn active() -> Option<Arc<Mutex<Display>>> {
+  ACTIVE.lock().ok()?.upgrade()
+}
+
+/// Returns `true` while a live progress display is shown on the terminal.
+pub fn is_active() -> bool {
+  active().is_some()
+}
+
+/// Hides the live lines until the returned guard is dropped.
+///
+/// Used around steps that write to or read from the terminal themselves, like
+/// the `sudo` password prompt. Messages logged in the meantime are printed as
+/// plain lines. Does nothing when no display is shown.
+pub fn suspend() -> Suspension {
+  let display = active();
+  if let Some(mut display) = display.as_deref().and_then(|display| display.lock().ok()) {
+    if display.suspended == 0 {
+      let mut out = String::new();
+      display.clear(&mut out);
+      display.write(&out);
+    }
+    display.suspended += 1;
+  }
+  Suspension { display }
+}
+
+/// Guard returned by [`suspend`]; shows the live lines again when dropped.
+pub struct Suspension {
+  display: Option<Arc<Mutex<Display>>>,
+}
+
+impl Drop for Suspension {
+  fn drop(&mut self) {
+    if let Some(mut display) = self.display.as_deref().and_then(|display| display.lock().ok()) {
+      display.suspended -= 1;
+      display.redraw(None);
+    }
+  }
+}
+
+impl Display {
+  fn line(&mut self, package: &str) -> Option<&mut PackageLine> {
+    self.lines.iter_mut().find(|line| line.package == package)
+  }
+
+  /// Moves the cursor back to the first live line and clears the block.
+  fn clear(&mut self, out: &mut String) {
+    if self.drawn > 0 {
+      out.push_str(&format!("\x1b[{}A", self.drawn));
+    }
+    out.push_str("\r\x1b[J");
+    self.drawn = 0;
+  }
+
+  fn draw(&mut self, out: &mut String) {
+    let width = terminal_width();
+    for line in &self.lines {
+      out.push_str(&line.render(self.tick, self.use_colors, width));
+      out.push('\n');
+    }
+    self.drawn = self.lines.len();
+
+    // Once every package is done, the block stays as it is and the next
+    // `Started` begins a new one below it
+    if !self.lines.is_empty() && self.lines.iter().all(|line| line.finished.is_some()) {
+      self.lines.clear();
+      self.drawn = 0;
+    }
+  }
+
+  fn redraw(&mut self, above: Option<&str>) {
+    let mut out = String::new();
+    if self.suspended == 0 {
+      self.clear(&mut out);
+    }
+    if let Some(text) = above {
+      out.push_str(text);
+      out.push('\n');
+    }
+    if self.suspended == 0 {
+      self.draw(&mut out);
+    }
+    self.write(&out);
+  }
+
+  fn write(&self, out: &str) {
+    let mut stdout = io::stdout().lock();
+    let _ = stdout.write_all(out.as_bytes());
+    let _ = stdout.flush();
+  }
+
+  /// Applies a package event; returns `false` if the event names no package
+  /// of the block.
+  fn apply(&mut self, event: &LogEvent, context_package: Option<&str>) -> bool {
+    match event {
+      LogEvent::Started { package, operation } => {
+        self.lines.retain(|line| line.package != *package);
+        self.lines.push(PackageLine::new(package, operation));
+      }
+      LogEvent::StepStarted {
+        package,
+        step,
+        total,
+        description,
+        ..
+      } => {
+        let Some(line) = self.line(package) else {
+          return false;
+        };
+        line.step = Some((*step, *total, description.clone()));
+        line.download = None;
+      }
+      LogEvent::Step { package, .. } => return self.line(package).is_some(),
+      LogEvent::Finished {
+        package,
+        outcome,
+        detail,
+        ..
+      } => {
+        let Some(line) = self.line(package) else {
+          return false;
+        };
+        line.finish(if *outcome == "skipped" {
+          Outcome::Skipped(detail.clone())
+        } else {
+          Outcome::Ok(detail.clone())
+        });
+      }
+      LogEvent::Failed { package, error, .. } => {
+        let Some(line) = self.line(package) else {
+          return false;
+        };
+        line.finish(Outcome::Failed(error.clone()));
+      }
+      LogEvent::Download { received, total } => {
+        let Some(line) = context_package.and_then(|package| self.line(package)) else {
+          return false;
+        };
+        line.download = Some((*received, *total));
+      }
+    }
+    true
+  }
+}
+
+/// Log output that shows a live line per package on a terminal.
+///
+/// Messages that are not shown on a package's line are formatted like
+/// [`ConsoleOutput`] does. A background thread redraws the lines for the
+/// spinner and the elapsed times; it ends with the output. If that thread
+/// cannot be started, every message is printed as a plain line instead.
+pub struct ProgressOutput {
+  display: Option<Arc<Mutex<Display>>>,
+  plain: ConsoleOutput,
+}
+
+impl ProgressOutput {
+  /// Creates the display; `plain` formats the messages printed above it.
+  pub fn new(plain: ConsoleOutput, use_colors: bool) -> Self {
+    let display = Arc::new(Mutex::new(Display {
+      lines: Vec::new(),
+      drawn: 0,
+      tick: 0,
+      use_colors,
+      suspended: 0,
+    }));
+
+    let weak: Weak<Mutex<Display>> = Arc::downgrade(&display);
+    let ticker = thread::Builder::new()
+      .name("progress".to_string())
+      .spawn(move || {
+        while let Some(shared) = weak.upgrade() {
+          if let Ok(mut display) = shared.lock()
+            && !display.lines.is_empty()
+            && display.suspended == 0
+          {
+            display.tick += 1;
+            display.redraw(None);
+          }
+          drop(shared);
+          thread::sleep(TICK);
+        }
+      });
+
+    let display = ticker.is_ok().then_some(display);
+    if let (Some(display), Ok(mut active)) = (&display, ACTIVE.lock()) {
+      *active = Arc::downgrade(display);
+    }
+    Self { display, plain }
+  }
+
+  fn display(&self) -> Option<MutexGuard<'_, Display>> {
+    self.display.as_ref()?.lock().ok()
+  }
+}
+
+impl LogOutput for ProgressOutput {
+  fn write(&self, message: &LogMessage) {
+    if self.display.is_none() {
+      self.plain.write(message);
+      return;
+    }
+    let Some(mut display) = self.display() else {
+      return;
+    };
+    let package = message
+      .context
+      .as_ref()
+      .and_then(|context| context.get("package"))
+      .map(String::as_str);
+
+    let shown = match &message.event {
+      Some(event) => display.apply(event, package),
+      None => match package.and_then(|package| display.line(package)) {
+        Some(line) if line.finished.is_none() => {
+          line.status = Some(message.message.clone());
+          true
+        }
+        _ => false,
+      },
+    };
+
+    // Progress events only ever belong on the lines; warnings and errors are
+    // kept in the scrollback even when their package has a line
+    if message.event.as_ref().is_some_and(LogEvent::is_progress)
+      || (shown && message.level < LogLevel::Warning)
+    {
+      display.redraw(None);
+    } else {
+      display.redraw(Some(&self.plain.format_message(message)));
+    }
+  }
+
+  fn flush(&self) {
+    let _ = io::stdout().flush();
+  }
+}
@@ -1,7 +1,19 @@
-mod logger;
-
 use clap::{Parser, Subcommand};
-use logger::LogLevel;
+use prowo_log::{ConsoleOutput, LevelFilter, LogLevel, LoggerSystem};
+use projektwoche_setup::config::interactive::{self, PromptSettings};
+use projektwoche_setup::config::settings::{SettingsCommand, SettingsStore};
+use serde::{Deserialize, Serialize};
+
+/// Application name under which the configuration is stored.
+const APP_NAME: &str = "projektwoche";
+
+/// Settings of the Projektwoche CLI, managed with `projektwoche config`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Config {
+  /// Log level configuration
+  #[serde(default)]
+  log_level: LogLevel,
+}
 
 #[derive(Parser, Debug)]
 #[clap(
@@ -13,6 +25,10 @@ use logger::LogLevel;
 struct Cli {
   #[clap(subcommand)]
   command: Commands,
+
+  /// Answer every confirmation with yes
+  #[clap(short, long, global = true)]
+  yes: bool,
 }
 
 #[derive(Subcommand, Debug)]
@@ -22,29 +38,47 @@ enum Commands {
   /// Configuration management
   Config {
     #[clap(subcommand)]
-    action: ConfigAction,
+    action: SettingsCommand,
   },
 }
 
-#[derive(Subcommand, Debug)]
-enum ConfigAction {
-  /// Set log level
-  LogLevel {
-    #[clap(subcommand)]
-    action: LogLevelAction,
+fn main() {
+  let cli = Cli::parse();
+  interactive::init(PromptSettings::new().with_assume_yes(cli.yes));
+
+  let store = SettingsStore::new(APP_NAME).with_env_prefix("PROWO_CLI");
+  let loaded = store.load::<Config>();
+
+  let (logger_system, mut collector) = LoggerSystem::new();
+  collector.add_output(Box::new(ConsoleOutput::new(prowo_log::colors_supported())));
+  let level = loaded
+    .as_ref()
+    .map(|config| config.log_level.clone())
+    .unwrap_or_default();
+  collector.add_filter(Box::new(LevelFilter::new(level)));
+  let (logger_system, collector_handle) = logger_system.start_collector(collector);
+  let logger = logger_system.create_logger("main", "main".to_string());
+
+  if let Err(e) = &loaded {
+    logger.warn(format!("Using the default settings: {}", e));
   }
-}
 
-#[derive(Subcommand, Debug)]
-enum LogLevelAction {
-  /// Set to default
-  Default,
-  /// Show current value
-  Show,
-  /// Set to a specific value
-  Set { value: LogLevel },
-}
+  let exit_code = match cli.command {
+    Commands::Setup => {
+      println!("Hello, world!");
+      0
+    }
+    Commands::Config { action } => match store.run::<Config>(&action) {
+      Ok(()) => 0,
+      Err(e) => {
+        logger.error(e.to_string());
+        1
+      }
+    },
+  };
 
-fn main() {
-  println!("Hello, world!");
+  if logger_system.shutdown() {
+    let _ = collector_handle.join();
+  }
+  std::process::exit(exit_code);
 }
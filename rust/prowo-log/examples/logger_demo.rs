@@ -1,6 +1,4 @@
-use projektwoche_setup::logger::{
-    ConsoleOutput, LevelFilter, LogCollector, LogLevel, Logger, LoggerSystem,
-};
+use prowo_log::{ConsoleOutput, LevelFilter, LogLevel, LoggerSystem};
 use std::thread;
 use std::time::Duration;
 
@@ -65,11 +63,12 @@ fn main() {
 
     main_logger.info("All installations completed successfully!");
 
-    // Drop the logger system to signal the collector to stop
-    drop(logger_system);
-
-    // Wait for the collector to finish
-    collector_handle.join().unwrap();
+    // Stop the collector once the queued messages are written; the loggers
+    // above are still alive and would otherwise keep it running
+    if logger_system.shutdown() {
+        // Wait for the collector to finish
+        collector_handle.join().unwrap();
+    }
 
     println!("Demo completed!");
 }
\ No newline at end of file
@@ -0,0 +1,1460 @@
+//! # Prowo Log
+//!
+//! The logging subsystem shared by the `projektwoche` and
+//! `projektwoche-setup` command line tools.
+//!
+//! Threads log through cheap [`Logger`] handles that send their messages over
+//! a channel to a single [`LogCollector`]. The collector filters them (see
+//! [`LevelFilter`]) and writes them to its outputs: the console
+//! ([`ConsoleOutput`]), JSON lines ([`JsonOutput`]) or a per-run log file
+//! ([`FileOutput`]) that [`list_runs`] and [`prune_runs`] manage.
+//!
+//! ## Features
+//!
+//! - `serde`: `Serialize`/`Deserialize` for [`LogLevel`], [`LogTimestamp`]
+//!   and [`LogFormat`], so they can be part of a configuration file, and
+//!   `Serialize` for [`LogEvent`]
+//! - `clap`: `ValueEnum` for the same types, so they can be command line
+//!   arguments
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! use prowo_log::{ConsoleOutput, LevelFilter, LogLevel, LoggerSystem};
+//!
+//! let (logger_system, mut collector) = LoggerSystem::new();
+//! collector.add_output(Box::new(ConsoleOutput::new(prowo_log::colors_supported())));
+//! collector.add_filter(Box::new(LevelFilter::new(LogLevel::Info)));
+//! let (logger_system, handle) = logger_system.start_collector(collector);
+//!
+//! let logger = logger_system.create_logger("main", "main".to_string());
+//! prowo_log::log_info!(logger, "Installing {} packages", 3);
+//!
+//! if logger_system.shutdown() {
+//!   let _ = handle.join();
+//! }
+//! ```
+
+use chrono::{DateTime, Local, NaiveDateTime, TimeZone, Utc};
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, ThreadId};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum LogLevel {
+  Debug,
+  #[default]
+  Info,
+  Warning,
+  Error,
+  Critical,
+}
+
+impl std::fmt::Display for LogLevel {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      LogLevel::Debug => write!(f, "DEBUG"),
+      LogLevel::Info => write!(f, "INFO"),
+      LogLevel::Warning => write!(f, "WARN"),
+      LogLevel::Error => write!(f, "ERROR"),
+      LogLevel::Critical => write!(f, "CRIT"),
+    }
+  }
+}
+
+impl LogLevel {
+  const ALL: [LogLevel; 5] = [
+    LogLevel::Debug,
+    LogLevel::Info,
+    LogLevel::Warning,
+    LogLevel::Error,
+    LogLevel::Critical,
+  ];
+
+  /// Returns the level `steps` places above this one, or below for negative
+  /// steps, stopping at Debug and Critical.
+  pub fn shifted(&self, steps: i32) -> LogLevel {
+    let index = Self::ALL.iter().position(|level| level == self).unwrap_or(1) as i32;
+    Self::ALL[(index + steps).clamp(0, Self::ALL.len() as i32 - 1) as usize].clone()
+  }
+
+  /// Parses a level name as used in log directives, ignoring case.
+  ///
+  /// Besides the variant names, the short forms `warn` and `crit` are accepted.
+  pub fn parse(name: &str) -> Option<LogLevel> {
+    match name.trim().to_ascii_lowercase().as_str() {
+      "debug" => Some(LogLevel::Debug),
+      "info" => Some(LogLevel::Info),
+      "warn" | "warning" => Some(LogLevel::Warning),
+      "error" => Some(LogLevel::Error),
+      "crit" | "critical" => Some(LogLevel::Critical),
+      _ => None,
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct LogMessage {
+  pub thread_id: ThreadId,
+  pub thread_name: String,
+  pub timestamp: u64,
+  pub level: LogLevel,
+  pub source: String,
+  pub message: String,
+  pub context: Option<HashMap<String, String>>,
+  pub file: Option<&'static str>,
+  pub line: Option<u32>,
+  pub event: Option<LogEvent>,
+}
+
+impl LogMessage {
+  pub fn new(
+    thread_name: String,
+    level: LogLevel,
+    source: String,
+    message: String,
+  ) -> Self {
+    Self {
+      thread_id: thread::current().id(),
+      thread_name,
+      timestamp: SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64,
+      level,
+      source,
+      message,
+      context: None,
+      file: None,
+      line: None,
+      event: None,
+    }
+  }
+
+  pub fn with_context(mut self, context: HashMap<String, String>) -> Self {
+    self.context = Some(context);
+    self
+  }
+
+  pub fn with_location(mut self, file: &'static str, line: u32) -> Self {
+    self.file = Some(file);
+    self.line = Some(line);
+    self
+  }
+
+  pub fn with_event(mut self, event: LogEvent) -> Self {
+    self.event = Some(event);
+    self
+  }
+}
+
+/// Progress of an operation on a package, attached to the log message that
+/// reports it so dashboards and scripts can follow a run without parsing text.
+///
+/// In JSON output the event is an object with a `type` field, e.g.
+/// `{"type":"step","package":"Git","operation":"install","step":1,"total":2}`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
+pub enum LogEvent {
+  /// The operation on the package began
+  Started {
+    package: &'static str,
+    operation: &'static str,
+  },
+  /// One of the operation's steps is about to run
+  StepStarted {
+    package: &'static str,
+    operation: &'static str,
+    step: usize,
+    total: usize,
+    description: String,
+  },
+  /// One of the operation's steps completed
+  Step {
+    package: &'static str,
+    operation: &'static str,
+    step: usize,
+    total: usize,
+  },
+  /// The operation ended without an error, `outcome` is `ok` or `skipped`
+  Finished {
+    package: &'static str,
+    operation: &'static str,
+    outcome: &'static str,
+    detail: String,
+  },
+  /// The operation ended with an error
+  Failed {
+    package: &'static str,
+    operation: &'static str,
+    error: String,
+  },
+  /// A download made progress; the package is in the message's fields.
+  ///
+  /// Only progress displays and JSON output show these, plain text output
+  /// skips them.
+  Download { received: u64, total: Option<u64> },
+}
+
+impl LogEvent {
+  /// Whether plain text output should skip messages with this event.
+  pub fn is_progress(&self) -> bool {
+    matches!(self, LogEvent::Download { .. })
+  }
+
+  /// Builds the JSON object of the event, with its kind in the `type` field.
+  pub fn to_json(&self) -> serde_json::Value {
+    match self {
+      LogEvent::Started { package, operation } => serde_json::json!({
+        "type": "started",
+        "package": package,
+        "operation": operation,
+      }),
+      LogEvent::StepStarted { package, operation, step, total, description } => serde_json::json!({
+        "type": "step_started",
+        "package": package,
+        "operation": operation,
+        "step": step,
+        "total": total,
+        "description": description,
+      }),
+      LogEvent::Step { package, operation, step, total } => serde_json::json!({
+        "type": "step",
+        "package": package,
+        "operation": operation,
+        "step": step,
+        "total": total,
+      }),
+      LogEvent::Finished { package, operation, outcome, detail } => serde_json::json!({
+        "type": "finished",
+        "package": package,
+        "operation": operation,
+        "outcome": outcome,
+        "detail": detail,
+      }),
+      LogEvent::Failed { package, operation, error } => serde_json::json!({
+        "type": "failed",
+        "package": package,
+        "operation": operation,
+        "error": error,
+      }),
+      LogEvent::Download { received, total } => serde_json::json!({
+        "type": "download",
+        "received": received,
+        "total": total,
+      }),
+    }
+  }
+}
+
+pub trait LogOutput: Send + Sync {
+  fn write(&self, message: &LogMessage);
+
+  /// Makes sure everything written so far has reached its destination.
+  fn flush(&self) {}
+}
+
+/// How [`ConsoleOutput`] shows the time of a message.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum LogTimestamp {
+  /// Local time of day, e.g. `14:37:57.123`
+  #[default]
+  #[cfg_attr(feature = "serde", serde(alias = "time"))]
+  Time,
+  /// Local date and time with UTC offset, e.g. `2026-10-18T14:37:57.123+02:00`
+  #[cfg_attr(feature = "serde", serde(alias = "iso8601"))]
+  Iso8601,
+  /// Seconds since the output was created, e.g. `+12.345s`
+  #[cfg_attr(feature = "serde", serde(alias = "elapsed"))]
+  Elapsed,
+  /// No time at all
+  #[cfg_attr(feature = "serde", serde(alias = "none"))]
+  None,
+}
+
+/// Returns `true` if ANSI colors should be written to stdout.
+///
+/// Colors are off when `NO_COLOR` is set (see <https://no-color.org>),
+/// `TERM` is `dumb` or stdout is not a terminal. On Windows, the console's
+/// virtual terminal mode is enabled so the escape sequences are understood.
+pub fn colors_supported() -> bool {
+  if std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+    return false;
+  }
+  if std::env::var("TERM").is_ok_and(|term| term == "dumb") {
+    return false;
+  }
+  io::stdout().is_terminal() && enable_virtual_terminal()
+}
+
+/// Returns `true` if stdout can show a live display that rewrites its lines.
+///
+/// This needs a terminal that understands ANSI cursor movement, so it is off
+/// when stdout is piped, `TERM` is `dumb` or `CI` is set.
+pub fn live_display_supported() -> bool {
+  if std::env::var_os("CI").is_some() || std::env::var("TERM").is_ok_and(|term| term == "dumb") {
+    return false;
+  }
+  io::stdout().is_terminal() && enable_virtual_terminal()
+}
+
+#[cfg(windows)]
+fn enable_virtual_terminal() -> bool {
+  use windows_sys::Win32::System::Console::{
+    ENABLE_VIRTUAL_TERMINAL_PROCESSING, GetConsoleMode, GetStdHandle, STD_OUTPUT_HANDLE,
+    SetConsoleMode,
+  };
+
+  // SAFETY: the handle comes from GetStdHandle and `mode` outlives the calls
+  unsafe {
+    let handle = GetStdHandle(STD_OUTPUT_HANDLE);
+    let mut mode = 0;
+    if GetConsoleMode(handle, &mut mode) == 0 {
+      return false;
+    }
+    mode & ENABLE_VIRTUAL_TERMINAL_PROCESSING != 0
+      || SetConsoleMode(handle, mode | ENABLE_VIRTUAL_TERMINAL_PROCESSING) != 0
+  }
+}
+
+#[cfg(not(windows))]
+fn enable_virtual_terminal() -> bool {
+  true
+}
+
+pub struct ConsoleOutput {
+  use_colors: bool,
+  timestamp: LogTimestamp,
+  started: u64,
+}
+
+impl ConsoleOutput {
+  /// Creates a console output; use [`colors_supported`] to decide on colors.
+  pub fn new(use_colors: bool) -> Self {
+    Self {
+      use_colors,
+      timestamp: LogTimestamp::default(),
+      started: SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64,
+    }
+  }
+
+  /// Sets how the time of each message is shown.
+  pub fn with_timestamp(mut self, timestamp: LogTimestamp) -> Self {
+    self.timestamp = timestamp;
+    self
+  }
+
+  /// Formats a message the way this output prints it.
+  pub fn format_message(&self, message: &LogMessage) -> String {
+    let time_str = match self.timestamp {
+      LogTimestamp::Time => local_time(message.timestamp).format("%H:%M:%S%.3f").to_string(),
+      LogTimestamp::Iso8601 => local_time(message.timestamp)
+        .format("%Y-%m-%dT%H:%M:%S%.3f%:z")
+        .to_string(),
+      LogTimestamp::Elapsed => {
+        let elapsed = message.timestamp.saturating_sub(self.started);
+        format!("+{}.{:03}s", elapsed / 1000, elapsed % 1000)
+      }
+      LogTimestamp::None => String::new(),
+    };
+
+    let level_str = if self.use_colors {
+      match message.level {
+        LogLevel::Debug => "\x1b[36mDEBUG\x1b[0m",
+        LogLevel::Info => "\x1b[32mINFO\x1b[0m",
+        LogLevel::Warning => "\x1b[33mWARN\x1b[0m",
+        LogLevel::Error => "\x1b[31mERROR\x1b[0m",
+        LogLevel::Critical => "\x1b[35mCRIT\x1b[0m",
+      }
+    } else {
+      match message.level {
+        LogLevel::Debug => "DEBUG",
+        LogLevel::Info => "INFO",
+        LogLevel::Warning => "WARN",
+        LogLevel::Error => "ERROR",
+        LogLevel::Critical => "CRIT",
+      }
+    };
+
+    let thread_name = if self.use_colors {
+      format!("\x1b[34m{}\x1b[0m", message.thread_name)
+    } else {
+      message.thread_name.clone()
+    };
+
+    let line = format!(
+      "[{}] [{}] {}: {}",
+      level_str, thread_name, message.source, message.message
+    );
+    if time_str.is_empty() {
+      line
+    } else {
+      format!("[{}] {}", time_str, line)
+    }
+  }
+}
+
+impl LogOutput for ConsoleOutput {
+  fn write(&self, message: &LogMessage) {
+    if message.event.as_ref().is_some_and(LogEvent::is_progress) {
+      return;
+    }
+    println!("{}", self.format_message(message));
+  }
+
+  fn flush(&self) {
+    let _ = io::stdout().flush();
+  }
+}
+
+/// Builds the JSON record of a message, as written by [`JsonOutput`] and
+/// JSON log files.
+///
+/// Context, source location and event are only included when present.
+pub fn json_record(message: &LogMessage) -> serde_json::Value {
+  let time = DateTime::<Utc>::from_timestamp_millis(message.timestamp as i64).unwrap_or_default();
+  let mut record = serde_json::json!({
+    "time": time.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string(),
+    "level": message.level.to_string(),
+    "thread": message.thread_name,
+    "source": message.source,
+    "message": message.message,
+  });
+  if let Some(context) = &message.context {
+    record["context"] = serde_json::json!(context);
+  }
+  if let (Some(file), Some(line)) = (message.file, message.line) {
+    record["file"] = serde_json::json!(file);
+    record["line"] = serde_json::json!(line);
+  }
+  if let Some(event) = &message.event {
+    record["event"] = event.to_json();
+  }
+  record
+}
+
+/// Writes every message as one line of JSON to stdout.
+///
+/// Used instead of [`ConsoleOutput`] with `--log-format json`, see
+/// [`json_record`] for the fields.
+#[derive(Default)]
+pub struct JsonOutput;
+
+impl JsonOutput {
+  pub fn new() -> Self {
+    Self
+  }
+}
+
+impl LogOutput for JsonOutput {
+  fn write(&self, message: &LogMessage) {
+    println!("{}", json_record(message));
+  }
+
+  fn flush(&self) {
+    let _ = io::stdout().flush();
+  }
+}
+
+/// Format of log output and log files.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum LogFormat {
+  /// One human-readable line per message, like the console without colors
+  #[default]
+  #[cfg_attr(feature = "serde", serde(alias = "text"))]
+  Text,
+  /// One JSON object per line, for log collectors and scripts
+  #[cfg_attr(feature = "serde", serde(alias = "json"))]
+  Json,
+}
+
+impl LogFormat {
+  /// File extension of logs in this format.
+  pub fn extension(&self) -> &'static str {
+    match self {
+      LogFormat::Text => "log",
+      LogFormat::Json => "jsonl",
+    }
+  }
+}
+
+/// Limits that keep the log directory from growing without bound.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogRotation {
+  /// A run's file stops growing at this size; later messages are dropped
+  pub max_file_size: u64,
+  /// The oldest runs are deleted while all runs together exceed this size
+  pub max_total_size: u64,
+  /// Runs older than this are deleted
+  pub max_age: Duration,
+}
+
+impl Default for LogRotation {
+  fn default() -> Self {
+    Self {
+      max_file_size: 10 * 1024 * 1024,
+      max_total_size: 50 * 1024 * 1024,
+      max_age: Duration::from_secs(30 * 24 * 60 * 60),
+    }
+  }
+}
+
+/// Converts a message timestamp (Unix milliseconds) into local time.
+fn local_time(millis: u64) -> DateTime<Local> {
+  Local
+    .timestamp_millis_opt(millis as i64)
+    .single()
+    .unwrap_or_else(Local::now)
+}
+
+struct FileState {
+  file: File,
+  written: u64,
+  truncated: bool,
+}
+
+/// Writes the messages of one run into its own file.
+///
+/// Files are named after the start time and the command of the run, e.g.
+/// `20261018-143757-install.log`, and flushed after every message so the
+/// log survives a crash. Old runs are pruned when the output is created
+/// (see [`LogRotation`]).
+pub struct FileOutput {
+  path: PathBuf,
+  format: LogFormat,
+  max_file_size: u64,
+  prune_failures: Vec<(PathBuf, io::Error)>,
+  state: Mutex<FileState>,
+}
+
+impl FileOutput {
+  /// Prunes old runs in `dir` and creates the file for a new run.
+  ///
+  /// # Arguments
+  ///
+  /// * `dir` - Log directory, created if missing
+  /// * `command` - Name of the command, used in the file name
+  /// * `format` - Plain text or JSON lines
+  /// * `rotation` - Limits for this file and the whole directory
+  pub fn create(
+    dir: &Path,
+    command: &str,
+    format: LogFormat,
+    rotation: &LogRotation,
+  ) -> io::Result<Self> {
+    fs::create_dir_all(dir)?;
+    let prune_failures = prune_runs(dir, rotation)?;
+
+    let stem = format!("{}-{}", Utc::now().format("%Y%m%d-%H%M%S"), command);
+
+    let mut path = dir.join(format!("{}.{}", stem, format.extension()));
+    let mut attempt = 1;
+    let file = loop {
+      match OpenOptions::new().write(true).create_new(true).open(&path) {
+        Ok(file) => break file,
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists && attempt < 100 => {
+          attempt += 1;
+          path = dir.join(format!("{}-{}.{}", stem, attempt, format.extension()));
+        }
+        Err(e) => return Err(e),
+      }
+    };
+
+    Ok(Self {
+      path,
+      format,
+      max_file_size: rotation.max_file_size,
+      prune_failures,
+      state: Mutex::new(FileState {
+        file,
+        written: 0,
+        truncated: false,
+      }),
+    })
+  }
+
+  /// Path of the file this run is logged to.
+  pub fn path(&self) -> &Path {
+    &self.path
+  }
+
+  /// Old runs that could not be deleted while pruning, with the reason.
+  pub fn prune_failures(&self) -> &[(PathBuf, io::Error)] {
+    &self.prune_failures
+  }
+
+  fn format_message(&self, message: &LogMessage) -> String {
+    match self.format {
+      LogFormat::Text => format!(
+        "{} [{}] [{}] {}: {}",
+        local_time(message.timestamp).format("%Y-%m-%d %H:%M:%S%.3f"),
+        message.level,
+        message.thread_name,
+        message.source,
+        message.message
+      ),
+      LogFormat::Json => json_record(message).to_string(),
+    }
+  }
+}
+
+impl LogOutput for FileOutput {
+  fn write(&self, message: &LogMessage) {
+    if self.format == LogFormat::Text && message.event.as_ref().is_some_and(LogEvent::is_progress) {
+      return;
+    }
+    let Ok(mut state) = self.state.lock() else {
+      return;
+    };
+    if state.truncated {
+      return;
+    }
+
+    let mut line = self.format_message(message);
+    if state.written + line.len() as u64 + 1 > self.max_file_size {
+      state.truncated = true;
+      line = match self.format {
+        LogFormat::Text => "Log file size limit reached, further messages are dropped.".to_string(),
+        LogFormat::Json => serde_json::json!({
+          "level": LogLevel::Warning.to_string(),
+          "message": "Log file size limit reached, further messages are dropped.",
+        })
+        .to_string(),
+      };
+    }
+
+    if writeln!(state.file, "{}", line).and_then(|_| state.file.flush()).is_ok() {
+      state.written += line.len() as u64 + 1;
+    }
+  }
+}
+
+/// A past run recorded in the log directory.
+#[derive(Debug, Clone)]
+pub struct LogRun {
+  /// File name without extension, e.g. `20261018-143757-install`
+  pub id: String,
+  /// Path of the log file
+  pub path: PathBuf,
+  /// Format of the file, derived from its extension
+  pub format: LogFormat,
+  /// Size of the file in bytes
+  pub size: u64,
+  /// Last time the file was written
+  pub modified: SystemTime,
+}
+
+impl LogRun {
+  /// Returns the start time of the run as `YYYY-MM-DD HH:MM:SS` (UTC), read from its id.
+  pub fn started(&self) -> String {
+    match run_start(&self.id) {
+      Some(start) => start.format("%Y-%m-%d %H:%M:%S").to_string(),
+      None => self.id.clone(),
+    }
+  }
+
+  /// Counts the warnings and errors (including critical messages) of the run.
+  pub fn problems(&self) -> io::Result<(usize, usize)> {
+    let reader = BufReader::new(File::open(&self.path)?);
+    let (mut warnings, mut errors) = (0, 0);
+    for line in reader.lines() {
+      let line = line?;
+      let level = match self.format {
+        LogFormat::Text => line.split(" [").nth(1).and_then(|rest| rest.split(']').next()).map(str::to_string),
+        LogFormat::Json => serde_json::from_str::<serde_json::Value>(&line)
+          .ok()
+          .and_then(|entry| entry["level"].as_str().map(str::to_string)),
+      };
+      match level.as_deref() {
+        Some("WARN") => warnings += 1,
+        Some("ERROR") | Some("CRIT") => errors += 1,
+        _ => {}
+      }
+    }
+    Ok((warnings, errors))
+  }
+}
+
+/// Reads the start time from a run id of the form `YYYYMMDD-HHMMSS-<command>`,
+/// as written by [`FileOutput`].
+///
+/// Returns `None` for any other name, so unrelated files are never taken
+/// for runs.
+fn run_start(id: &str) -> Option<NaiveDateTime> {
+  let (stamp, command) = (id.get(..15)?, id.get(16..)?);
+  let shaped = stamp
+    .bytes()
+    .enumerate()
+    .all(|(index, byte)| if index == 8 { byte == b'-' } else { byte.is_ascii_digit() });
+  if !shaped || id.as_bytes()[15] != b'-' || command.is_empty() {
+    return None;
+  }
+  NaiveDateTime::parse_from_str(stamp, "%Y%m%d-%H%M%S").ok()
+}
+
+/// Lists the runs recorded in `dir`, newest first.
+///
+/// Only files named like the runs [`FileOutput`] creates are listed; other
+/// files in a shared directory are left alone. A missing directory means no
+/// runs have been recorded yet.
+pub fn list_runs(dir: &Path) -> io::Result<Vec<LogRun>> {
+  let entries = match fs::read_dir(dir) {
+    Ok(entries) => entries,
+    Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+    Err(e) => return Err(e),
+  };
+
+  let mut runs = Vec::new();
+  for entry in entries {
+    let entry = entry?;
+    let path = entry.path();
+    let format = match path.extension().and_then(|ext| ext.to_str()) {
+      Some("log") => LogFormat::Text,
+      Some("jsonl") => LogFormat::Json,
+      _ => continue,
+    };
+    let Some(id) = path.file_stem().map(|stem| stem.to_string_lossy().to_string()) else {
+      continue;
+    };
+    if run_start(&id).is_none() {
+      continue;
+    }
+    let metadata = entry.metadata()?;
+    runs.push(LogRun {
+      id,
+      path,
+      format,
+      size: metadata.len(),
+      modified: metadata.modified().unwrap_or(UNIX_EPOCH),
+    });
+  }
+
+  runs.sort_by(|a, b| b.id.cmp(&a.id));
+  Ok(runs)
+}
+
+/// Deletes runs older than the maximum age, then the oldest runs until all
+/// remaining runs fit into the maximum total size.
+///
+/// A run that cannot be deleted does not stop the pruning; it is skipped and
+/// returned with the reason, so the caller can warn about it.
+pub fn prune_runs(dir: &Path, rotation: &LogRotation) -> io::Result<Vec<(PathBuf, io::Error)>> {
+  let now = SystemTime::now();
+  let mut total = 0;
+  let mut failures = Vec::new();
+  for run in list_runs(dir)? {
+    let age = now.duration_since(run.modified).unwrap_or_default();
+    if age > rotation.max_age || total + run.size > rotation.max_total_size {
+      if let Err(e) = fs::remove_file(&run.path) {
+        failures.push((run.path, e));
+      }
+    } else {
+      total += run.size;
+    }
+  }
+  Ok(failures)
+}
+
+pub trait LogFilter: Send + Sync {
+  fn allow(&self, message: &LogMessage) -> bool;
+}
+
+/// Lets messages through from a minimum level on.
+///
+/// Messages with a matching field or from a given source can get their own
+/// minimum level, e.g. to show the Debug messages of a single package:
+///
+/// ```rust,ignore
+/// let filter = LevelFilter::new(LogLevel::Info).with_field_level("package", "Node.js", LogLevel::Debug);
+/// ```
+///
+/// Field levels take precedence over source levels, which take precedence
+/// over the minimum level.
+pub struct LevelFilter {
+  min_level: LogLevel,
+  source_levels: Vec<(String, LogLevel)>,
+  field_levels: Vec<(String, String, LogLevel)>,
+}
+
+impl LevelFilter {
+  pub fn new(min_level: LogLevel) -> Self {
+    Self {
+      min_level,
+      source_levels: Vec::new(),
+      field_levels: Vec::new(),
+    }
+  }
+
+  /// Builds a filter from comma-separated directives in the style of
+  /// `env_logger`, starting from `min_level`:
+  ///
+  /// - `debug` sets the minimum level
+  /// - `installer=debug` sets the level of a source
+  /// - `package:Node.js=debug` sets the level of messages with a field value
+  ///
+  /// # Errors
+  ///
+  /// Returns a description of the first directive that cannot be parsed.
+  pub fn from_directives(min_level: LogLevel, directives: &str) -> Result<Self, String> {
+    let mut filter = Self::new(min_level);
+    for directive in directives.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+      let invalid = || format!("invalid log directive '{}'", directive);
+      match directive.rsplit_once('=') {
+        None => filter.min_level = LogLevel::parse(directive).ok_or_else(invalid)?,
+        Some((target, level)) => {
+          let level = LogLevel::parse(level).ok_or_else(invalid)?;
+          match target.split_once(':') {
+            Some((key, value)) if !key.is_empty() => {
+              filter = filter.with_field_level(key.trim(), value.trim(), level);
+            }
+            Some(_) => return Err(invalid()),
+            None if target.trim().is_empty() => return Err(invalid()),
+            None => filter = filter.with_source_level(target.trim(), level),
+          }
+        }
+      }
+    }
+    Ok(filter)
+  }
+
+  /// Minimum level for messages without a matching source or field level.
+  pub fn min_level(&self) -> &LogLevel {
+    &self.min_level
+  }
+
+  /// Changes the minimum level, keeping the source and field levels.
+  pub fn with_min_level(mut self, min_level: LogLevel) -> Self {
+    self.min_level = min_level;
+    self
+  }
+
+  /// Uses `level` as the minimum for messages from the logger `source`.
+  pub fn with_source_level<S: Into<String>>(mut self, source: S, level: LogLevel) -> Self {
+    self.source_levels.push((source.into(), level));
+    self
+  }
+
+  /// Uses `level` as the minimum for messages whose field `key` equals `value`.
+  ///
+  /// When several fields match, the first one added wins.
+  pub fn with_field_level<K: Into<String>, V: Into<String>>(
+    mut self,
+    key: K,
+    value: V,
+    level: LogLevel,
+  ) -> Self {
+    self.field_levels.push((key.into(), value.into(), level));
+    self
+  }
+
+  /// Returns the minimum level that applies to `message`.
+  fn min_level_for(&self, message: &LogMessage) -> &LogLevel {
+    message
+      .context
+      .as_ref()
+      .and_then(|context| {
+        self
+          .field_levels
+          .iter()
+          .find(|(key, value, _)| context.get(key) == Some(value))
+      })
+      .map(|(_, _, level)| level)
+      .or_else(|| {
+        self
+          .source_levels
+          .iter()
+          .find(|(source, _)| *source == message.source)
+          .map(|(_, level)| level)
+      })
+      .unwrap_or(&self.min_level)
+  }
+}
+
+impl LogFilter for LevelFilter {
+  fn allow(&self, message: &LogMessage) -> bool {
+    message.level >= *self.min_level_for(message)
+  }
+}
+
+/// What loggers and the logger system send to the collector.
+pub enum LogCommand {
+  /// A message to filter and write
+  Message(Box<LogMessage>),
+  /// Write everything sent before, flush the outputs and confirm
+  Flush(Sender<()>),
+  /// Write everything sent before, flush the outputs, confirm and stop
+  Stop(Sender<()>),
+}
+
+/// Receives the messages of all loggers and writes them to the outputs.
+///
+/// The collector runs until the logger system is shut down, even while
+/// loggers are still alive in other threads; their later messages are
+/// dropped. It also stops once every sender is gone.
+pub struct LogCollector {
+  receiver: Receiver<LogCommand>,
+  outputs: Vec<Box<dyn LogOutput>>,
+  filters: Vec<Box<dyn LogFilter>>,
+  /// Set on shutdown; queued messages are skipped once it has passed
+  deadline: Arc<Mutex<Option<Instant>>>,
+}
+
+impl LogCollector {
+  pub fn new(receiver: Receiver<LogCommand>) -> Self {
+    Self {
+      receiver,
+      outputs: Vec::new(),
+      filters: Vec::new(),
+      deadline: Arc::new(Mutex::new(None)),
+    }
+  }
+
+  pub fn add_output(&mut self, output: Box<dyn LogOutput>) {
+    self.outputs.push(output);
+  }
+
+  pub fn add_filter(&mut self, filter: Box<dyn LogFilter>) {
+    self.filters.push(filter);
+  }
+
+  /// Processes commands until a stop command arrives or all senders are gone.
+  pub fn run(&self) {
+    while let Ok(command) = self.receiver.recv() {
+      if self.past_deadline() {
+        self.flush();
+        self.skip_to_stop(command);
+        return;
+      }
+      match command {
+        LogCommand::Message(message) => self.write(&message),
+        LogCommand::Flush(done) => {
+          self.flush();
+          let _ = done.send(());
+        }
+        LogCommand::Stop(done) => {
+          self.flush();
+          let _ = done.send(());
+          return;
+        }
+      }
+    }
+    self.flush();
+  }
+
+  fn past_deadline(&self) -> bool {
+    self
+      .deadline
+      .lock()
+      .map(|deadline| deadline.is_some_and(|deadline| Instant::now() >= deadline))
+      .unwrap_or(false)
+  }
+
+  /// Drops the queued messages after the shutdown deadline, confirming the
+  /// pending flush and stop commands.
+  fn skip_to_stop(&self, mut command: LogCommand) {
+    loop {
+      match command {
+        LogCommand::Message(_) => {}
+        LogCommand::Flush(done) => {
+          let _ = done.send(());
+        }
+        LogCommand::Stop(done) => {
+          let _ = done.send(());
+          return;
+        }
+      }
+      match self.receiver.recv() {
+        Ok(next) => command = next,
+        Err(_) => return,
+      }
+    }
+  }
+
+  fn write(&self, message: &LogMessage) {
+    if self.filters.iter().all(|filter| filter.allow(message)) {
+      for output in &self.outputs {
+        output.write(message);
+      }
+    }
+  }
+
+  fn flush(&self) {
+    for output in &self.outputs {
+      output.flush();
+    }
+  }
+}
+
+/// Sends messages of one component to the collector.
+///
+/// A logger can carry key/value fields (e.g. `package`, `phase`) that are
+/// attached to each of its messages as context. Child loggers inherit them,
+/// so a step logger still reports the package it belongs to.
+#[derive(Clone)]
+pub struct Logger {
+  pub identifier: &'static str,
+  thread_name: String,
+  sender: Sender<LogCommand>,
+  fields: HashMap<String, String>,
+}
+
+impl std::fmt::Debug for Logger {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("Logger")
+      .field("identifier", &self.identifier)
+      .field("thread_name", &self.thread_name)
+      .field("fields", &self.fields)
+      .finish()
+  }
+}
+
+impl Logger {
+  pub fn new(identifier: &'static str, thread_name: String, sender: Sender<LogCommand>) -> Self {
+    Self {
+      identifier,
+      thread_name,
+      sender,
+      fields: HashMap::new(),
+    }
+  }
+
+  /// Adds a field that is attached to every message of this logger and its children.
+  pub fn with_field<K: Into<String>, V: ToString>(mut self, key: K, value: V) -> Self {
+    self.fields.insert(key.into(), value.to_string());
+    self
+  }
+
+  /// Returns a logger for a part of this component that keeps its thread
+  /// name and fields.
+  pub fn child(&self, identifier: &'static str) -> Logger {
+    Logger {
+      identifier,
+      ..self.clone()
+    }
+  }
+
+  /// Fields attached to the messages of this logger.
+  pub fn fields(&self) -> &HashMap<String, String> {
+    &self.fields
+  }
+
+  fn message(&self, level: LogLevel, message: String) -> LogMessage {
+    let log_message = LogMessage::new(
+      self.thread_name.clone(),
+      level,
+      self.identifier.to_string(),
+      message,
+    );
+    if self.fields.is_empty() {
+      log_message
+    } else {
+      log_message.with_context(self.fields.clone())
+    }
+  }
+
+  pub fn log(&self, level: LogLevel, message: String) {
+    let _ = self.sender.send(LogCommand::Message(Box::new(self.message(level, message))));
+  }
+
+  /// Logs a message with the source location it was written at, as the
+  /// `log_*` macros do.
+  pub fn log_at(&self, level: LogLevel, message: String, file: &'static str, line: u32) {
+    let message = self.message(level, message).with_location(file, line);
+    let _ = self.sender.send(LogCommand::Message(Box::new(message)));
+  }
+
+  /// Logs a message that reports a [`LogEvent`].
+  pub fn event<S: Into<String>>(&self, level: LogLevel, message: S, event: LogEvent) {
+    let message = self.message(level, message.into()).with_event(event);
+    let _ = self.sender.send(LogCommand::Message(Box::new(message)));
+  }
+
+  pub fn debug<S: Into<String>>(&self, message: S) {
+    self.log(LogLevel::Debug, message.into());
+  }
+
+  pub fn info<S: Into<String>>(&self, message: S) {
+    self.log(LogLevel::Info, message.into());
+  }
+
+  pub fn warn<S: Into<String>>(&self, message: S) {
+    self.log(LogLevel::Warning, message.into());
+  }
+
+  pub fn error<S: Into<String>>(&self, message: S) {
+    self.log(LogLevel::Error, message.into());
+  }
+
+  pub fn critical<S: Into<String>>(&self, message: S) {
+    self.log(LogLevel::Critical, message.into());
+  }
+}
+
+/// Time the collector gets to write the queued messages on shutdown.
+pub const DRAIN_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Creates loggers and controls the collector they send to.
+#[derive(Clone)]
+pub struct LoggerSystem {
+  sender: Sender<LogCommand>,
+  deadline: Arc<Mutex<Option<Instant>>>,
+}
+
+impl LoggerSystem {
+  pub fn new() -> (Self, LogCollector) {
+    let (sender, receiver) = mpsc::channel();
+    let collector = LogCollector::new(receiver);
+
+    (
+      Self {
+        sender,
+        deadline: collector.deadline.clone(),
+      },
+      collector,
+    )
+  }
+
+  pub fn start_collector(self, collector: LogCollector) -> (Self, thread::JoinHandle<()>) {
+    let handle = thread::spawn(move || {
+      collector.run();
+    });
+
+    (self, handle)
+  }
+
+  pub fn create_logger(&self, identifier: &'static str, thread_name: String) -> Logger {
+    Logger::new(identifier, thread_name, self.sender.clone())
+  }
+
+  /// Waits until every message sent before has been written and the
+  /// outputs are flushed.
+  ///
+  /// Returns `false` if the collector did not confirm within `timeout` or
+  /// is no longer running.
+  pub fn flush(&self, timeout: Duration) -> bool {
+    let (done, confirmed) = mpsc::channel();
+    self.sender.send(LogCommand::Flush(done)).is_ok() && confirmed.recv_timeout(timeout).is_ok()
+  }
+
+  /// Stops the collector after writing the queued messages, waiting at most
+  /// [`DRAIN_TIMEOUT`]. See [`LoggerSystem::shutdown_within`].
+  pub fn shutdown(self) -> bool {
+    self.shutdown_within(DRAIN_TIMEOUT)
+  }
+
+  /// Stops the collector after writing every message sent before this call.
+  ///
+  /// Loggers may still be alive; messages they send afterwards are dropped.
+  /// Returns `true` once the collector has finished, so its thread can be
+  /// joined without blocking, or `false` if writing the queued messages took
+  /// longer than `timeout`.
+  pub fn shutdown_within(self, timeout: Duration) -> bool {
+    if let Ok(mut deadline) = self.deadline.lock() {
+      *deadline = Some(Instant::now() + timeout);
+    }
+    let (done, confirmed) = mpsc::channel();
+    // Allow for writing the message that is in progress when the deadline passes
+    self.sender.send(LogCommand::Stop(done)).is_ok()
+      && confirmed.recv_timeout(timeout + timeout / 2).is_ok()
+  }
+}
+
+// The macros record the file and line they are called from, which JSON
+// output and log files include.
+
+#[macro_export]
+macro_rules! log_debug {
+  ($logger:expr, $($arg:tt)*) => {
+    $logger.log_at($crate::LogLevel::Debug, format!($($arg)*), file!(), line!())
+  };
+}
+
+#[macro_export]
+macro_rules! log_info {
+  ($logger:expr, $($arg:tt)*) => {
+    $logger.log_at($crate::LogLevel::Info, format!($($arg)*), file!(), line!())
+  };
+}
+
+#[macro_export]
+macro_rules! log_warn {
+  ($logger:expr, $($arg:tt)*) => {
+    $logger.log_at($crate::LogLevel::Warning, format!($($arg)*), file!(), line!())
+  };
+}
+
+#[macro_export]
+macro_rules! log_error {
+  ($logger:expr, $($arg:tt)*) => {
+    $logger.log_at($crate::LogLevel::Error, format!($($arg)*), file!(), line!())
+  };
+}
+
+#[macro_export]
+macro_rules! log_critical {
+  ($logger:expr, $($arg:tt)*) => {
+    $logger.log_at($crate::LogLevel::Critical, format!($($arg)*), file!(), line!())
+  };
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// Output that remembers the messages written to it.
+  #[derive(Clone, Default)]
+  struct Recorder {
+    messages: Arc<Mutex<Vec<String>>>,
+    delay: Duration,
+  }
+
+  impl Recorder {
+    fn slow(delay: Duration) -> Self {
+      Self {
+        delay,
+        ..Self::default()
+      }
+    }
+
+    fn messages(&self) -> Vec<String> {
+      self.messages.lock().unwrap().clone()
+    }
+  }
+
+  impl LogOutput for Recorder {
+    fn write(&self, message: &LogMessage) {
+      thread::sleep(self.delay);
+      self.messages.lock().unwrap().push(message.message.clone());
+    }
+  }
+
+  fn start(recorder: &Recorder) -> (LoggerSystem, thread::JoinHandle<()>) {
+    let (logger_system, mut collector) = LoggerSystem::new();
+    collector.add_output(Box::new(recorder.clone()));
+    logger_system.start_collector(collector)
+  }
+
+  #[test]
+  fn shutdown_writes_every_message_from_all_threads() {
+    let recorder = Recorder::default();
+    let (logger_system, handle) = start(&recorder);
+
+    let workers: Vec<_> = (0..8)
+      .map(|worker| {
+        let logger = logger_system.create_logger("test", format!("worker-{}", worker));
+        thread::spawn(move || {
+          for index in 0..500 {
+            logger.info(format!("{}-{}", worker, index));
+          }
+        })
+      })
+      .collect();
+    for worker in workers {
+      worker.join().unwrap();
+    }
+
+    assert!(logger_system.shutdown());
+    handle.join().unwrap();
+
+    let messages = recorder.messages();
+    assert_eq!(messages.len(), 8 * 500);
+    // Messages of one logger keep their order
+    for worker in 0..8 {
+      let own: Vec<_> = messages
+        .iter()
+        .filter(|message| message.starts_with(&format!("{}-", worker)))
+        .cloned()
+        .collect();
+      let expected: Vec<_> = (0..500).map(|index| format!("{}-{}", worker, index)).collect();
+      assert_eq!(own, expected);
+    }
+  }
+
+  #[test]
+  fn shutdown_does_not_wait_for_living_loggers() {
+    let recorder = Recorder::default();
+    let (logger_system, handle) = start(&recorder);
+    let logger = logger_system.create_logger("test", "main".to_string());
+    let bundle_copy = logger_system.clone();
+
+    logger.info("before shutdown");
+    assert!(logger_system.shutdown());
+    handle.join().unwrap();
+
+    // Late messages are dropped instead of blocking or panicking
+    logger.info("after shutdown");
+    drop(bundle_copy);
+    assert_eq!(recorder.messages(), vec!["before shutdown".to_string()]);
+  }
+
+  #[test]
+  fn flush_waits_for_earlier_messages() {
+    let recorder = Recorder::slow(Duration::from_millis(5));
+    let (logger_system, handle) = start(&recorder);
+    let logger = logger_system.create_logger("test", "main".to_string());
+
+    for index in 0..20 {
+      logger.info(index.to_string());
+    }
+    assert!(logger_system.flush(Duration::from_secs(5)));
+    assert_eq!(recorder.messages().len(), 20);
+
+    assert!(logger_system.shutdown());
+    handle.join().unwrap();
+  }
+
+  #[test]
+  fn shutdown_gives_up_at_the_drain_deadline() {
+    let recorder = Recorder::slow(Duration::from_millis(50));
+    let (logger_system, handle) = start(&recorder);
+    let logger = logger_system.create_logger("test", "main".to_string());
+
+    for index in 0..100 {
+      logger.info(index.to_string());
+    }
+    let started = Instant::now();
+    assert!(logger_system.shutdown_within(Duration::from_millis(200)));
+    assert!(started.elapsed() < Duration::from_secs(1));
+
+    handle.join().unwrap();
+    assert!(recorder.messages().len() < 100);
+  }
+
+  #[test]
+  fn collector_stops_when_all_senders_are_gone() {
+    let recorder = Recorder::default();
+    let (logger_system, handle) = start(&recorder);
+    let logger = logger_system.create_logger("test", "main".to_string());
+
+    logger.info("last words");
+    drop(logger);
+    drop(logger_system);
+    handle.join().unwrap();
+
+    assert_eq!(recorder.messages(), vec!["last words".to_string()]);
+  }
+
+  /// Creates an empty directory for one test below the system temp directory.
+  fn test_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("prowo-log-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+  }
+
+  /// Creates a file of `size` bytes that was last modified `age` ago.
+  fn touch(dir: &Path, name: &str, size: u64, age: Duration) {
+    let file = File::create(dir.join(name)).unwrap();
+    file.set_len(size).unwrap();
+    file.set_modified(SystemTime::now() - age).unwrap();
+  }
+
+  fn remaining(dir: &Path) -> Vec<String> {
+    let mut names: Vec<_> = fs::read_dir(dir)
+      .unwrap()
+      .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+      .collect();
+    names.sort();
+    names
+  }
+
+  fn rotation(max_total_size: u64, max_age_days: u64) -> LogRotation {
+    LogRotation {
+      max_total_size,
+      max_age: Duration::from_secs(max_age_days * 24 * 60 * 60),
+      ..LogRotation::default()
+    }
+  }
+
+  const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+  #[test]
+  fn list_runs_only_matches_run_files() {
+    let dir = test_dir("list");
+    touch(&dir, "20261018-143757-install.log", 1, Duration::ZERO);
+    touch(&dir, "20261018-143800-repair-2.jsonl", 1, Duration::ZERO);
+    let others = [
+      "syslog.log",
+      "events.jsonl",
+      "2026-install.log",
+      "20261018-143757-.log",
+      "20261318-143757-install.log",
+      "ä0261018-143757-install.log",
+      "20261018-143757-install.txt",
+    ];
+    for other in others {
+      touch(&dir, other, 1, Duration::ZERO);
+    }
+
+    let runs = list_runs(&dir).unwrap();
+    let ids: Vec<_> = runs.iter().map(|run| run.id.as_str()).collect();
+    assert_eq!(ids, ["20261018-143800-repair-2", "20261018-143757-install"]);
+    assert_eq!(runs[0].format, LogFormat::Json);
+    assert_eq!(runs[1].format, LogFormat::Text);
+    fs::remove_dir_all(&dir).unwrap();
+  }
+
+  #[test]
+  fn started_reads_the_id_without_panicking_on_other_names() {
+    let run = |id: &str| LogRun {
+      id: id.to_string(),
+      path: PathBuf::from(id),
+      format: LogFormat::Text,
+      size: 0,
+      modified: UNIX_EPOCH,
+    };
+    assert_eq!(run("20261018-143757-install").started(), "2026-10-18 14:37:57");
+    for id in ["äöüäöüäöüäöüäöü-install", "2026101ä-143757-install", "short"] {
+      assert_eq!(run(id).started(), id);
+    }
+  }
+
+  #[test]
+  fn prune_deletes_old_runs_then_the_oldest_over_the_size_limit() {
+    let dir = test_dir("prune");
+    touch(&dir, "20260101-090000-install.log", 10, 40 * DAY);
+    touch(&dir, "20261016-090000-install.log", 10, 2 * DAY);
+    touch(&dir, "20261017-090000-upgrade.log", 10, DAY);
+    touch(&dir, "20261018-090000-repair.jsonl", 10, Duration::ZERO);
+    // Unrelated files in a shared directory are neither deleted nor counted
+    touch(&dir, "syslog.log", 100, 400 * DAY);
+
+    let failures = prune_runs(&dir, &rotation(25, 30)).unwrap();
+    assert!(failures.is_empty());
+    assert_eq!(
+      remaining(&dir),
+      ["20261017-090000-upgrade.log", "20261018-090000-repair.jsonl", "syslog.log"]
+    );
+    fs::remove_dir_all(&dir).unwrap();
+  }
+
+  #[test]
+  fn prune_continues_after_a_failed_delete() {
+    let dir = test_dir("prune-failure");
+    // A directory named like a run cannot be removed as a file
+    fs::create_dir(dir.join("20261018-090000-install.log")).unwrap();
+    touch(&dir, "20261017-090000-install.log", 10, DAY);
+    touch(&dir, "20261019-090000-install.log", 10, Duration::ZERO);
+
+    let failures = prune_runs(&dir, &rotation(0, 30)).unwrap();
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].0, dir.join("20261018-090000-install.log"));
+    assert_eq!(remaining(&dir), ["20261018-090000-install.log"]);
+    fs::remove_dir_all(&dir).unwrap();
+  }
+}